@@ -0,0 +1,225 @@
+//! e-Govの法令閲覧画面と同じ形式のアンカーID
+//!
+//! 本則は`Mp-Pa_1-Ch_1-At_9-Pr_2-It_1`のように、`Mp`に続けて階層ごとの記号と番号を`-`でつなぐ。
+//! 附則は、改正法令の法令番号のないもの（制定時の附則）を`Sp`、改正法令の附則を文書中の順に`Sp_1`、`Sp_2`…とする。
+//! 別表などは`AppdxTable_1`のように、`Num`属性の値か、ない場合は文書中の順番を付ける。
+//!
+//! ```no_run
+//! use japanese_law_xml_schema::{anchor::AnchorIndex, parse_xml_file};
+//!
+//! let law = parse_xml_file("129AC0000000089_20230614_505AC0000000053.xml").unwrap();
+//! let index = AnchorIndex::new(&law);
+//! let article = index.get("Mp-Pa_1-Ch_1-At_1").unwrap();
+//! assert_eq!(index.id_of(&article), Some("Mp-Pa_1-Ch_1-At_1"));
+//! ```
+
+use crate::law::Law;
+use crate::resolve::Provision;
+use std::collections::HashMap;
+
+/// アンカーIDの中で要素の種類を表す記号
+fn code(p: &Provision) -> &'static str {
+  match p {
+    Provision::MainProvision(_) => "Mp",
+    Provision::SupplProvision(_) => "Sp",
+    Provision::Part(_) => "Pa",
+    Provision::Chapter(_) => "Ch",
+    Provision::Section(_) => "Se",
+    Provision::Subsection(_) => "Ss",
+    Provision::Division(_) => "Di",
+    Provision::Article(_) => "At",
+    Provision::Paragraph(_) => "Pr",
+    Provision::Item(_) => "It",
+    Provision::Subitem1(_) => "Si1",
+    Provision::Subitem2(_) => "Si2",
+    Provision::Subitem3(_) => "Si3",
+    Provision::Subitem4(_) => "Si4",
+    Provision::Subitem5(_) => "Si5",
+    Provision::Subitem6(_) => "Si6",
+    Provision::Subitem7(_) => "Si7",
+    Provision::Subitem8(_) => "Si8",
+    Provision::Subitem9(_) => "Si9",
+    Provision::Subitem10(_) => "Si10",
+    Provision::AppdxTable(_) => "AppdxTable",
+    Provision::AppdxNote(_) => "AppdxNote",
+    Provision::AppdxStyle(_) => "AppdxStyle",
+    Provision::AppdxFormat(_) => "AppdxFormat",
+    Provision::Appdx(_) => "Appdx",
+    Provision::AppdxFig(_) => "AppdxFig",
+  }
+}
+/// 要素のアドレス
+/// 要素を指すポインタ
+fn address(p: &Provision) -> usize {
+  match p {
+    Provision::MainProvision(t) => *t as *const _ as usize,
+    Provision::SupplProvision(t) => *t as *const _ as usize,
+    Provision::Part(t) => *t as *const _ as usize,
+    Provision::Chapter(t) => *t as *const _ as usize,
+    Provision::Section(t) => *t as *const _ as usize,
+    Provision::Subsection(t) => *t as *const _ as usize,
+    Provision::Division(t) => *t as *const _ as usize,
+    Provision::Article(t) => *t as *const _ as usize,
+    Provision::Paragraph(t) => *t as *const _ as usize,
+    Provision::Item(t) => *t as *const _ as usize,
+    Provision::Subitem1(t) => *t as *const _ as usize,
+    Provision::Subitem2(t) => *t as *const _ as usize,
+    Provision::Subitem3(t) => *t as *const _ as usize,
+    Provision::Subitem4(t) => *t as *const _ as usize,
+    Provision::Subitem5(t) => *t as *const _ as usize,
+    Provision::Subitem6(t) => *t as *const _ as usize,
+    Provision::Subitem7(t) => *t as *const _ as usize,
+    Provision::Subitem8(t) => *t as *const _ as usize,
+    Provision::Subitem9(t) => *t as *const _ as usize,
+    Provision::Subitem10(t) => *t as *const _ as usize,
+    Provision::AppdxTable(t) => *t as *const _ as usize,
+    Provision::AppdxNote(t) => *t as *const _ as usize,
+    Provision::AppdxStyle(t) => *t as *const _ as usize,
+    Provision::AppdxFormat(t) => *t as *const _ as usize,
+    Provision::Appdx(t) => *t as *const _ as usize,
+    Provision::AppdxFig(t) => *t as *const _ as usize,
+  }
+}
+
+/// 子要素にIDを付ける
+fn walk<'a>(prefix: &str, provision: &Provision<'a>, out: &mut Vec<(String, Provision<'a>)>) {
+  let mut counts: HashMap<&'static str, usize> = HashMap::new();
+  for (_, child) in provision.children() {
+    let code = code(&child);
+    let count = counts.entry(code).or_default();
+    *count += 1;
+    let num = match child.num() {
+      Some(num) => num.num_str(),
+      None => count.to_string(),
+    };
+    let id = format!("{prefix}-{code}_{num}");
+    out.push((id.clone(), child));
+    walk(&id, &child, out);
+  }
+}
+
+/// 法令中の全ての要素に、文書中の順にアンカーIDを付ける
+pub fn anchors(law: &Law) -> Vec<(String, Provision<'_>)> {
+  let mut out = Vec::new();
+  let main = Provision::MainProvision(&law.law_body.main_provision);
+  out.push(("Mp".to_string(), main));
+  walk("Mp", &main, &mut out);
+  let mut amend_count = 0;
+  for suppl_provision in law.law_body.suppl_provision.iter() {
+    let id = if suppl_provision.amend_law_num.is_some() {
+      amend_count += 1;
+      format!("Sp_{amend_count}")
+    } else {
+      "Sp".to_string()
+    };
+    let p = Provision::SupplProvision(suppl_provision);
+    out.push((id.clone(), p));
+    walk(&id, &p, &mut out);
+  }
+  fn appdx<'a, T>(
+    out: &mut Vec<(String, Provision<'a>)>,
+    lst: &'a [T],
+    num: impl Fn(&T) -> Option<&String>,
+    f: impl Fn(&'a T) -> Provision<'a>,
+  ) {
+    for (i, t) in lst.iter().enumerate() {
+      let p = f(t);
+      let num = num(t).cloned().unwrap_or_else(|| (i + 1).to_string());
+      out.push((format!("{}_{num}", code(&p)), p));
+    }
+  }
+  let body = &law.law_body;
+  appdx(
+    &mut out,
+    &body.appdx_table,
+    |t| t.num.as_ref(),
+    Provision::AppdxTable,
+  );
+  appdx(
+    &mut out,
+    &body.appdx_note,
+    |t| t.num.as_ref(),
+    Provision::AppdxNote,
+  );
+  appdx(
+    &mut out,
+    &body.appdx_style,
+    |t| t.num.as_ref(),
+    Provision::AppdxStyle,
+  );
+  appdx(
+    &mut out,
+    &body.appdx_format,
+    |t| t.num.as_ref(),
+    Provision::AppdxFormat,
+  );
+  appdx(&mut out, &body.appdx, |_| None, Provision::Appdx);
+  appdx(
+    &mut out,
+    &body.appdx_fig,
+    |t| t.num.as_ref(),
+    Provision::AppdxFig,
+  );
+  out
+}
+
+/// アンカーIDと要素の対応表
+pub struct AnchorIndex<'a> {
+  anchors: Vec<(String, Provision<'a>)>,
+  by_id: HashMap<String, usize>,
+  by_address: HashMap<(&'static str, usize), usize>,
+}
+
+impl<'a> AnchorIndex<'a> {
+  /// 法令中の全ての要素のアンカーIDを求める
+  pub fn new(law: &'a Law) -> Self {
+    let anchors = anchors(law);
+    let mut by_id = HashMap::new();
+    let mut by_address = HashMap::new();
+    for (i, (id, p)) in anchors.iter().enumerate() {
+      by_id.entry(id.clone()).or_insert(i);
+      by_address.entry((code(p), address(p))).or_insert(i);
+    }
+    AnchorIndex {
+      anchors,
+      by_id,
+      by_address,
+    }
+  }
+
+  /// アンカーIDから要素を探す
+  pub fn get(&self, id: &str) -> Option<Provision<'a>> {
+    self.by_id.get(id).map(|i| self.anchors[*i].1)
+  }
+
+  /// 要素のアンカーID
+  ///
+  /// 同じ法令の中の要素を指している場合だけIDを返す。
+  pub fn id_of(&self, provision: &Provision<'a>) -> Option<&str> {
+    self
+      .by_address
+      .get(&(code(provision), address(provision)))
+      .map(|i| self.anchors[*i].0.as_str())
+  }
+
+  /// 文書中の順にアンカーIDと要素を列挙する
+  pub fn iter(&self) -> impl Iterator<Item = (&str, Provision<'a>)> + '_ {
+    self.anchors.iter().map(|(id, p)| (id.as_str(), *p))
+  }
+}
+
+impl Law {
+  /// アンカーIDと要素の対応表を作る
+  ///
+  /// 複数のアンカーIDを探す場合は、対応表を一度だけ作って[`AnchorIndex::get`]で探す。
+  pub fn anchor_index(&self) -> AnchorIndex<'_> {
+    AnchorIndex::new(self)
+  }
+
+  /// アンカーIDが指す要素を探す
+  ///
+  /// 呼び出すたびに対応表を作るため、複数のアンカーIDを探す場合は[`Law::anchor_index`]を使う。
+  pub fn find_anchor(&self, id: &str) -> Option<Provision<'_>> {
+    self.anchor_index().get(id)
+  }
+}
@@ -22,37 +22,38 @@ pub struct AppdxTable {
 }
 
 impl Parser for AppdxTable {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "AppdxTable" {
       let num = get_attribute_opt_with_parse(element, "Num")?;
       let mut title = None;
       let mut related_article_num = None;
       let mut remarks = None;
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "AppdxTableTitle" => {
-              let v = TextWithWritingMode::parser(e)?;
+              let v = TextWithWritingMode::parser(reader, &e)?;
               title = Some(v)
             }
-            "RelatedArticleNum" => related_article_num = Some(Text::from_children(&e.children)),
+            "RelatedArticleNum" => related_article_num = Some(Text::from_reader(reader, &e)?),
             "TableStruct" => {
-              let v = TableStruct::parser(e)?;
+              let v = TableStruct::parser(reader, &e)?;
               children.push(AppdxTableContents::TableStruct(v))
             }
             "Item" => {
-              let v = Item::parser(e)?;
+              let v = Item::parser(reader, &e)?;
               children.push(AppdxTableContents::Item(v))
             }
             "Remarks" => {
-              let v = Remarks::parser(e)?;
+              let v = Remarks::parser(reader, &e)?;
               remarks = Some(v)
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(AppdxTable {
         title,
         related_article_num,
@@ -111,41 +112,42 @@ pub struct AppdxNote {
 }
 
 impl Parser for AppdxNote {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "AppdxNote" {
       let num = get_attribute_opt_with_parse(element, "Num")?;
       let mut title = None;
       let mut related_article_num = None;
       let mut remarks = None;
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "AppdxNoteTitle" => {
-              let v = TextWithWritingMode::parser(e)?;
+              let v = TextWithWritingMode::parser(reader, &e)?;
               title = Some(v)
             }
-            "RelatedArticleNum" => related_article_num = Some(Text::from_children(&e.children)),
+            "RelatedArticleNum" => related_article_num = Some(Text::from_reader(reader, &e)?),
             "TableStruct" => {
-              let v = TableStruct::parser(e)?;
+              let v = TableStruct::parser(reader, &e)?;
               children.push(AppdxNoteContents::TableStruct(v))
             }
             "FigStruct" => {
-              let v = FigStruct::parser(e)?;
+              let v = FigStruct::parser(reader, &e)?;
               children.push(AppdxNoteContents::FigStruct(v))
             }
             "NoteStruct" => {
-              let v = NoteStruct::parser(e)?;
+              let v = NoteStruct::parser(reader, &e)?;
               children.push(AppdxNoteContents::NoteStruct(v))
             }
             "Remarks" => {
-              let v = Remarks::parser(e)?;
+              let v = Remarks::parser(reader, &e)?;
               remarks = Some(v)
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(AppdxNote {
         title,
         related_article_num,
@@ -206,33 +208,34 @@ pub struct AppdxStyle {
 }
 
 impl Parser for AppdxStyle {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "AppdxStyle" {
       let num = get_attribute_opt_with_parse(element, "Num")?;
       let mut title = None;
       let mut related_article_num = None;
       let mut remarks = None;
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "AppdxStyleTitle" => {
-              let v = TextWithWritingMode::parser(e)?;
+              let v = TextWithWritingMode::parser(reader, &e)?;
               title = Some(v)
             }
-            "RelatedArticleNum" => related_article_num = Some(Text::from_children(&e.children)),
+            "RelatedArticleNum" => related_article_num = Some(Text::from_reader(reader, &e)?),
             "StyleStruct" => {
-              let v = StyleStruct::parser(e)?;
+              let v = StyleStruct::parser(reader, &e)?;
               children.push(v)
             }
             "Remarks" => {
-              let v = Remarks::parser(e)?;
+              let v = Remarks::parser(reader, &e)?;
               remarks = Some(v)
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(AppdxStyle {
         title,
         related_article_num,
@@ -282,33 +285,34 @@ pub struct AppdxFormat {
 }
 
 impl Parser for AppdxFormat {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "AppdxFormat" {
       let num = get_attribute_opt_with_parse(element, "Num")?;
       let mut title = None;
       let mut related_article_num = None;
       let mut remarks = None;
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "AppdxFormatTitle" => {
-              let v = TextWithWritingMode::parser(e)?;
+              let v = TextWithWritingMode::parser(reader, &e)?;
               title = Some(v)
             }
-            "RelatedArticleNum" => related_article_num = Some(Text::from_children(&e.children)),
+            "RelatedArticleNum" => related_article_num = Some(Text::from_reader(reader, &e)?),
             "FormatStruct" => {
-              let v = FormatStruct::parser(e)?;
+              let v = FormatStruct::parser(reader, &e)?;
               children.push(v)
             }
             "Remarks" => {
-              let v = Remarks::parser(e)?;
+              let v = Remarks::parser(reader, &e)?;
               remarks = Some(v)
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(AppdxFormat {
         title,
         related_article_num,
@@ -356,29 +360,30 @@ pub struct Appdx {
 }
 
 impl Parser for Appdx {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Appdx" {
       let mut arith_formula_num = None;
       let mut related_article_num = None;
       let mut remarks = None;
       let mut arith_formula = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
-            "ArithFormulaNum" => arith_formula_num = Some(Text::from_children(&e.children)),
-            "RelatedArticleNum" => related_article_num = Some(Text::from_children(&e.children)),
+            "ArithFormulaNum" => arith_formula_num = Some(Text::from_reader(reader, &e)?),
+            "RelatedArticleNum" => related_article_num = Some(Text::from_reader(reader, &e)?),
             "ArithFormula" => {
-              let v = ArithFormula::parser(e)?;
+              let v = ArithFormula::parser(reader, &e)?;
               arith_formula.push(v)
             }
             "Remarks" => {
-              let v = Remarks::parser(e)?;
+              let v = Remarks::parser(reader, &e)?;
               remarks = Some(v)
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(Appdx {
         arith_formula_num,
         related_article_num,
@@ -401,7 +406,7 @@ impl ToXmlElement for Appdx {
     }
     if let Some(v) = &self.related_article_num {
       e.children.push(XMLNode::Element(
-        v.to_xml_element_with_name("ArithFormulaNum"),
+        v.to_xml_element_with_name("RelatedArticleNum"),
       ))
     }
     for v in self.arith_formula.iter() {
@@ -423,32 +428,33 @@ pub struct AppdxFig {
 }
 
 impl Parser for AppdxFig {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "AppdxFig" {
       let num = get_attribute_opt_with_parse(element, "Num")?;
       let mut title = None;
       let mut related_article_num = None;
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "AppdxFigTitle" => {
-              let v = TextWithWritingMode::parser(e)?;
+              let v = TextWithWritingMode::parser(reader, &e)?;
               title = Some(v)
             }
-            "RelatedArticleNum" => related_article_num = Some(Text::from_children(&e.children)),
+            "RelatedArticleNum" => related_article_num = Some(Text::from_reader(reader, &e)?),
             "TableStruct" => {
-              let v = TableStruct::parser(e)?;
+              let v = TableStruct::parser(reader, &e)?;
               children.push(AppdxFigContents::TableStruct(v))
             }
             "FigStruct" => {
-              let v = FigStruct::parser(e)?;
+              let v = FigStruct::parser(reader, &e)?;
               children.push(AppdxFigContents::FigStruct(v))
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(AppdxFig {
         title,
         related_article_num,
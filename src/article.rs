@@ -44,17 +44,16 @@ impl Part {
 }
 
 impl Parser for Part {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Part" {
       let num_str = get_attribute(element, "Num")?;
       let num = ArticleNumber::from_num_str(&num_str)?;
       let delete = get_attribute_opt_with_parse(element, "Delete")?.unwrap_or(false);
       let hide = get_attribute_opt_with_parse(element, "Hide")?.unwrap_or(false);
-      let mut children = element.children.iter();
-      let title_element = children
-        .next()
+      let title_element = reader
+        .next_child(element)?
         .and_then(|n| {
-          if let XMLNode::Element(e) = n {
+          if let Node::Element(e) = n {
             Some(e)
           } else {
             None
@@ -63,7 +62,7 @@ impl Parser for Part {
         .ok_or(Error::missing_required_tag("PartTitle"))?;
       let title_element_name = title_element.name.clone();
       let title = if "PartTitle" == title_element_name.as_str() {
-        Text::from_children(&title_element.children)
+        Text::from_reader(reader, &title_element)?
       } else {
         return Err(Error::UnexpectedTag {
           wrong_name: title_element_name,
@@ -71,21 +70,22 @@ impl Parser for Part {
         });
       };
       let mut children_list = Vec::new();
-      for node in children {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Chapter" => {
-              let v = Chapter::parser(e)?;
+              let v = Chapter::parser(reader, &e)?;
               children_list.push(PartContents::Chapter(v))
             }
             "Article" => {
-              let v = Article::parser(e)?;
+              let v = Article::parser(reader, &e)?;
               children_list.push(PartContents::Article(v))
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(Part::new(title, num, delete, hide, children_list))
     } else {
       Err(Error::wrong_tag_name(element, "Part"))
@@ -162,17 +162,16 @@ impl Chapter {
 }
 
 impl Parser for Chapter {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Chapter" {
       let num_str = get_attribute(element, "Num")?;
       let num = ArticleNumber::from_num_str(&num_str)?;
       let delete = get_attribute_opt_with_parse(element, "Delete")?.unwrap_or(false);
       let hide = get_attribute_opt_with_parse(element, "Hide")?.unwrap_or(false);
-      let mut children = element.children.iter();
-      let title_element = children
-        .next()
+      let title_element = reader
+        .next_child(element)?
         .and_then(|n| {
-          if let XMLNode::Element(e) = n {
+          if let Node::Element(e) = n {
             Some(e)
           } else {
             None
@@ -181,7 +180,7 @@ impl Parser for Chapter {
         .ok_or(Error::missing_required_tag("ChapterTitle"))?;
       let title_element_name = title_element.name.clone();
       let title = if "ChapterTitle" == title_element_name.as_str() {
-        Text::from_children(&title_element.children)
+        Text::from_reader(reader, &title_element)?
       } else {
         return Err(Error::UnexpectedTag {
           wrong_name: title_element_name,
@@ -189,21 +188,22 @@ impl Parser for Chapter {
         });
       };
       let mut children_list = Vec::new();
-      for node in children {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Section" => {
-              let v = Section::parser(e)?;
+              let v = Section::parser(reader, &e)?;
               children_list.push(ChapterContents::Section(v))
             }
             "Article" => {
-              let v = Article::parser(e)?;
+              let v = Article::parser(reader, &e)?;
               children_list.push(ChapterContents::Article(v))
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(Chapter::new(title, num, delete, hide, children_list))
     } else {
       Err(Error::wrong_tag_name(element, "Chapter"))
@@ -280,17 +280,16 @@ impl Section {
 }
 
 impl Parser for Section {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Section" {
       let num_str = get_attribute(element, "Num")?;
       let num = ArticleNumber::from_num_str(&num_str)?;
       let delete = get_attribute_opt_with_parse(element, "Delete")?.unwrap_or(false);
       let hide = get_attribute_opt_with_parse(element, "Hide")?.unwrap_or(false);
-      let mut children = element.children.iter();
-      let title_element = children
-        .next()
+      let title_element = reader
+        .next_child(element)?
         .and_then(|n| {
-          if let XMLNode::Element(e) = n {
+          if let Node::Element(e) = n {
             Some(e)
           } else {
             None
@@ -299,7 +298,7 @@ impl Parser for Section {
         .ok_or(Error::missing_required_tag("SectionTitle"))?;
       let title_element_name = title_element.name.clone();
       let title = if "SectionTitle" == title_element_name.as_str() {
-        Text::from_children(&title_element.children)
+        Text::from_reader(reader, &title_element)?
       } else {
         return Err(Error::UnexpectedTag {
           wrong_name: title_element_name,
@@ -307,25 +306,26 @@ impl Parser for Section {
         });
       };
       let mut children_list = Vec::new();
-      for node in children {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Subsection" => {
-              let v = Subsection::parser(e)?;
+              let v = Subsection::parser(reader, &e)?;
               children_list.push(SectionContents::Subsection(v))
             }
             "Article" => {
-              let v = Article::parser(e)?;
+              let v = Article::parser(reader, &e)?;
               children_list.push(SectionContents::Article(v))
             }
             "Division" => {
-              let v = Division::parser(e)?;
+              let v = Division::parser(reader, &e)?;
               children_list.push(SectionContents::Division(v))
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(Section::new(title, num, delete, hide, children_list))
     } else {
       Err(Error::wrong_tag_name(element, "Section"))
@@ -406,17 +406,16 @@ impl Subsection {
 }
 
 impl Parser for Subsection {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Subsection" {
       let num_str = get_attribute(element, "Num")?;
       let num = ArticleNumber::from_num_str(&num_str)?;
       let delete = get_attribute_opt_with_parse(element, "Delete")?.unwrap_or(false);
       let hide = get_attribute_opt_with_parse(element, "Hide")?.unwrap_or(false);
-      let mut children = element.children.iter();
-      let title_element = children
-        .next()
+      let title_element = reader
+        .next_child(element)?
         .and_then(|n| {
-          if let XMLNode::Element(e) = n {
+          if let Node::Element(e) = n {
             Some(e)
           } else {
             None
@@ -425,7 +424,7 @@ impl Parser for Subsection {
         .ok_or(Error::missing_required_tag("SubsectionTitle"))?;
       let title_element_name = title_element.name.clone();
       let title = if "SubsectionTitle" == title_element_name.as_str() {
-        Text::from_children(&title_element.children)
+        Text::from_reader(reader, &title_element)?
       } else {
         return Err(Error::UnexpectedTag {
           wrong_name: title_element_name,
@@ -433,21 +432,22 @@ impl Parser for Subsection {
         });
       };
       let mut children_list = Vec::new();
-      for node in children {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Division" => {
-              let v = Division::parser(e)?;
+              let v = Division::parser(reader, &e)?;
               children_list.push(SubsectionContents::Division(v))
             }
             "Article" => {
-              let v = Article::parser(e)?;
+              let v = Article::parser(reader, &e)?;
               children_list.push(SubsectionContents::Article(v))
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(Subsection::new(title, num, delete, hide, children_list))
     } else {
       Err(Error::wrong_tag_name(element, "Subsection"))
@@ -526,17 +526,16 @@ impl Division {
 }
 
 impl Parser for Division {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Division" {
       let num_str = get_attribute(element, "Num")?;
       let num = ArticleNumber::from_num_str(&num_str)?;
       let delete = get_attribute_opt_with_parse(element, "Delete")?.unwrap_or(false);
       let hide = get_attribute_opt_with_parse(element, "Hide")?.unwrap_or(false);
-      let mut children = element.children.iter();
-      let title_element = children
-        .next()
+      let title_element = reader
+        .next_child(element)?
         .and_then(|n| {
-          if let XMLNode::Element(e) = n {
+          if let Node::Element(e) = n {
             Some(e)
           } else {
             None
@@ -545,7 +544,7 @@ impl Parser for Division {
         .ok_or(Error::missing_required_tag("DivisionTitle"))?;
       let title_element_name = title_element.name.clone();
       let title = if "DivisionTitle" == title_element_name.as_str() {
-        Text::from_children(&title_element.children)
+        Text::from_reader(reader, &title_element)?
       } else {
         return Err(Error::UnexpectedTag {
           wrong_name: title_element_name,
@@ -553,17 +552,18 @@ impl Parser for Division {
         });
       };
       let mut children_list = Vec::new();
-      for node in children {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Article" => {
-              let v = Article::parser(e)?;
+              let v = Article::parser(reader, &e)?;
               children_list.push(v)
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(Division::new(title, num, delete, hide, children_list))
     } else {
       Err(Error::wrong_tag_name(element, "Division"))
@@ -635,51 +635,36 @@ impl Article {
 }
 
 impl Parser for Article {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Article" {
       let num_str = get_attribute(element, "Num")?;
       let num = ArticleNumber::from_num_str(&num_str)?;
       let delete = get_attribute_opt_with_parse(element, "Delete")?.unwrap_or(false);
       let hide = get_attribute_opt_with_parse(element, "Hide")?.unwrap_or(false);
-      let mut children = element.children.iter().peekable();
       let mut caption = None;
       let mut title = Text::new();
-      loop {
-        if let Some(XMLNode::Element(e)) = children.peek() {
-          let tag_name = e.name.as_str();
-          if tag_name != "ArticleCaption" && tag_name != "ArticleTitle" {
-            break;
-          }
-        }
-        let next = children.next().unwrap();
-        if let XMLNode::Element(e) = next {
+      let mut paragraph = Vec::new();
+      let mut suppl_note = None;
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "ArticleCaption" => {
-              let v = Caption::parser(e)?;
+              let v = Caption::parser(reader, &e)?;
               caption = Some(v);
             }
             "ArticleTitle" => {
-              title = Text::from_children(&e.children);
+              title = Text::from_reader(reader, &e)?;
             }
-            s => return Err(Error::unexpected_tag(element, s)),
-          }
-        }
-      }
-      let mut paragraph = Vec::new();
-      let mut suppl_note = None;
-      for node in children {
-        if let XMLNode::Element(e) = node {
-          match e.name.as_str() {
             "Paragraph" => {
-              let v = Paragraph::parser(e)?;
+              let v = Paragraph::parser(reader, &e)?;
               paragraph.push(v);
             }
-            "SupplNote" => suppl_note = Some(Text::from_children(&e.children)),
-
+            "SupplNote" => suppl_note = Some(Text::from_reader(reader, &e)?),
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(Article::new(
         title, num, delete, hide, caption, paragraph, suppl_note,
       ))
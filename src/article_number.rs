@@ -5,9 +5,12 @@ use std::str::FromStr;
 
 use crate::result::*;
 use kansuji::Kansuji;
-use regex::Regex;
 use serde::{Deserialize, Serialize};
 
+/// 枝番号と範囲を持つ番号
+///
+/// 番号の順序は`第三条 < 第三条の二 < 第三条の二の二 < 第四条`となる。
+/// 始まりが同じ場合は、範囲でないものを先に、範囲の終わりが小さいものを先にする。
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
 pub struct ArticleNumber {
   /// 一番トップの番号
@@ -25,15 +28,15 @@ impl ArticleNumber {
     for n in self.eda_numbers.iter() {
       s.push('_');
       s.push_str(&n.to_string());
-      if !self.range_end_numbers.is_empty() {
-        let l = &self.range_end_numbers;
-        let mut l = l.iter();
-        s.push(':');
-        s.push_str(&l.next().unwrap().to_string());
-        for n in l {
-          s.push('_');
-          s.push_str(&n.to_string());
-        }
+    }
+    if !self.range_end_numbers.is_empty() {
+      let l = &self.range_end_numbers;
+      let mut l = l.iter();
+      s.push(':');
+      s.push_str(&l.next().unwrap().to_string());
+      for n in l {
+        s.push('_');
+        s.push_str(&n.to_string());
       }
     }
     s
@@ -176,8 +179,21 @@ impl ArticleNumber {
     s
   }
 
-  pub fn from_text() -> Self {
-    todo!()
+  /// 編・章・節・款・目・条・項・号の番号を表す`第十条の二`や`第十条から第十二条まで`のような文字列から生成する
+  pub fn from_text(text: &str) -> Result<Self> {
+    let err = || Error::ParsingError("ArticleNumber".to_string(), text.to_string());
+    let mut cursor = Cursor::new(text.trim());
+    let segment = cursor.segment_with_range().ok_or_else(err)?;
+    if cursor.rest.is_empty() {
+      Ok(segment.number)
+    } else {
+      Err(err())
+    }
+  }
+
+  /// 階層に応じた`第十条の二`のような文字列を生成する
+  pub fn level_text(&self, level: ArticleLevel) -> String {
+    self.gen_articles_text(&level.suffix().to_string())
   }
 
   pub fn zero() -> Self {
@@ -192,6 +208,79 @@ impl ArticleNumber {
     self.base_number == 0
   }
 
+  /// 範囲の始まりの番号
+  pub fn start(&self) -> ArticleNumber {
+    ArticleNumber {
+      base_number: self.base_number,
+      eda_numbers: self.eda_numbers.clone(),
+      range_end_numbers: Vec::new(),
+    }
+  }
+
+  /// 範囲の終わりの番号
+  ///
+  /// 範囲でない場合は自身と同じ番号になる。
+  pub fn end(&self) -> ArticleNumber {
+    match self.range_end_numbers.split_first() {
+      Some((base_number, eda_numbers)) => ArticleNumber {
+        base_number: *base_number,
+        eda_numbers: eda_numbers.to_vec(),
+        range_end_numbers: Vec::new(),
+      },
+      None => self.start(),
+    }
+  }
+
+  /// 範囲かどうか
+  pub fn is_range(&self) -> bool {
+    !self.range_end_numbers.is_empty()
+  }
+
+  /// 番号が範囲に含まれるかどうか
+  ///
+  /// `第三条から第五条まで`には`第五条の二`は含まれない。
+  /// `other`が範囲の場合は、その始まりと終わりの両方が含まれるかどうかを返す。
+  pub fn contains(&self, other: &ArticleNumber) -> bool {
+    let (start, end) = (self.start(), self.end());
+    start <= other.start() && other.end() <= end
+  }
+
+  /// 与えられた要素のうち番号が範囲に含まれるものを順に取り出す
+  ///
+  /// `num`で要素から番号を取り出す。範囲に飛び番号や枝番号があっても、実際にある条などだけを列挙するために使う。
+  pub fn select<'a, T, I, F>(&'a self, items: I, num: F) -> impl Iterator<Item = &'a T> + 'a
+  where
+    T: 'a,
+    I: IntoIterator<Item = &'a T>,
+    I::IntoIter: 'a,
+    F: Fn(&T) -> &ArticleNumber + 'a,
+  {
+    items
+      .into_iter()
+      .filter(move |item| self.contains(num(item)))
+  }
+
+  /// 枝番号を一段深くした最初の番号
+  ///
+  /// `第三条`から`第三条の二`を、`第三条の二`から`第三条の二の二`を生成する。
+  pub fn next_branch(&self) -> ArticleNumber {
+    let mut n = self.start();
+    n.eda_numbers.push(2);
+    n
+  }
+
+  /// 同じ深さで次の番号
+  ///
+  /// `第三条`から`第四条`を、`第三条の二`から`第三条の三`を生成する。
+  pub fn next_sibling(&self) -> ArticleNumber {
+    let mut n = self.start();
+    match n.eda_numbers.last_mut() {
+      Some(last) => *last += 1,
+      None => n.base_number += 1,
+    }
+    n
+  }
+
   pub fn from_item_number(n: &ItemNumber) -> Self {
     ArticleNumber {
       base_number: n.base_number,
@@ -201,6 +290,22 @@ impl ArticleNumber {
   }
 }
 
+impl PartialOrd for ArticleNumber {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for ArticleNumber {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    (self.base_number, &self.eda_numbers, &self.range_end_numbers).cmp(&(
+      other.base_number,
+      &other.eda_numbers,
+      &other.range_end_numbers,
+    ))
+  }
+}
+
 impl FromStr for ArticleNumber {
   type Err = Error;
   fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
@@ -208,112 +313,376 @@ impl FromStr for ArticleNumber {
   }
 }
 
-pub fn parse_article_number(str: &str) -> Option<ArticleNumber> {
-  let re_article = Regex::new(r"^(第)?((?<arabic_num>[0-9]+)|(?<zenkaku_num>[０-９]+)|(?<kansuji>[一二三四五六七八九十百千]+))(?<suffix>(編|章|節|款|目|条|項|号))(?<eda_str>((の|ノ)([0-9]+|[０-９]+|[一二三四五六七八九十百千]+))*)(から(第((?<arabic_num_2>[0-9]+)|(?<zenkaku_num_2>[０-９]+)|(?<kansuji_2>[一二三四五六七八九十百千]+))(?<suffix_2>(編|章|節|款|目|条|項|号))(?<eda_str_2>((の|ノ)([0-9]+|[０-９]+|[一二三四五六七八九十百千]+))*))まで)?").unwrap();
-  let re_paragraph = Regex::new(
-    r"^(?<num>([０-９]+|[0-9]+))(?<eda_str>((の|ノ)([０-９]+|[0-9]+))*)(から(?<num_2>([０-９]+|[0-9]+))(?<eda_str_2>((の|ノ)([０-９]+|[0-9]+))*)まで)?",
-  )
-  .unwrap();
-  if let Some(caps) = re_article.captures(str) {
-    // 編-条
-    let base_number = if let Some(arabic_num) = caps.name("arabic_num") {
-      arabic_num.as_str().parse::<usize>().unwrap()
-    } else if let Some(zenkaku_num) = caps.name("zenkaku_num") {
-      parse_zenkaku_num(zenkaku_num.as_str())?
-    } else if let Some(kansuji) = caps.name("kansuji") {
-      let kansuji = Kansuji::try_from(kansuji.as_str()).ok()?;
-      let n: u128 = kansuji.into();
-      n as usize
+/// 番号の付いた階層
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ArticleLevel {
+  /// 編
+  Part,
+  /// 章
+  Chapter,
+  /// 節
+  Section,
+  /// 款
+  Subsection,
+  /// 目
+  Division,
+  /// 条
+  Article,
+  /// 項
+  Paragraph,
+  /// 号
+  Item,
+}
+
+impl ArticleLevel {
+  /// 番号の後ろに付く文字
+  pub fn suffix(&self) -> char {
+    match self {
+      ArticleLevel::Part => '編',
+      ArticleLevel::Chapter => '章',
+      ArticleLevel::Section => '節',
+      ArticleLevel::Subsection => '款',
+      ArticleLevel::Division => '目',
+      ArticleLevel::Article => '条',
+      ArticleLevel::Paragraph => '項',
+      ArticleLevel::Item => '号',
+    }
+  }
+
+  /// 番号の後ろに付く文字から求める
+  pub fn from_suffix(c: char) -> Option<Self> {
+    match c {
+      '編' => Some(ArticleLevel::Part),
+      '章' => Some(ArticleLevel::Chapter),
+      '節' => Some(ArticleLevel::Section),
+      '款' => Some(ArticleLevel::Subsection),
+      '目' => Some(ArticleLevel::Division),
+      '条' => Some(ArticleLevel::Article),
+      '項' => Some(ArticleLevel::Paragraph),
+      '号' => Some(ArticleLevel::Item),
+      _ => None,
+    }
+  }
+}
+
+/// 引用される番号の一つ分
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct CitationSegment {
+  /// 階層
+  pub level: ArticleLevel,
+  /// 番号
+  pub number: ArticleNumber,
+}
+
+impl std::fmt::Display for CitationSegment {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.number.level_text(self.level))
+  }
+}
+
+/// `第三条の二第二項第一号`のような、階層を順にたどる番号の列
+///
+/// 範囲を表す`第三条第二項から第四項まで`は、最後の番号の`range_end_numbers`に範囲の終わりを持つ。
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct ArticlePath {
+  /// 上の階層から順に並べた番号
+  pub segments: Vec<CitationSegment>,
+}
+
+impl FromStr for ArticlePath {
+  type Err = Error;
+  fn from_str(s: &str) -> Result<Self> {
+    let err = || Error::ParsingError("ArticlePath".to_string(), s.to_string());
+    let (segments, rest) = parse_article_path_prefix(s.trim());
+    if segments.is_empty() || !rest.is_empty() {
+      Err(err())
     } else {
-      return None;
+      Ok(ArticlePath { segments })
+    }
+  }
+}
+
+impl std::fmt::Display for ArticlePath {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for segment in self.segments.iter() {
+      write!(f, "{segment}")?;
+    }
+    Ok(())
+  }
+}
+
+/// 文字列の先頭から、上の階層から順に並んだ番号を読めるだけ読み、残りの文字列と組にして返す
+pub(crate) fn parse_article_path_prefix(s: &str) -> (Vec<CitationSegment>, &str) {
+  let mut cursor = Cursor::new(s);
+  let mut segments: Vec<CitationSegment> = Vec::new();
+  loop {
+    let save = cursor.rest;
+    let segment = match cursor.segment_with_range() {
+      Some(segment) => segment,
+      None => break,
     };
-    let mut eda_numbers = Vec::new();
-    for s in caps["eda_str"]
-      .replace("ノ", "の")
-      .split('の')
-      .filter(|s| !s.is_empty())
-    {
-      if let Ok(n) = s.parse::<usize>() {
-        eda_numbers.push(n)
-      } else if let Some(n) = parse_zenkaku_num(s) {
-        eda_numbers.push(n)
+    if let Some(last) = segments.last() {
+      if last.level >= segment.level || last.number.is_range() {
+        cursor.rest = save;
+        break;
+      }
+    }
+    segments.push(segment);
+  }
+  (segments, cursor.rest)
+}
+
+/// 番号を先頭から読み進める
+struct Cursor<'a> {
+  rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+  fn new(rest: &'a str) -> Self {
+    Cursor { rest }
+  }
+
+  fn eat(&mut self, s: &str) -> bool {
+    match self.rest.strip_prefix(s) {
+      Some(rest) => {
+        self.rest = rest;
+        true
+      }
+      None => false,
+    }
+  }
+
+  /// 算用数字・全角数字・漢数字のいずれかで書かれた数
+  ///
+  /// `kansuji`が`false`の場合は漢数字を読まない。
+  fn number(&mut self, kansuji: bool) -> Option<usize> {
+    let first = self.rest.chars().next()?;
+    let class = |c: char| -> u8 {
+      if c.is_ascii_digit() {
+        1
+      } else if ('０'..='９').contains(&c) {
+        2
+      } else if kansuji && "〇一二三四五六七八九十百千".contains(c) {
+        3
       } else {
-        let kansuji = Kansuji::try_from(s).ok()?;
-        let n: u128 = kansuji.into();
-        eda_numbers.push(n as usize)
+        0
       }
+    };
+    let cls = class(first);
+    if cls == 0 {
+      return None;
+    }
+    let len = self
+      .rest
+      .char_indices()
+      .find(|(_, c)| class(*c) != cls)
+      .map_or(self.rest.len(), |(i, _)| i);
+    let (s, rest) = self.rest.split_at(len);
+    let n = match cls {
+      1 => s.parse().ok()?,
+      2 => parse_zenkaku_num(s)?,
+      _ => parse_kansuji(s)?,
+    };
+    self.rest = rest;
+    Some(n)
+  }
+
+  /// `の二の三`のような枝番号
+  fn eda_numbers(&mut self, kansuji: bool) -> Vec<usize> {
+    let mut v = Vec::new();
+    loop {
+      let save = self.rest;
+      if !(self.eat("の") || self.eat("ノ")) {
+        break;
+      }
+      match self.number(kansuji) {
+        Some(n) => v.push(n),
+        None => {
+          self.rest = save;
+          break;
+        }
+      }
+    }
+    v
+  }
+
+  /// `第十条の二`のような番号一つ
+  fn segment(&mut self) -> Option<CitationSegment> {
+    let save = self.rest;
+    let segment = (|| {
+      self.eat("第");
+      let base_number = self.number(true)?;
+      let level = ArticleLevel::from_suffix(self.rest.chars().next()?)?;
+      self.rest = &self.rest[level.suffix().len_utf8()..];
+      let eda_numbers = self.eda_numbers(true);
+      Some(CitationSegment {
+        level,
+        number: ArticleNumber {
+          base_number,
+          eda_numbers,
+          range_end_numbers: Vec::new(),
+        },
+      })
+    })();
+    if segment.is_none() {
+      self.rest = save;
     }
-    let mut num_str = base_number.to_string();
-    for n in eda_numbers.iter() {
-      num_str.push_str(&format!("_{n}"))
+    segment
+  }
+
+  /// `第十条から第十二条の二まで`のような範囲を含む番号一つ
+  fn segment_with_range(&mut self) -> Option<CitationSegment> {
+    let mut segment = self.segment()?;
+    let save = self.rest;
+    if self.eat("から") {
+      match self.segment() {
+        Some(end) if end.level == segment.level && self.eat("まで") => {
+          segment.number.range_end_numbers = vec![end.number.base_number];
+          segment
+            .number
+            .range_end_numbers
+            .extend(end.number.eda_numbers);
+        }
+        _ => self.rest = save,
+      }
     }
+    Some(segment)
+  }
+
+  /// `２の２`や`２から４まで`のような段落番号
+  fn paragraph_number(&mut self) -> Option<ArticleNumber> {
+    let base_number = self.number(false)?;
+    let eda_numbers = self.eda_numbers(false);
     let mut range_end_numbers = Vec::new();
-    if let Some(arabic_num) = caps.name("arabic_num_2") {
-      range_end_numbers.push(arabic_num.as_str().parse::<usize>().unwrap())
-    } else if let Some(zenkaku_num) = caps.name("zenkaku_num_2") {
-      range_end_numbers.push(parse_zenkaku_num(zenkaku_num.as_str())?)
-    } else if let Some(kansuji) = caps.name("kansuji_2") {
-      let kansuji = Kansuji::try_from(kansuji.as_str()).ok()?;
-      let n: u128 = kansuji.into();
-      range_end_numbers.push(n as usize)
-    };
-    if let Some(s) = &caps.name("eda_str_2") {
-      let eda_numbers_2 = s
-        .as_str()
-        .split('の')
-        .filter(|s| !s.is_empty())
-        .collect::<Vec<_>>();
-      for s in eda_numbers_2.iter() {
-        if let Ok(n) = s.parse::<usize>() {
+    let save = self.rest;
+    if self.eat("から") {
+      match self.number(false) {
+        Some(n) => {
           range_end_numbers.push(n);
-        } else if let Some(n) = parse_zenkaku_num(s) {
-          range_end_numbers.push(n);
-        } else {
-          let kansuji = Kansuji::try_from(*s).ok()?;
-          let n: u128 = kansuji.into();
-          range_end_numbers.push(n as usize);
+          range_end_numbers.extend(self.eda_numbers(false));
+          if !self.eat("まで") {
+            range_end_numbers.clear();
+            self.rest = save;
+          }
         }
+        None => self.rest = save,
       }
     }
     Some(ArticleNumber {
       base_number,
-      eda_numbers: eda_numbers.clone(),
+      eda_numbers,
       range_end_numbers,
     })
-  } else if let Some(caps) = re_paragraph.captures(str) {
-    // 段落
-    let base_number = if let Ok(n) = &caps["num"].parse::<usize>() {
-      *n
-    } else {
-      parse_zenkaku_num(&caps["num"]).unwrap()
-    };
-    let eda_numbers = &caps["eda_str"]
-      .split('の')
-      .filter(|s| !s.is_empty())
-      .map(|s| {
-        if let Ok(n) = s.parse::<usize>() {
-          n
-        } else {
-          parse_zenkaku_num(s).unwrap()
+  }
+
+  /// `イ`や`（１）`のような号の番号一つ
+  fn item_number(&mut self) -> Option<(ItemPattern, usize)> {
+    use ItemPattern::*;
+    let save = self.rest;
+    let paren = self.eat("（");
+    let number = (|| {
+      let class = |c: char| -> u8 {
+        match c {
+          'ア'..='ン' => 1,
+          'あ'..='ん' => 2,
+          _ if "一二三四五六七八九十百千".contains(c) => 3,
+          '０'..='９' => 4,
+          'Ａ'..='Ｚ' => 5,
+          'ａ'..='ｚ' => 6,
+          _ => 0,
         }
-      })
-      .collect::<Vec<usize>>();
-    let mut range_end_numbers = Vec::new();
-    if let Some(m) = caps.name("num_2") {
-      if let Ok(n) = m.as_str().parse::<usize>() {
-        range_end_numbers.push(n)
+      };
+      let cls = class(self.rest.chars().next()?);
+      if cls == 0 {
+        return None;
+      }
+      // いろはは一文字で、続く`の`や`から`と区別できないため一文字だけ読む
+      let len = if cls <= 2 {
+        self.rest.chars().next()?.len_utf8()
+      } else {
+        self
+          .rest
+          .char_indices()
+          .find(|(_, c)| class(*c) != cls)
+          .map_or(self.rest.len(), |(i, _)| i)
+      };
+      let (s, rest) = self.rest.split_at(len);
+      let is_roman = s.chars().any(|c| "ｉｘｖｌｃＩＸＶＬＣ".contains(c));
+      let (pattern, paren_pattern, n) = match cls {
+        1 => (
+          NoParenIrohaKatakana,
+          ParenIrohaKatakana,
+          parse_iroha_katakana(s)?,
+        ),
+        2 => (
+          NoParenIrohaHiragana,
+          ParenIrohaHiragana,
+          parse_iroha_hiragana(s)?,
+        ),
+        3 => (NoParenKansuji, ParenKansuji, parse_kansuji(s)?),
+        4 => (NoParenZenkakuNum, ParenZenkakuNum, parse_zenkaku_num(s)?),
+        5 if is_roman => (
+          NoParenZenkakuRomanUpper,
+          ParenZenkakuRomanUpper,
+          parse_roman(s)?,
+        ),
+        5 => (
+          NoParenZenkakuUpper,
+          ParenZenkakuUpper,
+          parse_zenkaku_alphabet(s)?,
+        ),
+        _ if is_roman => (
+          NoParenZenkakuRomanLower,
+          ParenZenkakuRomanLower,
+          parse_roman(s)?,
+        ),
+        _ => (
+          NoParenZenkakuLower,
+          ParenZenkakuLower,
+          parse_zenkaku_alphabet(s)?,
+        ),
+      };
+      self.rest = rest;
+      if !paren {
+        Some((pattern, n))
+      } else if self.eat("）") {
+        Some((paren_pattern, n))
       } else {
-        range_end_numbers.push(parse_zenkaku_num(m.as_str())?);
+        None
       }
+    })();
+    if number.is_none() {
+      self.rest = save;
     }
-    Some(ArticleNumber {
-      base_number,
-      eda_numbers: eda_numbers.clone(),
-      range_end_numbers,
-    })
-  } else {
-    None
+    number
   }
+
+  /// `の二`や`の（２）`のような号の枝番号
+  fn item_eda_numbers(&mut self) -> Vec<usize> {
+    let mut v = Vec::new();
+    loop {
+      let save = self.rest;
+      if !self.eat("の") {
+        break;
+      }
+      match self.item_number() {
+        Some((_, n)) => v.push(n),
+        None => {
+          self.rest = save;
+          break;
+        }
+      }
+    }
+    v
+  }
+}
+
+/// 文字列の先頭にある編・章・節・款・目・条・項・号の番号か段落番号を読む
+pub fn parse_article_number(str: &str) -> Option<ArticleNumber> {
+  Cursor::new(str)
+    .segment_with_range()
+    .map(|segment| segment.number)
+    .or_else(|| Cursor::new(str).paragraph_number())
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
@@ -433,102 +802,19 @@ impl ItemPattern {
   }
 }
 
-fn parse_item_number_base(str: &str) -> Option<(ItemPattern, usize)> {
-  use ItemPattern::*;
-  let re_item = Regex::new(r"^(?<str>(（((?<paren_iroha_katakana>[ア-ン]+)|(?<paren_iroha_hiragana>[あ-ん]+)|(?<paren_kansuji>[一二三四五六七八九十百千]+)|(?<paren_zenkaku_num>[０-９]+)|(?<paren_zenkaku_upper>[Ａ-Ｚ]+)|(?<paren_zenkaku_lower>[ａ-ｚ]+))）|((?<no_paren_iroha_katakana>[ア-ン]+)|(?<no_paren_iroha_hiragana>[あ-ん]+)|(?<no_paren_kansuji>[一二三四五六七八九十百千]+)|(?<no_paren_zenkaku_num>[０-９]+)|(?<no_paren_zenkaku_upper>[Ａ-Ｚ]+)|(?<no_paren_zenkaku_lower>[ａ-ｚ]+))))").unwrap();
-  let re_is_roman = Regex::new(r"[ixvlcIXVLCｉｘｖｌｃＩＸＶＬＣ]+").unwrap();
-  if let Some(caps) = re_item.captures(str) {
-    let (pattern, number) = if let Some(s) = caps.name("paren_iroha_katakana") {
-      (
-        ParenIrohaKatakana,
-        parse_iroha_katakana(s.as_str()).unwrap(),
-      )
-    } else if let Some(s) = caps.name("paren_iroha_hiragana") {
-      (
-        ParenIrohaHiragana,
-        parse_iroha_hiragana(s.as_str()).unwrap(),
-      )
-    } else if let Some(s) = caps.name("paren_kansuji") {
-      let kansuji = Kansuji::try_from(s.as_str()).unwrap();
-      let n: u128 = kansuji.into();
-      (ParenKansuji, n as usize)
-    } else if let Some(s) = caps.name("paren_zenkaku_num") {
-      (ParenZenkakuNum, parse_zenkaku_num(s.as_str()).unwrap())
-    } else if let Some(s) = caps.name("paren_zenkaku_upper") {
-      if re_is_roman.is_match(s.as_str()) {
-        (ParenZenkakuRomanUpper, parse_roman(s.as_str()).unwrap())
-      } else {
-        (
-          ParenZenkakuUpper,
-          parse_zenkaku_alphabet(s.as_str()).unwrap(),
-        )
-      }
-    } else if let Some(s) = caps.name("paren_zenkaku_lower") {
-      if re_is_roman.is_match(s.as_str()) {
-        (ParenZenkakuRomanLower, parse_roman(s.as_str()).unwrap())
-      } else {
-        (
-          ParenZenkakuLower,
-          parse_zenkaku_alphabet(s.as_str()).unwrap(),
-        )
-      }
-    }
-    // 括弧なし
-    else if let Some(s) = caps.name("no_paren_iroha_katakana") {
-      (
-        NoParenIrohaKatakana,
-        parse_iroha_katakana(s.as_str()).unwrap(),
-      )
-    } else if let Some(s) = caps.name("no_paren_iroha_hiragana") {
-      (
-        NoParenIrohaHiragana,
-        parse_iroha_hiragana(s.as_str()).unwrap(),
-      )
-    } else if let Some(s) = caps.name("no_paren_kansuji") {
-      let kansuji = Kansuji::try_from(s.as_str()).unwrap();
-      let n: u128 = kansuji.into();
-      (NoParenKansuji, n as usize)
-    } else if let Some(s) = caps.name("no_paren_zenkaku_num") {
-      (NoParenZenkakuNum, parse_zenkaku_num(s.as_str()).unwrap())
-    } else if let Some(s) = caps.name("no_paren_zenkaku_upper") {
-      if re_is_roman.is_match(s.as_str()) {
-        (NoParenZenkakuRomanUpper, parse_roman(s.as_str()).unwrap())
-      } else {
-        (
-          NoParenZenkakuUpper,
-          parse_zenkaku_alphabet(s.as_str()).unwrap(),
-        )
-      }
-    } else if let Some(s) = caps.name("no_paren_zenkaku_lower") {
-      if re_is_roman.is_match(s.as_str()) {
-        (NoParenZenkakuRomanLower, parse_roman(s.as_str()).unwrap())
-      } else {
-        (
-          NoParenZenkakuLower,
-          parse_zenkaku_alphabet(s.as_str()).unwrap(),
-        )
-      }
-    } else {
-      unreachable!()
-    };
-    Some((pattern, number))
-  } else {
-    None
-  }
-}
-
+/// 行頭の番号と、空白で区切られた残りの本文に分ける
 pub fn split_number(str: &str) -> Option<(String, String)> {
-  let re = Regex::new(r"^(?<s>[^\s　]+)[\s　]*(?<text>.+)$").unwrap();
-  re.captures(str).map(|caps| {
-    let s = &caps["s"];
-    let text = &caps["text"];
-    (s.to_string(), text.to_string())
-  })
+  let (s, text) = str.split_once(|c: char| c.is_whitespace())?;
+  let text = text.trim_start();
+  if s.is_empty() || text.is_empty() {
+    return None;
+  }
+  Some((s.to_string(), text.to_string()))
 }
 
 #[test]
 fn check_parse_article_number() {
-  let cases = vec![
+  let cases = [
     (
       "第一条",
       ArticleNumber {
@@ -553,62 +839,243 @@ fn check_parse_article_number() {
         range_end_numbers: Vec::new(),
       },
     ),
+    (
+      "第三条の二第二項",
+      ArticleNumber {
+        base_number: 3,
+        eda_numbers: vec![2],
+        range_end_numbers: Vec::new(),
+      },
+    ),
+    (
+      "２の２",
+      ArticleNumber {
+        base_number: 2,
+        eda_numbers: vec![2],
+        range_end_numbers: Vec::new(),
+      },
+    ),
+    (
+      "２から４まで",
+      ArticleNumber {
+        base_number: 2,
+        eda_numbers: Vec::new(),
+        range_end_numbers: vec![4],
+      },
+    ),
   ];
   for (s, a) in cases.iter() {
     let r = parse_article_number(s);
     assert_eq!(r, Some(a.clone()))
   }
+  assert_eq!(parse_article_number("一"), None);
+}
+
+#[test]
+fn check_article_number_from_text() {
+  let cases = [
+    ("第十条", "10"),
+    ("第十条の二", "10_2"),
+    ("第10条ノ2", "10_2"),
+    ("第１０条の２", "10_2"),
+    ("第千二百三十四条", "1234"),
+    ("第十条から第十二条まで", "10:12"),
+    ("第十条の二から第十二条の三まで", "10_2:12_3"),
+    ("第三項", "3"),
+    ("第二号", "2"),
+  ];
+  for (s, num_str) in cases {
+    let n = ArticleNumber::from_text(s).unwrap();
+    assert_eq!(n, ArticleNumber::from_num_str(num_str).unwrap());
+  }
+  let n = ArticleNumber::from_text("第十条の二から第十二条の三まで").unwrap();
+  assert_eq!(n.article_text(), "第十条の二から第十二条の三まで");
+  assert!(ArticleNumber::from_text("第十条第二項").is_err());
+  assert!(ArticleNumber::from_text("十").is_err());
+  assert!(ArticleNumber::from_text("第十").is_err());
+}
+
+#[test]
+fn check_article_path() {
+  let path = ArticlePath::from_str("第三条の二第二項第一号").unwrap();
+  assert_eq!(
+    path.segments,
+    vec![
+      CitationSegment {
+        level: ArticleLevel::Article,
+        number: ArticleNumber::from_num_str("3_2").unwrap(),
+      },
+      CitationSegment {
+        level: ArticleLevel::Paragraph,
+        number: ArticleNumber::from_num_str("2").unwrap(),
+      },
+      CitationSegment {
+        level: ArticleLevel::Item,
+        number: ArticleNumber::from_num_str("1").unwrap(),
+      },
+    ]
+  );
+  assert_eq!(path.to_string(), "第三条の二第二項第一号");
+  let path = ArticlePath::from_str("第一編第二章第三節第四款第五目").unwrap();
+  assert_eq!(path.segments.len(), 5);
+  assert_eq!(path.to_string(), "第一編第二章第三節第四款第五目");
+  let path = ArticlePath::from_str("第三条第二項から第四項まで").unwrap();
+  assert_eq!(
+    path.segments[1].number,
+    ArticleNumber::from_num_str("2:4").unwrap()
+  );
+  assert_eq!(path.to_string(), "第三条第二項から第四項まで");
+  assert!(ArticlePath::from_str("第二項第三条").is_err());
+  assert!(ArticlePath::from_str("第三条から第四項まで").is_err());
+  assert!(ArticlePath::from_str("第三条の規定").is_err());
+  assert!(ArticlePath::from_str("").is_err());
+}
+
+#[test]
+fn check_article_number_ord() {
+  let n = |s: &str| ArticleNumber::from_num_str(s).unwrap();
+  let mut v = vec![n("4"), n("3_2_2"), n("3"), n("3_2"), n("3:5"), n("10")];
+  v.sort();
+  assert_eq!(
+    v,
+    vec![n("3"), n("3:5"), n("3_2"), n("3_2_2"), n("4"), n("10")]
+  );
+  assert_eq!(v.binary_search(&n("3_2_2")), Ok(3));
 }
 
+#[test]
+fn check_article_number_range() {
+  let n = |s: &str| ArticleNumber::from_num_str(s).unwrap();
+  let range = n("3:5");
+  assert!(range.contains(&n("3")));
+  assert!(range.contains(&n("4_2")));
+  assert!(range.contains(&n("5")));
+  assert!(!range.contains(&n("5_2")));
+  assert!(!range.contains(&n("2")));
+  assert!(range.contains(&n("3_2:4")));
+  assert!(!range.contains(&n("4:6")));
+  assert!(n("3").contains(&n("3")));
+  assert!(!n("3").contains(&n("3_2")));
+  let articles = [n("2"), n("3"), n("3_2"), n("5"), n("5_2"), n("6")];
+  let selected = range.select(articles.iter(), |a| a).collect::<Vec<_>>();
+  assert_eq!(selected, vec![&n("3"), &n("3_2"), &n("5")]);
+  assert_eq!(n("3").next_branch(), n("3_2"));
+  assert_eq!(n("3_2").next_branch(), n("3_2_2"));
+  assert_eq!(n("3").next_sibling(), n("4"));
+  assert_eq!(n("3_2").next_sibling(), n("3_3"));
+  assert_eq!(n("3:5").next_sibling(), n("4"));
+}
+
+/// `一`や`（１）`、`イの二`、`一から三まで`のような号の番号を読む
 pub fn parse_item_number(str: &str) -> Option<ItemNumber> {
-  let re_item = Regex::new(r"^(?<base_number>(（(([ア-ン]+)|([あ-ん]+)|([一二三四五六七八九十百千]+)|([０-９]+)|([Ａ-Ｚ]+)|([ａ-ｚ]+))）|(([ア-ン]+)|([あ-ん]+)|([一二三四五六七八九十百千]+)|([０-９]+)|([Ａ-Ｚ]+)|([ａ-ｚ]+))))(?<eda_numbers>(の(（(([ア-ン]+)|([あ-ん]+)|([一二三四五六七八九十百千]+)|([０-９]+)|([Ａ-Ｚ]+)|([ａ-ｚ]+))）|(([ア-ン]+)|([あ-ん]+)|([一二三四五六七八九十百千]+)|([０-９]+)|([Ａ-Ｚ]+)|([ａ-ｚ]+))))+)?(から(?<base_number2>(（(([ア-ン]+)|([あ-ん]+)|([一二三四五六七八九十百千]+)|([０-９]+)|([Ａ-Ｚ]+)|([ａ-ｚ]+))）|(([ア-ン]+)|([あ-ん]+)|([一二三四五六七八九十百千]+)|([０-９]+)|([Ａ-Ｚ]+)|([ａ-ｚ]+))))(?<eda_numbers2>(の(（(([ア-ン]+)|([あ-ん]+)|([一二三四五六七八九十百千]+)|([０-９]+)|([Ａ-Ｚ]+)|([ａ-ｚ]+))）|(([ア-ン]+)|([あ-ん]+)|([一二三四五六七八九十百千]+)|([０-９]+)|([Ａ-Ｚ]+)|([ａ-ｚ]+))))+)?まで)?$").unwrap();
-  if let Some(caps) = re_item.captures(str.trim()) {
-    println!("ok!!!");
-    if let Some((pat, base_number)) = parse_item_number_base(&caps["base_number"]) {
-      let eda_numbers = if let Some(m) = &caps.name("eda_numbers") {
-        m.as_str()
-          .split('の')
-          .filter(|s| !s.is_empty())
-          .map(parse_item_number_base)
-          .filter(|v| v.is_some())
-          .map(|v| v.unwrap().1)
-          .collect::<Vec<_>>()
-      } else {
-        Vec::new()
-      };
-      let mut range_end_numbers = Vec::new();
-      if let Some(m) = &caps.name("base_number_2") {
-        if let Some((_, base_number2)) = parse_item_number_base(m.as_str()) {
-          range_end_numbers.push(base_number2);
+  let mut cursor = Cursor::new(str.trim());
+  let (pattern, base_number) = cursor.item_number()?;
+  let eda_numbers = cursor.item_eda_numbers();
+  let mut range_end_numbers = Vec::new();
+  let save = cursor.rest;
+  if cursor.eat("から") {
+    match cursor.item_number() {
+      Some((_, n)) => {
+        range_end_numbers.push(n);
+        range_end_numbers.extend(cursor.item_eda_numbers());
+        if !cursor.eat("まで") {
+          range_end_numbers.clear();
+          cursor.rest = save;
         }
       }
-      if let Some(m) = &caps.name("eda_number_2") {
-        let eda_numbers2 = &m
-          .as_str()
-          .split('の')
-          .filter(|s| !s.is_empty())
-          .map(parse_item_number_base)
-          .filter(|v| v.is_some())
-          .map(|v| v.unwrap().1)
-          .collect::<Vec<_>>();
-        for v in eda_numbers2.iter() {
-          range_end_numbers.push(*v)
+      None => cursor.rest = save,
+    }
+  }
+  if !cursor.rest.is_empty() {
+    return None;
+  }
+  Some(ItemNumber {
+    str: str.to_string(),
+    base_number,
+    eda_numbers,
+    range_end_numbers,
+    pattern,
+  })
+}
+
+#[test]
+fn check_parse_item_number() {
+  let n = parse_item_number("イ").unwrap();
+  assert_eq!(
+    (n.pattern, n.base_number),
+    (ItemPattern::NoParenIrohaKatakana, 1)
+  );
+  let n = parse_item_number("（１）").unwrap();
+  assert_eq!(
+    (n.pattern, n.base_number),
+    (ItemPattern::ParenZenkakuNum, 1)
+  );
+  let n = parse_item_number("十二の二").unwrap();
+  assert_eq!((n.base_number, n.eda_numbers), (12, vec![2]));
+  let n = parse_item_number("ろの二").unwrap();
+  assert_eq!((n.base_number, n.eda_numbers), (2, vec![2]));
+  let n = parse_item_number("一から三まで").unwrap();
+  assert_eq!((n.base_number, n.range_end_numbers), (1, vec![3]));
+  let n = parse_item_number("いからはまで").unwrap();
+  assert_eq!((n.base_number, n.range_end_numbers), (1, vec![3]));
+  let n = parse_item_number("一の二から三の四まで").unwrap();
+  assert_eq!(n.range_end_numbers, vec![3, 4]);
+  let n = parse_item_number("（ｉｉ）").unwrap();
+  assert_eq!(
+    (n.pattern, n.base_number),
+    (ItemPattern::ParenZenkakuRomanLower, 2)
+  );
+  let n = parse_item_number("（ｉｖ）").unwrap();
+  assert_eq!(
+    (n.pattern, n.base_number),
+    (ItemPattern::ParenZenkakuRomanLower, 4)
+  );
+  let n = parse_item_number("ＩＶ").unwrap();
+  assert_eq!(
+    (n.pattern, n.base_number),
+    (ItemPattern::NoParenZenkakuRomanUpper, 4)
+  );
+  let n = parse_item_number("ｖｉｉ").unwrap();
+  assert_eq!(
+    (n.pattern, n.base_number),
+    (ItemPattern::NoParenZenkakuRomanLower, 7)
+  );
+  for s in ["アイ", "ン", "（ン）", "（１", "第一条", "一から"] {
+    assert_eq!(parse_item_number(s), None, "{s}");
+  }
+}
+
+/// `八十九`のような漢数字と`八九`のような位取りの漢数字を数値にする
+pub(crate) fn parse_kansuji(s: &str) -> Option<usize> {
+  fn digit(c: char) -> Option<usize> {
+    "〇一二三四五六七八九".chars().position(|d| d == c)
+  }
+  if s.is_empty() {
+    return None;
+  }
+  if s.chars().all(|c| digit(c).is_some()) {
+    return s
+      .chars()
+      .try_fold(0usize, |n, c| n.checked_mul(10)?.checked_add(digit(c)?));
+  }
+  let mut total = 0;
+  let mut current: Option<usize> = None;
+  for c in s.chars() {
+    let unit = match c {
+      '十' => 10,
+      '百' => 100,
+      '千' => 1000,
+      _ => {
+        if current.is_some() {
+          return None;
         }
+        current = Some(digit(c)?);
+        continue;
       }
-      Some(ItemNumber {
-        str: str.to_string(),
-        base_number,
-        eda_numbers,
-        range_end_numbers,
-        pattern: pat,
-      })
-    } else {
-      None
-    }
-  } else {
-    println!("err!!");
-    None
+    };
+    total += current.take().unwrap_or(1) * unit;
   }
+  Some(total + current.unwrap_or(0))
 }
 
 fn parse_zenkaku_num(str: &str) -> Option<usize> {
@@ -857,14 +1324,17 @@ fn parse_roman(str: &str) -> Option<usize> {
   roman::from(
     &str
       .replace('i', "I")
+      .replace('v', "V")
       .replace('x', "X")
       .replace('l', "L")
       .replace('c', "C")
       .replace('ｉ', "I")
+      .replace('ｖ', "V")
       .replace('ｘ', "X")
       .replace('ｌ', "L")
       .replace('ｃ', "C")
       .replace('Ｉ', "I")
+      .replace('Ｖ', "V")
       .replace('Ｘ', "X")
       .replace('Ｌ', "L")
       .replace('Ｃ', "C"),
@@ -875,6 +1345,7 @@ fn parse_roman(str: &str) -> Option<usize> {
 fn to_zenkaku_roman_upper(n: usize) -> Option<String> {
   roman::to(n as i32).map(|s| {
     s.replace('I', "Ｉ")
+      .replace('V', "Ｖ")
       .replace('X', "Ｘ")
       .replace('L', "Ｌ")
       .replace('C', "Ｃ")
@@ -884,6 +1355,7 @@ fn to_zenkaku_roman_upper(n: usize) -> Option<String> {
 fn to_zenkaku_roman_lower(n: usize) -> Option<String> {
   roman::to(n as i32).map(|s| {
     s.replace('I', "ｉ")
+      .replace('V', "ｖ")
       .replace('X', "ｘ")
       .replace('L', "ｌ")
       .replace('C', "ｃ")
@@ -21,30 +21,31 @@ pub struct Class {
 }
 
 impl Parser for Class {
-  fn parser(element: &Element) -> Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> Result<Self> {
     if element.name.as_str() == "Class" {
       let num = get_attribute(element, "Num")?;
       let mut class_title = None;
       let mut class_sentence_opt = None;
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "ClassTitle" => {
-              class_title = Some(Text::from_children(&e.children));
+              class_title = Some(Text::from_reader(reader, &e)?);
             }
             "ClassSentence" => {
-              let v = SentenceOrColumnOrTable::from_element(e)?;
+              let v = SentenceOrColumnOrTable::from_element(reader, &e)?;
               class_sentence_opt = Some(v);
             }
             "Item" => {
-              let v = Item::parser(e)?;
+              let v = Item::parser(reader, &e)?;
               children.push(v)
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(class_sentence) = class_sentence_opt {
         Ok(Class {
           class_title,
@@ -112,29 +113,30 @@ impl ToXmlElementWithName for SentenceOrColumnOrTable {
 }
 
 impl SentenceOrColumnOrTable {
-  pub(crate) fn from_element(element: &Element) -> Result<Self> {
+  pub(crate) fn from_element(reader: &mut XmlReader, element: &Tag) -> Result<Self> {
     let mut sentence_opt = None;
     let mut sentence_list = Vec::new();
     let mut column_list = Vec::new();
-    for node in element.children.iter() {
-      if let XMLNode::Element(e) = node {
+    reader.each_child(element, |reader, node| {
+      if let Node::Element(e) = node {
         match e.name.as_str() {
           "Sentence" => {
-            let v = Sentence::parser(e)?;
+            let v = Sentence::parser(reader, &e)?;
             sentence_list.push(v);
           }
           "Column" => {
-            let v = Column::parser(e)?;
+            let v = Column::parser(reader, &e)?;
             column_list.push(v);
           }
           "Table" => {
-            let v = Table::parser(e)?;
+            let v = Table::parser(reader, &e)?;
             sentence_opt = Some(SentenceOrColumnOrTable::Table(v));
           }
           s => return Err(Error::unexpected_tag(element, s)),
         }
       }
-    }
+      Ok(())
+    })?;
     if !sentence_list.is_empty() {
       sentence_opt = Some(SentenceOrColumnOrTable::Sentence(sentence_list.clone()));
     }
@@ -167,8 +169,8 @@ impl Caption {
 }
 
 impl Parser for Caption {
-  fn parser(element: &Element) -> Result<Self> {
-    let text = text::Text::from_children(&element.children);
+  fn parser(reader: &mut XmlReader, element: &Tag) -> Result<Self> {
+    let text = text::Text::from_reader(reader, element)?;
     let common_caption = get_attribute_opt_with_parse::<bool>(element, "CommonCaption")?;
     Ok(Caption::new(text, common_caption))
   }
@@ -195,18 +197,19 @@ pub struct Column {
 }
 
 impl Parser for Column {
-  fn parser(element: &Element) -> Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> Result<Self> {
     if element.name.as_str() == "Column" {
       let num = get_attribute_opt_with_parse(element, "Num")?;
       let line_break = get_attribute_opt_with_parse(element, "LineBreak")?.unwrap_or(false);
       let align = Align::from_attribute(element.attributes.get("Align"));
       let mut sentence = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
-          let v = Sentence::parser(e)?;
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
+          let v = Sentence::parser(reader, &e)?;
           sentence.push(v);
         }
-      }
+        Ok(())
+      })?;
       Ok(Column {
         sentence,
         num,
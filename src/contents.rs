@@ -25,141 +25,142 @@ pub struct Contents {
 }
 
 impl parser::Parser for Contents {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     let mut lst = Vec::new();
-    for node in element.children.iter() {
-      if let XMLNode::Element(e) = node {
+    reader.each_child(element, |reader, node| {
+      if let Node::Element(e) = node {
         match e.name.as_str() {
           "Table" => {
-            let v = Table::parser(e)?;
+            let v = Table::parser(reader, &e)?;
             lst.push(ContentsElement::Table(v));
           }
           "TableStruct" => {
-            let v = TableStruct::parser(e)?;
+            let v = TableStruct::parser(reader, &e)?;
             lst.push(ContentsElement::TableStruct(v));
           }
           "Fig" => {
-            let v = Fig::parser(e)?;
+            let v = Fig::parser(reader, &e)?;
             lst.push(ContentsElement::Fig(v));
           }
           "FigStruct" => {
-            let v = FigStruct::parser(e)?;
+            let v = FigStruct::parser(reader, &e)?;
             lst.push(ContentsElement::FigStruct(v));
           }
           "QuoteStruct" => {
-            let v = QuoteStruct::parser(e)?;
+            let v = QuoteStruct::parser(reader, &e)?;
             lst.push(ContentsElement::QuoteStruct(v));
           }
           "NoteStruct" => {
-            let v = NoteStruct::parser(e)?;
+            let v = NoteStruct::parser(reader, &e)?;
             lst.push(ContentsElement::NoteStruct(v));
           }
           "StyleStruct" => {
-            let v = StyleStruct::parser(e)?;
+            let v = StyleStruct::parser(reader, &e)?;
             lst.push(ContentsElement::StyleStruct(v));
           }
           "AppdxTable" => {
-            let v = AppdxTable::parser(e)?;
+            let v = AppdxTable::parser(reader, &e)?;
             lst.push(ContentsElement::AppdxTable(v));
           }
           "Ruby" => {
-            let v = Ruby::parser(e)?;
+            let v = Ruby::parser(reader, &e)?;
             lst.push(ContentsElement::Ruby(v));
           }
           "Line" => {
-            let v = Line::parser(e)?;
+            let v = Line::parser(reader, &e)?;
             lst.push(ContentsElement::Line(v));
           }
           "Sup" => {
-            let v = Sup::parser(e)?;
+            let v = Sup::parser(reader, &e)?;
             lst.push(ContentsElement::Sup(v));
           }
           "Sub" => {
-            let v = Sub::parser(e)?;
+            let v = Sub::parser(reader, &e)?;
             lst.push(ContentsElement::Sub(v));
           }
           "Paragraph" => {
-            let v = Paragraph::parser(e)?;
+            let v = Paragraph::parser(reader, &e)?;
             lst.push(ContentsElement::Paragraph(v));
           }
           "Item" => {
-            let v = Item::parser(e)?;
+            let v = Item::parser(reader, &e)?;
             lst.push(ContentsElement::Item(v));
           }
           "Subitem1" => {
-            let v = Subitem1::parser(e)?;
+            let v = Subitem1::parser(reader, &e)?;
             lst.push(ContentsElement::Subitem1(v));
           }
           "Subitem2" => {
-            let v = Subitem2::parser(e)?;
+            let v = Subitem2::parser(reader, &e)?;
             lst.push(ContentsElement::Subitem2(v));
           }
           "Subitem3" => {
-            let v = Subitem3::parser(e)?;
+            let v = Subitem3::parser(reader, &e)?;
             lst.push(ContentsElement::Subitem3(v));
           }
           "Subitem4" => {
-            let v = Subitem4::parser(e)?;
+            let v = Subitem4::parser(reader, &e)?;
             lst.push(ContentsElement::Subitem4(v));
           }
           "Subitem5" => {
-            let v = Subitem5::parser(e)?;
+            let v = Subitem5::parser(reader, &e)?;
             lst.push(ContentsElement::Subitem5(v));
           }
           "Subitem6" => {
-            let v = Subitem6::parser(e)?;
+            let v = Subitem6::parser(reader, &e)?;
             lst.push(ContentsElement::Subitem6(v));
           }
           "Subitem7" => {
-            let v = Subitem7::parser(e)?;
+            let v = Subitem7::parser(reader, &e)?;
             lst.push(ContentsElement::Subitem7(v));
           }
           "Subitem8" => {
-            let v = Subitem8::parser(e)?;
+            let v = Subitem8::parser(reader, &e)?;
             lst.push(ContentsElement::Subitem8(v));
           }
           "Subitem9" => {
-            let v = Subitem9::parser(e)?;
+            let v = Subitem9::parser(reader, &e)?;
             lst.push(ContentsElement::Subitem9(v));
           }
           "Subitem10" => {
-            let v = Subitem10::parser(e)?;
+            let v = Subitem10::parser(reader, &e)?;
             lst.push(ContentsElement::Subitem10(v));
           }
           "List" => {
-            let v = List::parser(e)?;
+            let v = List::parser(reader, &e)?;
             lst.push(ContentsElement::List(v));
           }
           "Sentence" => {
-            let v = Sentence::parser(e)?;
+            let v = Sentence::parser(reader, &e)?;
             lst.push(ContentsElement::Sentence(v));
           }
           "ArithFormula" => {
-            let v = ArithFormula::parser(e)?;
+            let v = ArithFormula::parser(reader, &e)?;
             lst.push(ContentsElement::ArithFormula(v));
           }
           "TOC" => {
-            let v = TOC::parser(e)?;
+            let v = TOC::parser(reader, &e)?;
             lst.push(ContentsElement::TOC(v));
           }
           "TOCSection" => {
-            let v = TOCSection::parser(e)?;
+            let v = TOCSection::parser(reader, &e)?;
             lst.push(ContentsElement::TOCSection(v));
           }
           "Remarks" => {
-            let v = Remarks::parser(e)?;
+            let v = Remarks::parser(reader, &e)?;
             lst.push(ContentsElement::Remarks(v));
           }
           "TableRow" => {
-            let v = TableRow::parser(e)?;
+            let v = TableRow::parser(reader, &e)?;
             lst.push(ContentsElement::TableRow(v));
           }
           s => return Err(Error::unexpected_tag(element, s)),
         }
-      } else if let XMLNode::Text(s) = node {
-        lst.push(ContentsElement::String(s.to_string()))
+      } else if let Node::Text(s) = node {
+        lst.push(ContentsElement::String(s))
       }
-    }
+      Ok(())
+    })?;
     Ok(Contents { contents: lst })
   }
 }
@@ -250,8 +251,12 @@ pub struct Style {
 }
 
 impl Parser for Style {
-  fn parser(element: &Element) -> result::Result<Self> {
-    Contents::parser(element).map(|c| Style { contentes: c })
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
+    if element.name.as_str() == "Style" {
+      Contents::parser(reader, element).map(|c| Style { contentes: c })
+    } else {
+      Err(result::Error::wrong_tag_name(element, "Style"))
+    }
   }
 }
 
@@ -267,8 +272,12 @@ pub struct Note {
 }
 
 impl Parser for Note {
-  fn parser(element: &Element) -> result::Result<Self> {
-    Contents::parser(element).map(|c| Note { contentes: c })
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
+    if element.name.as_str() == "Note" {
+      Contents::parser(reader, element).map(|c| Note { contentes: c })
+    } else {
+      Err(result::Error::wrong_tag_name(element, "Note"))
+    }
   }
 }
 
@@ -285,8 +294,12 @@ pub struct Format {
 }
 
 impl Parser for Format {
-  fn parser(element: &Element) -> result::Result<Self> {
-    Contents::parser(element).map(|c| Format { contentes: c })
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
+    if element.name.as_str() == "Format" {
+      Contents::parser(reader, element).map(|c| Format { contentes: c })
+    } else {
+      Err(result::Error::wrong_tag_name(element, "Format"))
+    }
   }
 }
 
@@ -304,14 +317,22 @@ pub struct ArithFormula {
 }
 
 impl Parser for ArithFormula {
-  fn parser(element: &Element) -> result::Result<Self> {
-    let num = get_attribute_opt_with_parse(element, "Num")?;
-    Contents::parser(element).map(|c| ArithFormula { num, contentes: c })
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
+    if element.name.as_str() == "ArithFormula" {
+      let num = get_attribute_opt_with_parse(element, "Num")?;
+      Contents::parser(reader, element).map(|c| ArithFormula { num, contentes: c })
+    } else {
+      Err(result::Error::wrong_tag_name(element, "ArithFormula"))
+    }
   }
 }
 
 impl ToXmlElement for ArithFormula {
   fn to_xml_element(&self) -> Element {
-    self.contentes.to_xml_element_with_name("ArithFormula")
+    let mut e = self.contentes.to_xml_element_with_name("ArithFormula");
+    if let Some(n) = self.num {
+      e.attributes.insert("Num".to_string(), n.to_string());
+    }
+    e
   }
 }
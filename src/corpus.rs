@@ -0,0 +1,302 @@
+//! e-Govの一括ダウンロードデータ
+//!
+//! e-Govの一括ダウンロードで配布されるzipファイル、またはそれを展開したディレクトリを読み込む。
+//! 法令ごとに`129AC0000000089_20230614_505AC0000000053/`のようなディレクトリがあり、
+//! その中に法令のXMLファイルと、`Fig`要素の`src`属性から参照される画像やPDFのファイルが入っている。
+//!
+//! ```no_run
+//! use japanese_law_xml_schema::corpus::Corpus;
+//!
+//! let corpus = Corpus::open("all_xml.zip").unwrap();
+//! for (entry, law) in corpus.laws() {
+//!   let law = law.unwrap();
+//!   println!("{}: {:?}", entry.law_id, law.law_body.law_title);
+//! }
+//! ```
+
+use crate::fig::Fig;
+use crate::input::zip_error;
+use crate::law::Law;
+use crate::law_id::{LawId, LawRevisionId};
+use crate::result::{Error, Result};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use zip::ZipArchive;
+
+/// 一括ダウンロードデータ中の法令一件
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CorpusEntry {
+  /// 法令のディレクトリ名
+  pub name: String,
+  /// 法令ID
+  pub law_id: LawId,
+  /// 法令履歴ID
+  ///
+  /// ディレクトリ名が法令履歴IDの形式でない場合は`None`になる。
+  pub revision_id: Option<LawRevisionId>,
+  /// XMLファイルのパス
+  pub xml_path: String,
+  /// 添付ファイルの、法令のディレクトリからの相対パス
+  pub attachments: Vec<String>,
+  /// 法令のディレクトリのパス
+  dir: String,
+}
+
+impl CorpusEntry {
+  /// XMLファイルのパスから作る
+  ///
+  /// 法令のディレクトリ名が法令IDで始まらない場合はエラーを返す。
+  fn new(xml_path: &str) -> Result<Self> {
+    let (dir, file_name) = match xml_path.rsplit_once('/') {
+      Some((dir, file_name)) => (dir, file_name),
+      None => ("", xml_path),
+    };
+    let name = match dir.rsplit_once('/') {
+      Some((_, name)) => name,
+      None if dir.is_empty() => file_name
+        .rsplit_once('.')
+        .map_or(file_name, |(stem, _)| stem),
+      None => dir,
+    };
+    let law_id = LawId::from_str(name.split('_').next().unwrap_or_default())?;
+    let revision_id = LawRevisionId::from_str(name).ok();
+    Ok(CorpusEntry {
+      name: name.to_string(),
+      law_id,
+      revision_id,
+      xml_path: xml_path.to_string(),
+      attachments: Vec::new(),
+      dir: dir.to_string(),
+    })
+  }
+
+  /// `Fig`要素の`src`属性の値から添付ファイルのパスを求める
+  fn attachment_path(&self, src: &str) -> Option<String> {
+    let src = src.trim_start_matches("./");
+    if self.attachments.iter().any(|s| s == src) {
+      if self.dir.is_empty() {
+        Some(src.to_string())
+      } else {
+        Some(format!("{}/{}", self.dir, src))
+      }
+    } else {
+      None
+    }
+  }
+}
+
+enum Source {
+  Zip(RefCell<ZipArchive<BufReader<File>>>),
+  Dir(PathBuf),
+}
+
+/// e-Govの一括ダウンロードデータ
+pub struct Corpus {
+  source: Source,
+  entries: Vec<CorpusEntry>,
+  skipped: Vec<String>,
+}
+
+impl Corpus {
+  /// zipファイルまたはディレクトリを開く
+  pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+    let path = path.as_ref();
+    if path.is_dir() {
+      Corpus::open_dir(path)
+    } else {
+      Corpus::open_zip(path)
+    }
+  }
+
+  /// zipファイルを開く
+  pub fn open_zip<P: AsRef<Path>>(path: P) -> Result<Self> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|error| file_io_error(path, error))?;
+    let archive = ZipArchive::new(BufReader::new(file)).map_err(zip_error)?;
+    let files = archive
+      .file_names()
+      .filter(|name| !name.ends_with('/'))
+      .map(|name| name.to_string())
+      .collect::<Vec<_>>();
+    let (entries, skipped) = entries(files);
+    Ok(Corpus {
+      source: Source::Zip(RefCell::new(archive)),
+      entries,
+      skipped,
+    })
+  }
+
+  /// zipファイルを展開したディレクトリを開く
+  pub fn open_dir<P: AsRef<Path>>(path: P) -> Result<Self> {
+    let path = path.as_ref();
+    let mut files = Vec::new();
+    walk_dir(path, "", &mut files)?;
+    let (entries, skipped) = entries(files);
+    Ok(Corpus {
+      source: Source::Dir(path.to_path_buf()),
+      entries,
+      skipped,
+    })
+  }
+
+  /// 法令の一覧
+  pub fn entries(&self) -> &[CorpusEntry] {
+    &self.entries
+  }
+
+  /// 法令IDで始まらないディレクトリにあったため、法令として読み込まなかったXMLファイルのパス
+  pub fn skipped(&self) -> &[String] {
+    &self.skipped
+  }
+
+  /// 法令IDかディレクトリ名が一致する法令を探す
+  pub fn find(&self, id: &str) -> impl Iterator<Item = &CorpusEntry> {
+    let law_id = LawId::from_str(id).ok();
+    let id = id.to_string();
+    self
+      .entries
+      .iter()
+      .filter(move |entry| law_id.as_ref() == Some(&entry.law_id) || entry.name == id)
+  }
+
+  /// 法令のXMLファイルを読み込む
+  pub fn read_xml(&self, entry: &CorpusEntry) -> Result<Vec<u8>> {
+    self.read_file(&entry.xml_path)
+  }
+
+  /// 法令のXMLファイルを読み込んで法律の構造体に変換する
+  pub fn parse(&self, entry: &CorpusEntry) -> Result<Law> {
+    let xml = self.read_xml(entry)?;
+    crate::parse_xml(&xml)
+  }
+
+  /// 全ての法令を順に変換する
+  ///
+  /// 変換は取り出すたびに行う。
+  pub fn laws(&self) -> impl Iterator<Item = (&CorpusEntry, Result<Law>)> {
+    self
+      .entries
+      .iter()
+      .map(move |entry| (entry, self.parse(entry)))
+  }
+
+  /// 添付ファイルを読み込む
+  ///
+  /// `src`には`Fig`要素の`src`属性の値である`./pict/001.jpg`のような法令のディレクトリからの相対パスを与える。
+  pub fn read_attachment(&self, entry: &CorpusEntry, src: &str) -> Result<Vec<u8>> {
+    let path = entry
+      .attachment_path(src)
+      .ok_or_else(|| Error::AttachmentNotFound(src.to_string()))?;
+    self.read_file(&path)
+  }
+
+  /// `Fig`要素が参照している画像やPDFのファイルを読み込む
+  pub fn fig(&self, entry: &CorpusEntry, fig: &Fig) -> Result<Vec<u8>> {
+    self.read_attachment(entry, &fig.src)
+  }
+
+  fn read_file(&self, path: &str) -> Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    match &self.source {
+      Source::Zip(archive) => {
+        let mut archive = archive.borrow_mut();
+        let mut file = archive.by_name(path).map_err(zip_error)?;
+        file
+          .read_to_end(&mut buf)
+          .map_err(|e| Error::IoError(Arc::new(e)))?;
+      }
+      Source::Dir(dir) => {
+        let path = dir.join(path);
+        buf = std::fs::read(&path).map_err(|error| file_io_error(&path, error))?;
+      }
+    }
+    Ok(buf)
+  }
+}
+
+fn file_io_error(path: &Path, error: std::io::Error) -> Error {
+  Error::FileIoError {
+    path: path.to_path_buf(),
+    error: Arc::new(error),
+  }
+}
+
+fn walk_dir(root: &Path, prefix: &str, files: &mut Vec<String>) -> Result<()> {
+  let dir = root.join(prefix);
+  let read_dir = std::fs::read_dir(&dir).map_err(|error| file_io_error(&dir, error))?;
+  for entry in read_dir {
+    let entry = entry.map_err(|error| file_io_error(&dir, error))?;
+    let name = entry.file_name().to_string_lossy().to_string();
+    let path = if prefix.is_empty() {
+      name
+    } else {
+      format!("{prefix}/{name}")
+    };
+    let file_type = entry
+      .file_type()
+      .map_err(|error| file_io_error(&entry.path(), error))?;
+    if file_type.is_dir() {
+      walk_dir(root, &path, files)?;
+    } else {
+      files.push(path);
+    }
+  }
+  Ok(())
+}
+
+fn is_xml(path: &str) -> bool {
+  path.to_ascii_lowercase().ends_with(".xml")
+}
+
+/// ファイルの一覧から法令の一覧を作る
+///
+/// XMLファイルを法令とし、それ以外のファイルは最も近い祖先のディレクトリにある法令の添付ファイルとする。
+/// 法令IDで始まらないディレクトリにあるXMLファイルは法令とせず、そのパスを別に返す。
+fn entries(mut files: Vec<String>) -> (Vec<CorpusEntry>, Vec<String>) {
+  files.sort();
+  let mut entries = Vec::new();
+  let mut skipped = Vec::new();
+  for path in files.iter().filter(|path| is_xml(path)) {
+    match CorpusEntry::new(path) {
+      Ok(entry) => entries.push(entry),
+      Err(_) => skipped.push(path.to_string()),
+    }
+  }
+  let mut dirs: HashMap<String, Vec<usize>> = HashMap::new();
+  for (i, entry) in entries.iter().enumerate() {
+    dirs.entry(entry.dir.clone()).or_default().push(i);
+  }
+  let mut attachments: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+  for path in files.iter().filter(|path| !is_xml(path)) {
+    let mut dir = path.as_str();
+    loop {
+      dir = dir.rsplit_once('/').map_or("", |(dir, _)| dir);
+      if let Some(indexes) = dirs.get(dir) {
+        let relative = if dir.is_empty() {
+          path.as_str()
+        } else {
+          &path[dir.len() + 1..]
+        };
+        for i in indexes {
+          attachments
+            .entry(*i)
+            .or_default()
+            .push(relative.to_string());
+        }
+        break;
+      }
+      if dir.is_empty() {
+        break;
+      }
+    }
+  }
+  for (i, lst) in attachments {
+    entries[i].attachments = lst;
+  }
+  (entries, skipped)
+}
@@ -12,16 +12,20 @@ pub struct Fig {
 }
 
 impl Parser for Fig {
-  fn parser(element: &Element) -> result::Result<Self> {
-    let src = element
-      .attributes
-      .get("src")
-      .ok_or(result::Error::MissingRequiredAttribute {
-        tag_name: "Fig".to_string(),
-        attribute_name: "src".to_string(),
-      })?
-      .clone();
-    Ok(Fig { src })
+  fn parser(_reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
+    if element.name.as_str() == "Fig" {
+      let src = element
+        .attributes
+        .get("src")
+        .ok_or(result::Error::MissingRequiredAttribute {
+          tag_name: "Fig".to_string(),
+          attribute_name: "src".to_string(),
+        })?
+        .clone();
+      Ok(Fig { src })
+    } else {
+      Err(result::Error::wrong_tag_name(element, "Fig"))
+    }
   }
 }
 
@@ -0,0 +1,223 @@
+//! 要素単位でのXMLとの相互変換
+//!
+//! `<Law>`全体ではなく、`<Article>`や`<TableStruct>`などの一部分だけのXMLを読み書きする。
+//!
+//! ```
+//! use japanese_law_xml_schema::{article::Article, fragment::*};
+//!
+//! let xml = r#"<Article Num="1">
+//!   <ArticleTitle>第一条</ArticleTitle>
+//!   <Paragraph Num="1">
+//!     <ParagraphNum/>
+//!     <ParagraphSentence><Sentence>本文</Sentence></ParagraphSentence>
+//!   </Paragraph>
+//! </Article>"#;
+//! let article = Article::from_xml(xml.as_bytes()).unwrap();
+//! let s = article.to_xml_string().unwrap();
+//! assert_eq!(Article::from_xml(s.as_bytes()).unwrap(), article);
+//! ```
+
+use crate::parser;
+use crate::result::{Diagnostic, Error, Result};
+use crate::to_xml::{ToXmlElement, ToXmlElementWithName};
+use crate::writer::{self, WriteOptions};
+use crate::*;
+use std::io::{Read, Write};
+
+/// XMLから変換できる要素
+pub trait FromXml: Sized {
+  /// 入力からXMLを読み込んで変換する
+  fn from_xml_reader<R: Read>(r: R) -> Result<Self>;
+
+  /// 入力からXMLを読み込んで変換する
+  ///
+  /// スキーマに合わない子要素を読み飛ばし、読み飛ばした箇所を診断情報として返す。
+  fn from_xml_reader_lenient<R: Read>(r: R) -> Result<(Self, Vec<Diagnostic>)>;
+
+  /// XML文字列から変換する
+  fn from_xml(xml: &[u8]) -> Result<Self> {
+    Self::from_xml_reader(xml)
+  }
+}
+
+/// XMLに変換できる要素
+pub trait ToXml {
+  /// 設定に従ってXMLを書き出す
+  fn write_xml_with<W: Write>(&self, w: &mut W, options: &WriteOptions) -> Result<()>;
+
+  /// XMLを書き出す
+  fn write_xml<W: Write>(&self, w: &mut W) -> Result<()> {
+    self.write_xml_with(w, &WriteOptions::default())
+  }
+
+  /// 設定に従ってXML文字列に変換する
+  fn to_xml_string_with(&self, options: &WriteOptions) -> Result<String> {
+    let mut buf = Vec::new();
+    self.write_xml_with(&mut buf, options)?;
+    String::from_utf8(buf).map_err(|_| Error::Io)
+  }
+
+  /// XML文字列に変換する
+  fn to_xml_string(&self) -> Result<String> {
+    self.to_xml_string_with(&WriteOptions::default())
+  }
+}
+
+/// 要素名を与えてXMLに変換できる要素
+///
+/// 見出しや文字列のように、同じ構造で`ArticleCaption`や`ParagraphCaption`など複数の要素名を持つもの。
+pub trait ToXmlWithName {
+  /// 設定に従って、与えた要素名でXMLを書き出す
+  fn write_xml_with_name<W: Write>(
+    &self,
+    name: &str,
+    w: &mut W,
+    options: &WriteOptions,
+  ) -> Result<()>;
+
+  /// 与えた要素名でXML文字列に変換する
+  fn to_xml_string_with_name(&self, name: &str) -> Result<String> {
+    let mut buf = Vec::new();
+    self.write_xml_with_name(name, &mut buf, &WriteOptions::default())?;
+    String::from_utf8(buf).map_err(|_| Error::Io)
+  }
+}
+
+/// 入力からXMLを読み込んで要素に変換する
+pub fn from_xml_reader<T: FromXml, R: Read>(r: R) -> Result<T> {
+  T::from_xml_reader(r)
+}
+
+/// 要素をXMLとして書き出す
+pub fn to_xml_writer<T: ToXml, W: Write>(t: &T, w: &mut W) -> Result<()> {
+  t.write_xml(w)
+}
+
+macro_rules! impl_xml {
+  ($($t:ty),* $(,)?) => {
+    $(
+      impl FromXml for $t {
+        fn from_xml_reader<R: Read>(r: R) -> Result<Self> {
+          parser::parse_reader(r)
+        }
+
+        fn from_xml_reader_lenient<R: Read>(r: R) -> Result<(Self, Vec<Diagnostic>)> {
+          parser::parse_reader_lenient(r)
+        }
+      }
+
+      impl ToXml for $t {
+        fn write_xml_with<W: Write>(&self, w: &mut W, options: &WriteOptions) -> Result<()> {
+          writer::write_element(&self.to_xml_element(), w, options)
+        }
+      }
+    )*
+  };
+}
+
+impl_xml!(
+  law::Law,
+  law::LawBody,
+  law::LawTitle,
+  law::Preamble,
+  law::MainProvision,
+  law::AmendProvision,
+  table_of_contents::TOC,
+  table_of_contents::TOCPart,
+  table_of_contents::TOCChapter,
+  table_of_contents::TOCSection,
+  table_of_contents::TOCSubsection,
+  table_of_contents::TOCDivision,
+  table_of_contents::TOCArticle,
+  table_of_contents::TOCSupplProvision,
+  article::Part,
+  article::Chapter,
+  article::Section,
+  article::Subsection,
+  article::Division,
+  article::Article,
+  paragraph::Paragraph,
+  paragraph::Item,
+  paragraph::Subitem1,
+  paragraph::Subitem2,
+  paragraph::Subitem3,
+  paragraph::Subitem4,
+  paragraph::Subitem5,
+  paragraph::Subitem6,
+  paragraph::Subitem7,
+  paragraph::Subitem8,
+  paragraph::Subitem9,
+  paragraph::Subitem10,
+  sentence::Sentence,
+  class::Class,
+  class::Column,
+  suppl_provision::SupplProvision,
+  suppl_provision::SupplProvisionAppdxTable,
+  suppl_provision::SupplProvisionAppdxStyle,
+  suppl_provision::SupplProvisionAppdx,
+  appdx::AppdxTable,
+  appdx::AppdxNote,
+  appdx::AppdxStyle,
+  appdx::AppdxFormat,
+  appdx::Appdx,
+  appdx::AppdxFig,
+  structs::Struct,
+  structs::QuoteStruct,
+  structs::NoteStruct,
+  structs::StyleStruct,
+  structs::FormatStruct,
+  structs::FigStruct,
+  structs::TableStruct,
+  contents::Note,
+  contents::Style,
+  contents::Format,
+  contents::ArithFormula,
+  table::Table,
+  table::TableHeaderRow,
+  table::TableRow,
+  table::TableColumn,
+  fig::Fig,
+  remarks::Remarks,
+  remarks::RemarksLabel,
+  list::List,
+  list::Sublist1,
+  list::Sublist2,
+  list::Sublist3,
+  line::Line,
+  text::Ruby,
+  text::Sup,
+  text::Sub,
+);
+
+macro_rules! impl_xml_with_name {
+  ($($t:ty => $f:expr),* $(,)?) => {
+    $(
+      impl FromXml for $t {
+        fn from_xml_reader<R: Read>(r: R) -> Result<Self> {
+          parser::parse_reader_with(r, $f)
+        }
+
+        fn from_xml_reader_lenient<R: Read>(r: R) -> Result<(Self, Vec<Diagnostic>)> {
+          parser::parse_reader_lenient_with(r, $f)
+        }
+      }
+
+      impl ToXmlWithName for $t {
+        fn write_xml_with_name<W: Write>(
+          &self,
+          name: &str,
+          w: &mut W,
+          options: &WriteOptions,
+        ) -> Result<()> {
+          writer::write_element(&self.to_xml_element_with_name(name), w, options)
+        }
+      }
+    )*
+  };
+}
+
+impl_xml_with_name!(
+  class::Caption => <class::Caption as parser::Parser>::parser,
+  text::Text => text::Text::from_reader,
+  text::TextWithWritingMode => <text::TextWithWritingMode as parser::Parser>::parser,
+);
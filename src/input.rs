@@ -0,0 +1,95 @@
+//! 圧縮された入力の展開
+//!
+//! 先頭のバイト列からgzipとzipを見分けて展開する。
+
+use crate::result::{Error, Result};
+use flate2::read::MultiGzDecoder;
+use std::cell::RefCell;
+use std::io::{self, BufReader, Cursor, Read};
+use std::rc::Rc;
+use std::sync::Arc;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const ZIP_MAGIC: [u8; 4] = [b'P', b'K', 0x03, 0x04];
+
+/// 入力がgzipやzipであれば展開して読み込めるようにする
+///
+/// zipの場合は、アーカイブ中の拡張子が`.xml`のファイルのうち名前順で最初のものを読み込む。
+pub(crate) fn decompress<'a, R: Read + 'a>(mut r: R) -> Result<Box<dyn Read + 'a>> {
+  let mut head = [0; 4];
+  let mut len = 0;
+  while len < head.len() {
+    match r.read(&mut head[len..]) {
+      Ok(0) => break,
+      Ok(n) => len += n,
+      Err(e) if e.kind() == io::ErrorKind::Interrupted => (),
+      Err(e) => return Err(Error::IoError(Arc::new(e))),
+    }
+  }
+  let head = &head[..len];
+  let r = Cursor::new(head.to_vec()).chain(r);
+  if head.starts_with(&GZIP_MAGIC) {
+    Ok(Box::new(BufReader::new(MultiGzDecoder::new(r))))
+  } else if head == ZIP_MAGIC {
+    read_zip(r)
+  } else {
+    Ok(Box::new(BufReader::new(r)))
+  }
+}
+
+fn read_zip<'a, R: Read>(mut r: R) -> Result<Box<dyn Read + 'a>> {
+  let mut buf = Vec::new();
+  r.read_to_end(&mut buf)
+    .map_err(|e| Error::IoError(Arc::new(e)))?;
+  let mut archive = zip::ZipArchive::new(Cursor::new(buf)).map_err(zip_error)?;
+  let name = archive
+    .file_names()
+    .filter(|name| name.to_ascii_lowercase().ends_with(".xml"))
+    .min()
+    .map(|name| name.to_string())
+    .ok_or_else(|| Error::Archive("XML file not found in the archive".to_string()))?;
+  let mut file = archive.by_name(&name).map_err(zip_error)?;
+  let mut xml = Vec::new();
+  file
+    .read_to_end(&mut xml)
+    .map_err(|e| Error::IoError(Arc::new(e)))?;
+  Ok(Box::new(Cursor::new(xml)))
+}
+
+pub(crate) fn zip_error(e: zip::result::ZipError) -> Error {
+  match e {
+    zip::result::ZipError::Io(e) => Error::IoError(Arc::new(e)),
+    e => Error::Archive(e.to_string()),
+  }
+}
+
+/// 読み込み中に起きた入出力のエラーを取っておく
+///
+/// XMLパーサーは入出力のエラーを自身のエラーに包んでしまうため、元のエラーをここに残す。
+pub(crate) struct KeepIoError<R> {
+  inner: R,
+  error: Rc<RefCell<Option<io::Error>>>,
+}
+
+impl<R> KeepIoError<R> {
+  pub(crate) fn new(inner: R) -> (Self, Rc<RefCell<Option<io::Error>>>) {
+    let error = Rc::new(RefCell::new(None));
+    (
+      KeepIoError {
+        inner,
+        error: error.clone(),
+      },
+      error,
+    )
+  }
+}
+
+impl<R: Read> Read for KeepIoError<R> {
+  fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    self.inner.read(buf).map_err(|e| {
+      let proxy = io::Error::new(e.kind(), e.to_string());
+      *self.error.borrow_mut() = Some(e);
+      proxy
+    })
+  }
+}
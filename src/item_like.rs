@@ -0,0 +1,239 @@
+//! 号と細分を共通に扱うための型
+//!
+//! `Item`と`Subitem1`から`Subitem10`までは同じ形をしているため、
+//! 参照をまとめた[`ItemLike`]と、階層の深さによらない[`ItemTree`]を用意する。
+
+use crate::article_number::ArticleNumber;
+use crate::class::SentenceOrColumnOrTable;
+use crate::paragraph::{
+  Item, Subitem1, Subitem10, Subitem2, Subitem3, Subitem4, Subitem5, Subitem6, Subitem7, Subitem8,
+  Subitem9,
+};
+use crate::result::{Error, Result};
+use crate::structs::Struct;
+use crate::text::Text;
+use serde::{Deserialize, Serialize};
+
+/// 号または細分への参照
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemLike<'a> {
+  Item(&'a Item),
+  Subitem1(&'a Subitem1),
+  Subitem2(&'a Subitem2),
+  Subitem3(&'a Subitem3),
+  Subitem4(&'a Subitem4),
+  Subitem5(&'a Subitem5),
+  Subitem6(&'a Subitem6),
+  Subitem7(&'a Subitem7),
+  Subitem8(&'a Subitem8),
+  Subitem9(&'a Subitem9),
+  Subitem10(&'a Subitem10),
+}
+
+macro_rules! each_item_like {
+  ($e:expr, $t:ident => $body:expr) => {
+    match $e {
+      ItemLike::Item($t) => $body,
+      ItemLike::Subitem1($t) => $body,
+      ItemLike::Subitem2($t) => $body,
+      ItemLike::Subitem3($t) => $body,
+      ItemLike::Subitem4($t) => $body,
+      ItemLike::Subitem5($t) => $body,
+      ItemLike::Subitem6($t) => $body,
+      ItemLike::Subitem7($t) => $body,
+      ItemLike::Subitem8($t) => $body,
+      ItemLike::Subitem9($t) => $body,
+      ItemLike::Subitem10($t) => $body,
+    }
+  };
+}
+
+impl<'a> ItemLike<'a> {
+  /// 号を0、細分1を1とする階層の深さ
+  pub fn depth(&self) -> usize {
+    match self {
+      ItemLike::Item(_) => 0,
+      ItemLike::Subitem1(_) => 1,
+      ItemLike::Subitem2(_) => 2,
+      ItemLike::Subitem3(_) => 3,
+      ItemLike::Subitem4(_) => 4,
+      ItemLike::Subitem5(_) => 5,
+      ItemLike::Subitem6(_) => 6,
+      ItemLike::Subitem7(_) => 7,
+      ItemLike::Subitem8(_) => 8,
+      ItemLike::Subitem9(_) => 9,
+      ItemLike::Subitem10(_) => 10,
+    }
+  }
+
+  /// 見出し（`一`や`イ`など）
+  pub fn title(&self) -> Option<&'a Text> {
+    each_item_like!(*self, t => t.title.as_ref())
+  }
+
+  /// 本文
+  pub fn sentence(&self) -> &'a SentenceOrColumnOrTable {
+    each_item_like!(*self, t => &t.sentence)
+  }
+
+  /// 番号
+  pub fn num(&self) -> Option<&'a ArticleNumber> {
+    each_item_like!(*self, t => t.num.as_ref())
+  }
+
+  /// 削除されているかどうか
+  pub fn delete(&self) -> bool {
+    each_item_like!(*self, t => t.delete)
+  }
+
+  /// 非表示かどうか
+  pub fn hide(&self) -> bool {
+    each_item_like!(*self, t => t.hide)
+  }
+
+  /// 表や図などの付随する要素
+  pub fn struct_list(&self) -> &'a [Struct] {
+    each_item_like!(*self, t => &t.struct_list)
+  }
+
+  /// 一つ下の階層の細分
+  pub fn children(&self) -> Vec<ItemLike<'a>> {
+    match *self {
+      ItemLike::Item(t) => t.children.iter().map(ItemLike::Subitem1).collect(),
+      ItemLike::Subitem1(t) => t.children.iter().map(ItemLike::Subitem2).collect(),
+      ItemLike::Subitem2(t) => t.children.iter().map(ItemLike::Subitem3).collect(),
+      ItemLike::Subitem3(t) => t.children.iter().map(ItemLike::Subitem4).collect(),
+      ItemLike::Subitem4(t) => t.children.iter().map(ItemLike::Subitem5).collect(),
+      ItemLike::Subitem5(t) => t.children.iter().map(ItemLike::Subitem6).collect(),
+      ItemLike::Subitem6(t) => t.children.iter().map(ItemLike::Subitem7).collect(),
+      ItemLike::Subitem7(t) => t.children.iter().map(ItemLike::Subitem8).collect(),
+      ItemLike::Subitem8(t) => t.children.iter().map(ItemLike::Subitem9).collect(),
+      ItemLike::Subitem9(t) => t.children.iter().map(ItemLike::Subitem10).collect(),
+      ItemLike::Subitem10(_) => Vec::new(),
+    }
+  }
+
+  /// 階層の深さによらない木に変換する
+  pub fn to_tree(&self) -> ItemTree {
+    ItemTree {
+      title: self.title().cloned(),
+      sentence: self.sentence().clone(),
+      struct_list: self.struct_list().to_vec(),
+      num: self.num().cloned(),
+      delete: self.delete(),
+      hide: self.hide(),
+      children: self.children().iter().map(|c| c.to_tree()).collect(),
+    }
+  }
+}
+
+macro_rules! impl_from_ref {
+  ($($t:ident),*) => {
+    $(
+      impl<'a> From<&'a $t> for ItemLike<'a> {
+        fn from(t: &'a $t) -> Self {
+          ItemLike::$t(t)
+        }
+      }
+    )*
+  };
+}
+
+impl_from_ref!(
+  Item, Subitem1, Subitem2, Subitem3, Subitem4, Subitem5, Subitem6, Subitem7, Subitem8, Subitem9,
+  Subitem10
+);
+
+/// 階層の深さによらない号と細分の木
+#[derive(Debug, Clone, Hash, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ItemTree {
+  pub title: Option<Text>,
+  pub sentence: SentenceOrColumnOrTable,
+  pub struct_list: Vec<Struct>,
+  pub num: Option<ArticleNumber>,
+  pub delete: bool,
+  pub hide: bool,
+  pub children: Vec<ItemTree>,
+}
+
+impl ItemTree {
+  /// 号に変換する
+  ///
+  /// 細分10より深い階層がある場合はエラーになる。
+  pub fn into_item(self) -> Result<Item> {
+    FromTree::from_tree(self)
+  }
+
+  /// 一番深い階層の深さ（号だけの場合は0）
+  pub fn depth(&self) -> usize {
+    self
+      .children
+      .iter()
+      .map(|c| c.depth() + 1)
+      .max()
+      .unwrap_or(0)
+  }
+}
+
+impl<'a> From<ItemLike<'a>> for ItemTree {
+  fn from(item: ItemLike<'a>) -> Self {
+    item.to_tree()
+  }
+}
+
+trait FromTree: Sized {
+  fn from_tree(tree: ItemTree) -> Result<Self>;
+}
+
+macro_rules! impl_from_tree {
+  ($($t:ident => $child:ident),*) => {
+    $(
+      impl FromTree for $t {
+        fn from_tree(tree: ItemTree) -> Result<Self> {
+          Ok($t {
+            title: tree.title,
+            sentence: tree.sentence,
+            children: tree
+              .children
+              .into_iter()
+              .map($child::from_tree)
+              .collect::<Result<Vec<_>>>()?,
+            struct_list: tree.struct_list,
+            num: tree.num,
+            delete: tree.delete,
+            hide: tree.hide,
+          })
+        }
+      }
+    )*
+  };
+}
+
+impl_from_tree!(
+  Item => Subitem1,
+  Subitem1 => Subitem2,
+  Subitem2 => Subitem3,
+  Subitem3 => Subitem4,
+  Subitem4 => Subitem5,
+  Subitem5 => Subitem6,
+  Subitem6 => Subitem7,
+  Subitem7 => Subitem8,
+  Subitem8 => Subitem9,
+  Subitem9 => Subitem10
+);
+
+impl FromTree for Subitem10 {
+  fn from_tree(tree: ItemTree) -> Result<Self> {
+    if !tree.children.is_empty() {
+      return Err(Error::ItemTooDeep);
+    }
+    Ok(Subitem10 {
+      title: tree.title,
+      sentence: tree.sentence,
+      struct_list: tree.struct_list,
+      num: tree.num,
+      delete: tree.delete,
+      hide: tree.hide,
+    })
+  }
+}
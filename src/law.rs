@@ -45,7 +45,7 @@ pub struct Law {
 }
 
 impl Parser for Law {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Law" {
       let era = match element.attributes.get("Era").map(|s| s.as_str()) {
         Some("Meiji") => Era::Meiji,
@@ -121,11 +121,10 @@ impl Parser for Law {
           })
         }
       };
-      let mut children = element.children.iter();
-      let law_num_element = children
-        .next()
+      let law_num_element = reader
+        .next_child(element)?
         .and_then(|n| {
-          if let XMLNode::Element(e) = n {
+          if let Node::Element(e) = n {
             Some(e)
           } else {
             None
@@ -134,34 +133,30 @@ impl Parser for Law {
         .ok_or(Error::missing_required_tag("LawNum"))?;
       let law_num_element_tag_name = law_num_element.name.clone();
       let law_num = if law_num_element_tag_name == "LawNum" {
-        law_num_element
-          .children
-          .iter()
-          .map(|n| {
-            if let XMLNode::Text(s) = n {
-              s.clone()
-            } else {
-              String::new()
-            }
-          })
-          .collect::<String>()
+        let mut law_num = String::new();
+        while let Some(n) = reader.next_child(&law_num_element)? {
+          if let Node::Text(s) = n {
+            law_num.push_str(&s)
+          }
+        }
+        law_num
       } else {
         return Err(Error::UnexpectedTag {
           wrong_name: law_num_element_tag_name,
           tag: "LawNum".to_string(),
         });
       };
-      let law_body_element = children
-        .next()
+      let law_body_element = reader
+        .next_child(element)?
         .and_then(|n| {
-          if let XMLNode::Element(e) = n {
+          if let Node::Element(e) = n {
             Some(e)
           } else {
             None
           }
         })
         .ok_or(Error::missing_required_tag("LawBody"))?;
-      let law_body = LawBody::parser(law_body_element)?;
+      let law_body = LawBody::parser(reader, &law_body_element)?;
       Ok(Law {
         era,
         year,
@@ -332,7 +327,7 @@ pub struct LawBody {
 }
 
 impl Parser for LawBody {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "LawBody" {
       let subject = get_attribute_opt_with_parse(element, "Subject")?;
       let mut law_title = None;
@@ -347,58 +342,59 @@ impl Parser for LawBody {
       let mut appdx = Vec::new();
       let mut appdx_fig = Vec::new();
       let mut appdx_format = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "LawTitle" => {
-              let v = LawTitle::parser(e)?;
+              let v = LawTitle::parser(reader, &e)?;
               law_title = Some(v);
             }
-            "EnactStatement" => enact_statement.push(Text::from_children(&e.children)),
+            "EnactStatement" => enact_statement.push(Text::from_reader(reader, &e)?),
             "TOC" => {
-              let v = TOC::parser(e)?;
+              let v = TOC::parser(reader, &e)?;
               toc = Some(v);
             }
             "Preamble" => {
-              let v = Preamble::parser(e)?;
+              let v = Preamble::parser(reader, &e)?;
               preamble = Some(v);
             }
             "MainProvision" => {
-              let v = MainProvision::parser(e)?;
+              let v = MainProvision::parser(reader, &e)?;
               main_provision = Some(v);
             }
             "SupplProvision" => {
-              let v = SupplProvision::parser(e)?;
+              let v = SupplProvision::parser(reader, &e)?;
               suppl_provision.push(v);
             }
             "AppdxTable" => {
-              let v = AppdxTable::parser(e)?;
+              let v = AppdxTable::parser(reader, &e)?;
               appdx_table.push(v);
             }
             "AppdxNote" => {
-              let v = AppdxNote::parser(e)?;
+              let v = AppdxNote::parser(reader, &e)?;
               appdx_note.push(v);
             }
             "AppdxStyle" => {
-              let v = AppdxStyle::parser(e)?;
+              let v = AppdxStyle::parser(reader, &e)?;
               appdx_style.push(v);
             }
             "Appdx" => {
-              let v = Appdx::parser(e)?;
+              let v = Appdx::parser(reader, &e)?;
               appdx.push(v);
             }
             "AppdxFig" => {
-              let v = AppdxFig::parser(e)?;
+              let v = AppdxFig::parser(reader, &e)?;
               appdx_fig.push(v);
             }
             "AppdxFormat" => {
-              let v = AppdxFormat::parser(e)?;
+              let v = AppdxFormat::parser(reader, &e)?;
               appdx_format.push(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(main_provision) = main_provision {
         Ok(LawBody {
           law_title,
@@ -485,12 +481,12 @@ pub struct LawTitle {
 }
 
 impl Parser for LawTitle {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "LawTitle" {
       let kana = get_attribute_opt_with_parse(element, "Kana")?;
       let abbrev = get_attribute_opt_with_parse(element, "Abbrev")?;
       let abbrev_kana = get_attribute_opt_with_parse(element, "AbbrevKana")?;
-      let text = Text::from_children(&element.children);
+      let text = Text::from_reader(reader, element)?;
       Ok(LawTitle {
         kana,
         abbrev,
@@ -527,17 +523,18 @@ pub struct Preamble {
 }
 
 impl Parser for Preamble {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Preamble" {
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           if e.name.as_str() == "Paragraph" {
-            let v = Paragraph::parser(e)?;
+            let v = Paragraph::parser(reader, &e)?;
             children.push(v)
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(Preamble { children })
     } else {
       Err(Error::wrong_tag_name(element, "Preamble"))
@@ -564,37 +561,38 @@ pub struct MainProvision {
 }
 
 impl Parser for MainProvision {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "MainProvision" {
       let extract = get_attribute_opt_with_parse(element, "Extract")?;
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Part" => {
-              let v = Part::parser(e)?;
+              let v = Part::parser(reader, &e)?;
               children.push(MainProvisionContents::Part(v))
             }
             "Chapter" => {
-              let v = Chapter::parser(e)?;
+              let v = Chapter::parser(reader, &e)?;
               children.push(MainProvisionContents::Chapter(v))
             }
             "Section" => {
-              let v = Section::parser(e)?;
+              let v = Section::parser(reader, &e)?;
               children.push(MainProvisionContents::Section(v))
             }
             "Article" => {
-              let v = Article::parser(e)?;
+              let v = Article::parser(reader, &e)?;
               children.push(MainProvisionContents::Article(v))
             }
             "Paragraph" => {
-              let v = Paragraph::parser(e)?;
+              let v = Paragraph::parser(reader, &e)?;
               children.push(MainProvisionContents::Paragraph(v))
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(MainProvision { children, extract })
     } else {
       Err(Error::wrong_tag_name(element, "MainProvision"))
@@ -646,233 +644,233 @@ pub struct AmendProvision {
 }
 
 impl Parser for AmendProvision {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "AmendProvision" {
       let mut sentence = None;
       let mut new_provision = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "AmendProvisionSentence" => {
-              let n = e
-                .children
-                .first()
+              let n = reader
+                .next_child(&e)?
                 .and_then(|n| {
-                  if let XMLNode::Element(e) = n {
+                  if let Node::Element(e) = n {
                     Some(e)
                   } else {
                     None
                   }
                 })
                 .ok_or(Error::missing_required_tag("AmendProvision"))?;
-              let v = Sentence::parser(n)?;
+              let v = Sentence::parser(reader, &n)?;
               sentence = Some(v)
             }
             "NewProvision" => {
-              for node in e.children.iter() {
-                if let XMLNode::Element(e) = node {
+              reader.each_child(&e, |reader, node| {
+                if let Node::Element(e) = node {
                   match e.name.as_str() {
                     "LawTitle" => {
-                      let v = LawTitle::parser(e)?;
+                      let v = LawTitle::parser(reader, &e)?;
                       new_provision.push(NewProvision::LawTitle(v));
                     }
                     "Preamble" => {
-                      let v = Preamble::parser(e)?;
+                      let v = Preamble::parser(reader, &e)?;
                       new_provision.push(NewProvision::Preamble(v));
                     }
                     "TOC" => {
-                      let v = TOC::parser(e)?;
+                      let v = TOC::parser(reader, &e)?;
                       new_provision.push(NewProvision::TOC(v));
                     }
                     "Part" => {
-                      let v = Part::parser(e)?;
+                      let v = Part::parser(reader, &e)?;
                       new_provision.push(NewProvision::Part(v));
                     }
                     "PartTitle" => {
-                      new_provision.push(NewProvision::PartTitle(Text::from_children(&e.children)));
+                      new_provision.push(NewProvision::PartTitle(Text::from_reader(reader, &e)?));
                     }
                     "Chapter" => {
-                      let v = Chapter::parser(e)?;
+                      let v = Chapter::parser(reader, &e)?;
                       new_provision.push(NewProvision::Chapter(v));
                     }
                     "ChapterTitle" => {
                       new_provision
-                        .push(NewProvision::ChapterTitle(Text::from_children(&e.children)));
+                        .push(NewProvision::ChapterTitle(Text::from_reader(reader, &e)?));
                     }
                     "Section" => {
-                      let v = Section::parser(e)?;
+                      let v = Section::parser(reader, &e)?;
                       new_provision.push(NewProvision::Section(v));
                     }
                     "SectionTitle" => {
                       new_provision
-                        .push(NewProvision::SectionTitle(Text::from_children(&e.children)));
+                        .push(NewProvision::SectionTitle(Text::from_reader(reader, &e)?));
                     }
                     "Subsection" => {
-                      let v = Subsection::parser(e)?;
+                      let v = Subsection::parser(reader, &e)?;
                       new_provision.push(NewProvision::Subsection(v));
                     }
                     "SubsectionTitle" => {
-                      new_provision.push(NewProvision::SubsectionTitle(Text::from_children(
-                        &e.children,
-                      )));
+                      new_provision.push(NewProvision::SubsectionTitle(Text::from_reader(
+                        reader, &e,
+                      )?));
                     }
                     "Division" => {
-                      let v = Division::parser(e)?;
+                      let v = Division::parser(reader, &e)?;
                       new_provision.push(NewProvision::Division(v));
                     }
                     "DivisionTitle" => {
-                      new_provision.push(NewProvision::DivisionTitle(Text::from_children(
-                        &e.children,
-                      )));
+                      new_provision
+                        .push(NewProvision::DivisionTitle(Text::from_reader(reader, &e)?));
                     }
                     "Article" => {
-                      let v = Article::parser(e)?;
+                      let v = Article::parser(reader, &e)?;
                       new_provision.push(NewProvision::Article(v));
                     }
                     "SupplNote" => {
-                      new_provision.push(NewProvision::SupplNote(Text::from_children(&e.children)));
+                      new_provision.push(NewProvision::SupplNote(Text::from_reader(reader, &e)?));
                     }
                     "Paragraph" => {
-                      let v = Paragraph::parser(e)?;
+                      let v = Paragraph::parser(reader, &e)?;
                       new_provision.push(NewProvision::Paragraph(v));
                     }
                     "Item" => {
-                      let v = Item::parser(e)?;
+                      let v = Item::parser(reader, &e)?;
                       new_provision.push(NewProvision::Item(v));
                     }
                     "Subitem1" => {
-                      let v = Subitem1::parser(e)?;
+                      let v = Subitem1::parser(reader, &e)?;
                       new_provision.push(NewProvision::Subitem1(v));
                     }
                     "Subitem2" => {
-                      let v = Subitem2::parser(e)?;
+                      let v = Subitem2::parser(reader, &e)?;
                       new_provision.push(NewProvision::Subitem2(v));
                     }
                     "Subitem3" => {
-                      let v = Subitem3::parser(e)?;
+                      let v = Subitem3::parser(reader, &e)?;
                       new_provision.push(NewProvision::Subitem3(v));
                     }
                     "Subitem4" => {
-                      let v = Subitem4::parser(e)?;
+                      let v = Subitem4::parser(reader, &e)?;
                       new_provision.push(NewProvision::Subitem4(v));
                     }
                     "Subitem5" => {
-                      let v = Subitem5::parser(e)?;
+                      let v = Subitem5::parser(reader, &e)?;
                       new_provision.push(NewProvision::Subitem5(v));
                     }
                     "Subitem6" => {
-                      let v = Subitem6::parser(e)?;
+                      let v = Subitem6::parser(reader, &e)?;
                       new_provision.push(NewProvision::Subitem6(v));
                     }
                     "Subitem7" => {
-                      let v = Subitem7::parser(e)?;
+                      let v = Subitem7::parser(reader, &e)?;
                       new_provision.push(NewProvision::Subitem7(v));
                     }
                     "Subitem8" => {
-                      let v = Subitem8::parser(e)?;
+                      let v = Subitem8::parser(reader, &e)?;
                       new_provision.push(NewProvision::Subitem8(v));
                     }
                     "Subitem9" => {
-                      let v = Subitem9::parser(e)?;
+                      let v = Subitem9::parser(reader, &e)?;
                       new_provision.push(NewProvision::Subitem9(v));
                     }
                     "Subitem10" => {
-                      let v = Subitem10::parser(e)?;
+                      let v = Subitem10::parser(reader, &e)?;
                       new_provision.push(NewProvision::Subitem10(v));
                     }
                     "List" => {
-                      let v = List::parser(e)?;
+                      let v = List::parser(reader, &e)?;
                       new_provision.push(NewProvision::List(v));
                     }
                     "Sentence" => {
-                      let v = Sentence::parser(e)?;
+                      let v = Sentence::parser(reader, &e)?;
                       new_provision.push(NewProvision::Sentence(v));
                     }
                     "AmendProvision" => {
-                      let v = AmendProvision::parser(e)?;
+                      let v = AmendProvision::parser(reader, &e)?;
                       new_provision.push(NewProvision::AmendProvision(v));
                     }
                     "AppdxTable" => {
-                      let v = AppdxTable::parser(e)?;
+                      let v = AppdxTable::parser(reader, &e)?;
                       new_provision.push(NewProvision::AppdxTable(v));
                     }
                     "AppdxNote" => {
-                      let v = AppdxNote::parser(e)?;
+                      let v = AppdxNote::parser(reader, &e)?;
                       new_provision.push(NewProvision::AppdxNote(v));
                     }
                     "AppdxStyle" => {
-                      let v = AppdxStyle::parser(e)?;
+                      let v = AppdxStyle::parser(reader, &e)?;
                       new_provision.push(NewProvision::AppdxStyle(v));
                     }
                     "Appdx" => {
-                      let v = Appdx::parser(e)?;
+                      let v = Appdx::parser(reader, &e)?;
                       new_provision.push(NewProvision::Appdx(v));
                     }
                     "AppdxFig" => {
-                      let v = AppdxFig::parser(e)?;
+                      let v = AppdxFig::parser(reader, &e)?;
                       new_provision.push(NewProvision::AppdxFig(v));
                     }
                     "AppdxFormat" => {
-                      let v = AppdxFormat::parser(e)?;
+                      let v = AppdxFormat::parser(reader, &e)?;
                       new_provision.push(NewProvision::AppdxFormat(v));
                     }
                     "SupplProvisionAppdxStyle" => {
-                      let v = SupplProvisionAppdxStyle::parser(e)?;
+                      let v = SupplProvisionAppdxStyle::parser(reader, &e)?;
                       new_provision.push(NewProvision::SupplProvisionAppdxStyle(v));
                     }
                     "SupplProvisionAppdxTable" => {
-                      let v = SupplProvisionAppdxTable::parser(e)?;
+                      let v = SupplProvisionAppdxTable::parser(reader, &e)?;
                       new_provision.push(NewProvision::SupplProvisionAppdxTable(v));
                     }
                     "SupplProvisionAppdx" => {
-                      let v = SupplProvisionAppdx::parser(e)?;
+                      let v = SupplProvisionAppdx::parser(reader, &e)?;
                       new_provision.push(NewProvision::SupplProvisionAppdx(v));
                     }
                     "TableStruct" => {
-                      let v = TableStruct::parser(e)?;
+                      let v = TableStruct::parser(reader, &e)?;
                       new_provision.push(NewProvision::TableStruct(v));
                     }
                     "TableRow" => {
-                      let v = TableRow::parser(e)?;
+                      let v = TableRow::parser(reader, &e)?;
                       new_provision.push(NewProvision::TableRow(v));
                     }
                     "TableColumn" => {
-                      let v = TableColumn::parser(e)?;
+                      let v = TableColumn::parser(reader, &e)?;
                       new_provision.push(NewProvision::TableColumn(v));
                     }
                     "FigStruct" => {
-                      let v = FigStruct::parser(e)?;
+                      let v = FigStruct::parser(reader, &e)?;
                       new_provision.push(NewProvision::FigStruct(v));
                     }
                     "NoteStruct" => {
-                      let v = NoteStruct::parser(e)?;
+                      let v = NoteStruct::parser(reader, &e)?;
                       new_provision.push(NewProvision::NoteStruct(v));
                     }
                     "StyleStruct" => {
-                      let v = StyleStruct::parser(e)?;
+                      let v = StyleStruct::parser(reader, &e)?;
                       new_provision.push(NewProvision::StyleStruct(v));
                     }
                     "FormatStruct" => {
-                      let v = FormatStruct::parser(e)?;
+                      let v = FormatStruct::parser(reader, &e)?;
                       new_provision.push(NewProvision::FormatStruct(v));
                     }
                     "Remarks" => {
-                      let v = Remarks::parser(e)?;
+                      let v = Remarks::parser(reader, &e)?;
                       new_provision.push(NewProvision::Remarks(v));
                     }
                     "LawBody" => {
-                      let v = LawBody::parser(e)?;
+                      let v = LawBody::parser(reader, &e)?;
                       new_provision.push(NewProvision::LawBody(Box::new(v)));
                     }
                     s => return Err(Error::unexpected_tag(element, s)),
                   }
                 }
-              }
+                Ok(())
+              })?;
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(AmendProvision {
         sentence,
         new_provision,
@@ -892,7 +890,7 @@ impl ToXmlElement for AmendProvision {
       e.children.push(XMLNode::Element(se));
     }
     for v in self.new_provision.iter() {
-      let mut se = Element::new("AmendProvisionSentence");
+      let mut se = Element::new("NewProvision");
       match v {
         NewProvision::LawTitle(v) => se.children.push(XMLNode::Element(v.to_xml_element())),
         NewProvision::Preamble(v) => se.children.push(XMLNode::Element(v.to_xml_element())),
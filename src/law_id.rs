@@ -0,0 +1,333 @@
+//! e-Govの法令IDと法令履歴ID
+//!
+//! 法令IDは`129AC0000000089`のような15文字の文字列で、先頭から元号・年・法令の種別・番号を表す。
+//! 法令履歴IDは`129AC0000000089_20230614_505AC0000000053`のように法令ID・施行日・改正法令の法令IDをつなげたものである。
+
+use crate::article_number::ArticleNumber;
+use crate::law::{Era, Law, LawType};
+use crate::result::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// 法令ID
+#[derive(Debug, Clone, Hash, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LawId {
+  /// 年号
+  pub era: Era,
+  /// 制定年
+  pub year: usize,
+  /// 法令の種別と番号
+  pub kind: LawIdKind,
+}
+
+/// 法令IDの表す法令の種別と番号
+#[derive(Debug, Clone, Hash, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LawIdKind {
+  /// 憲法（`CONSTITUTION`）
+  Constitution,
+  /// 法律（`AC`）
+  Act(usize),
+  /// 政令（`CO`）
+  CabinetOrder(usize),
+  /// 勅令（`IO`）
+  ImperialOrder(usize),
+  /// 府省令（`M`と発令した機関の8文字のコード）
+  MinisterialOrdinance { issuer: String, num: usize },
+  /// 規則（`R`と制定した機関の8文字のコード）
+  Rule { issuer: String, num: usize },
+  /// その他（太政官布告の`DF`などの2文字のコード）
+  Misc { code: String, num: usize },
+}
+
+impl LawId {
+  /// 法令の種類
+  pub fn law_type(&self) -> LawType {
+    match self.kind {
+      LawIdKind::Constitution => LawType::Constitution,
+      LawIdKind::Act(_) => LawType::Act,
+      LawIdKind::CabinetOrder(_) => LawType::CabinetOrder,
+      LawIdKind::ImperialOrder(_) => LawType::ImperialOrder,
+      LawIdKind::MinisterialOrdinance { .. } => LawType::MinisterialOrdinance,
+      LawIdKind::Rule { .. } => LawType::Rule,
+      LawIdKind::Misc { .. } => LawType::Misc,
+    }
+  }
+
+  /// その年で制定された法令の通し番号
+  pub fn num(&self) -> Option<usize> {
+    match &self.kind {
+      LawIdKind::Constitution => None,
+      LawIdKind::Act(num) | LawIdKind::CabinetOrder(num) | LawIdKind::ImperialOrder(num) => {
+        Some(*num)
+      }
+      LawIdKind::MinisterialOrdinance { num, .. }
+      | LawIdKind::Rule { num, .. }
+      | LawIdKind::Misc { num, .. } => Some(*num),
+    }
+  }
+
+  /// [`Law::num`]と同じ形式の番号
+  pub fn article_number(&self) -> Option<ArticleNumber> {
+    self.num().map(|base_number| ArticleNumber {
+      base_number,
+      eda_numbers: Vec::new(),
+      range_end_numbers: Vec::new(),
+    })
+  }
+
+  /// 法律の構造体から法令IDを求める
+  ///
+  /// 府省令・規則・その他の法令は、発令した機関などのコードを法律の構造体から求められないため`None`を返す。
+  pub fn from_law(law: &Law) -> Option<Self> {
+    let num = || match &law.num {
+      Some(n) if n.eda_numbers.is_empty() && n.range_end_numbers.is_empty() => Some(n.base_number),
+      _ => None,
+    };
+    let kind = match law.law_type {
+      LawType::Constitution => LawIdKind::Constitution,
+      LawType::Act => LawIdKind::Act(num()?),
+      LawType::CabinetOrder => LawIdKind::CabinetOrder(num()?),
+      LawType::ImperialOrder => LawIdKind::ImperialOrder(num()?),
+      LawType::MinisterialOrdinance | LawType::Rule | LawType::Misc => return None,
+    };
+    Some(LawId {
+      era: law.era.clone(),
+      year: law.year,
+      kind,
+    })
+  }
+
+  /// 法律の構造体の年号・制定年・種類・番号が一致するかどうか
+  pub fn matches(&self, law: &Law) -> bool {
+    self.era == law.era
+      && self.year == law.year
+      && self.law_type() == law.law_type
+      && self.article_number() == law.num
+  }
+}
+
+fn era_code(era: &Era) -> char {
+  match era {
+    Era::Meiji => '1',
+    Era::Taisho => '2',
+    Era::Showa => '3',
+    Era::Heisei => '4',
+    Era::Reiwa => '5',
+  }
+}
+
+fn parse_digits(s: &str) -> Option<usize> {
+  if !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()) {
+    s.parse().ok()
+  } else {
+    None
+  }
+}
+
+impl FromStr for LawId {
+  type Err = Error;
+  fn from_str(s: &str) -> Result<Self> {
+    let err = || Error::ParsingError("LawId".to_string(), s.to_string());
+    if s.len() != 15 || !s.is_ascii() {
+      return Err(err());
+    }
+    let era = match &s[0..1] {
+      "1" => Era::Meiji,
+      "2" => Era::Taisho,
+      "3" => Era::Showa,
+      "4" => Era::Heisei,
+      "5" => Era::Reiwa,
+      _ => return Err(err()),
+    };
+    let year = parse_digits(&s[1..3]).ok_or_else(err)?;
+    let rest = &s[3..];
+    let is_code = |c: &str| c.chars().all(|c| c.is_ascii_uppercase());
+    let is_issuer = |c: &str| c.chars().all(|c| c.is_ascii_alphanumeric());
+    let kind = if rest == "CONSTITUTION" {
+      LawIdKind::Constitution
+    } else if (rest.starts_with('M') || rest.starts_with('R')) && is_issuer(&rest[1..9]) {
+      let issuer = rest[1..9].to_string();
+      let num = parse_digits(&rest[9..]).ok_or_else(err)?;
+      if rest.starts_with('M') {
+        LawIdKind::MinisterialOrdinance { issuer, num }
+      } else {
+        LawIdKind::Rule { issuer, num }
+      }
+    } else if is_code(&rest[..2]) {
+      let num = parse_digits(&rest[2..]).ok_or_else(err)?;
+      match &rest[..2] {
+        "AC" => LawIdKind::Act(num),
+        "CO" => LawIdKind::CabinetOrder(num),
+        "IO" => LawIdKind::ImperialOrder(num),
+        code => LawIdKind::Misc {
+          code: code.to_string(),
+          num,
+        },
+      }
+    } else {
+      return Err(err());
+    };
+    Ok(LawId { era, year, kind })
+  }
+}
+
+impl std::fmt::Display for LawId {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}{:02}", era_code(&self.era), self.year)?;
+    match &self.kind {
+      LawIdKind::Constitution => write!(f, "CONSTITUTION"),
+      LawIdKind::Act(num) => write!(f, "AC{num:010}"),
+      LawIdKind::CabinetOrder(num) => write!(f, "CO{num:010}"),
+      LawIdKind::ImperialOrder(num) => write!(f, "IO{num:010}"),
+      LawIdKind::MinisterialOrdinance { issuer, num } => write!(f, "M{issuer}{num:03}"),
+      LawIdKind::Rule { issuer, num } => write!(f, "R{issuer}{num:03}"),
+      LawIdKind::Misc { code, num } => write!(f, "{code}{num:010}"),
+    }
+  }
+}
+
+/// 法令履歴ID
+///
+/// 改正されていない法令では、改正法令の法令IDの部分が`000000000000000`になる。
+#[derive(Debug, Clone, Hash, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LawRevisionId {
+  /// 法令ID
+  pub law_id: LawId,
+  /// 施行日の西暦年
+  pub year: usize,
+  /// 施行日の月
+  pub month: usize,
+  /// 施行日の日
+  pub day: usize,
+  /// 最後に改正した法令の法令ID
+  pub amend_law_id: Option<LawId>,
+}
+
+impl LawRevisionId {
+  /// `129AC0000000089_20230614_505AC0000000053.xml`のようなファイル名やディレクトリ名から生成する
+  pub fn from_file_name(name: &str) -> Result<Self> {
+    let name = name.rsplit(['/', '\\']).next().unwrap_or(name);
+    let name = match name.rsplit_once('.') {
+      Some((stem, _)) => stem,
+      None => name,
+    };
+    LawRevisionId::from_str(name)
+  }
+
+  /// 施行日
+  pub fn date(&self) -> Option<koyomi::Date> {
+    koyomi::Date::from_ymd(self.year as i32, self.month as u32, self.day as u32).ok()
+  }
+}
+
+impl FromStr for LawRevisionId {
+  type Err = Error;
+  fn from_str(s: &str) -> Result<Self> {
+    let err = || Error::ParsingError("LawRevisionId".to_string(), s.to_string());
+    let mut parts = s.split('_');
+    let (law_id, date, amend_law_id) =
+      match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(law_id), Some(date), Some(amend_law_id), None) => (law_id, date, amend_law_id),
+        _ => return Err(err()),
+      };
+    let law_id = LawId::from_str(law_id)?;
+    if date.len() != 8 || !date.is_ascii() {
+      return Err(err());
+    }
+    let year = parse_digits(&date[0..4]).ok_or_else(err)?;
+    let month = parse_digits(&date[4..6]).ok_or_else(err)?;
+    let day = parse_digits(&date[6..8]).ok_or_else(err)?;
+    let amend_law_id = if amend_law_id.chars().all(|c| c == '0') {
+      None
+    } else {
+      Some(LawId::from_str(amend_law_id)?)
+    };
+    Ok(LawRevisionId {
+      law_id,
+      year,
+      month,
+      day,
+      amend_law_id,
+    })
+  }
+}
+
+impl std::fmt::Display for LawRevisionId {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}_{:04}{:02}{:02}_",
+      self.law_id, self.year, self.month, self.day
+    )?;
+    match &self.amend_law_id {
+      Some(id) => write!(f, "{id}"),
+      None => write!(f, "000000000000000"),
+    }
+  }
+}
+
+#[test]
+fn check_law_id() {
+  let cases = [
+    ("129AC0000000089", Era::Meiji, 29, LawIdKind::Act(89)),
+    ("321CONSTITUTION", Era::Showa, 21, LawIdKind::Constitution),
+    (
+      "322CO0000000016",
+      Era::Showa,
+      22,
+      LawIdKind::CabinetOrder(16),
+    ),
+    (
+      "322IO0000000207",
+      Era::Showa,
+      22,
+      LawIdKind::ImperialOrder(207),
+    ),
+    (
+      "325M50080000014",
+      Era::Showa,
+      25,
+      LawIdKind::MinisterialOrdinance {
+        issuer: "50080000".to_string(),
+        num: 14,
+      },
+    ),
+    (
+      "106DF0000000065",
+      Era::Meiji,
+      6,
+      LawIdKind::Misc {
+        code: "DF".to_string(),
+        num: 65,
+      },
+    ),
+  ];
+  for (s, era, year, kind) in cases {
+    let id = LawId::from_str(s).unwrap();
+    assert_eq!(id, LawId { era, year, kind });
+    assert_eq!(id.to_string(), s);
+  }
+  assert!(LawId::from_str("129AC00000000").is_err());
+  assert!(LawId::from_str("929AC0000000089").is_err());
+}
+
+#[test]
+fn check_law_revision_id() {
+  let id = LawRevisionId::from_file_name("src/tests/129AC0000000089_20230614_505AC0000000053.xml")
+    .unwrap();
+  assert_eq!(id.law_id, LawId::from_str("129AC0000000089").unwrap());
+  assert_eq!((id.year, id.month, id.day), (2023, 6, 14));
+  assert_eq!(
+    id.amend_law_id,
+    Some(LawId {
+      era: Era::Reiwa,
+      year: 5,
+      kind: LawIdKind::Act(53),
+    })
+  );
+  assert_eq!(id.to_string(), "129AC0000000089_20230614_505AC0000000053");
+  let id = LawRevisionId::from_str("129AC0000000089_18960427_000000000000000").unwrap();
+  assert_eq!(id.amend_law_id, None);
+  assert_eq!(id.to_string(), "129AC0000000089_18960427_000000000000000");
+  assert!(LawRevisionId::from_str("129AC0000000089_2023061_505AC0000000053").is_err());
+}
@@ -0,0 +1,392 @@
+//! 法令番号
+//!
+//! `明治二十九年法律第八十九号`のような法令番号や、
+//! 附則の`AmendLawNum`属性にある`令和五年六月一四日法律第五三号`のような公布日付きの法令番号を扱う。
+
+use crate::article_number::{parse_kansuji, ArticleNumber};
+use crate::law::{Era, Law, LawType};
+use crate::result::{Error, Result};
+use kansuji::Kansuji;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// 法令番号
+#[derive(Debug, Clone, Hash, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LawNum {
+  /// 年号
+  pub era: Era,
+  /// 制定年
+  pub year: usize,
+  /// 公布月
+  pub promulgate_month: Option<usize>,
+  /// 公布日
+  pub promulgate_day: Option<usize>,
+  /// 法令の種別
+  pub kind: LawNumKind,
+  /// その年で制定された法令の通し番号
+  pub num: Option<ArticleNumber>,
+}
+
+/// 法令番号に書かれている法令の種別
+#[derive(Debug, Clone, Hash, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LawNumKind {
+  /// 憲法
+  Constitution,
+  /// 法律
+  Act,
+  /// 政令
+  CabinetOrder,
+  /// 勅令
+  ImperialOrder,
+  /// 府令・省令（`issuer`は`内閣府`や`財務省`など）
+  Ordinance { issuer: String },
+  /// 規則（`issuer`は`人事院`や`最高裁判所`など）
+  Rule { issuer: String },
+  /// 告示
+  Notice { issuer: String },
+  /// その他（`太政官布告`など）
+  Other(String),
+}
+
+impl LawNumKind {
+  fn from_name(name: &str) -> Option<Self> {
+    let kind = match name {
+      "" => return None,
+      "憲法" => LawNumKind::Constitution,
+      "法律" => LawNumKind::Act,
+      "政令" => LawNumKind::CabinetOrder,
+      "勅令" => LawNumKind::ImperialOrder,
+      _ if name.ends_with("府令") || name.ends_with("省令") => LawNumKind::Ordinance {
+        issuer: name.trim_end_matches('令').to_string(),
+      },
+      _ if name.ends_with("規則") => LawNumKind::Rule {
+        issuer: name.trim_end_matches("規則").to_string(),
+      },
+      _ if name.ends_with("告示") => LawNumKind::Notice {
+        issuer: name.trim_end_matches("告示").to_string(),
+      },
+      _ => LawNumKind::Other(name.to_string()),
+    };
+    Some(kind)
+  }
+
+  /// `法律`や`内閣府令`のような種別の名前
+  pub fn name(&self) -> String {
+    match self {
+      LawNumKind::Constitution => "憲法".to_string(),
+      LawNumKind::Act => "法律".to_string(),
+      LawNumKind::CabinetOrder => "政令".to_string(),
+      LawNumKind::ImperialOrder => "勅令".to_string(),
+      LawNumKind::Ordinance { issuer } => format!("{issuer}令"),
+      LawNumKind::Rule { issuer } => format!("{issuer}規則"),
+      LawNumKind::Notice { issuer } => format!("{issuer}告示"),
+      LawNumKind::Other(name) => name.clone(),
+    }
+  }
+
+  /// `人事院規則一―二`のように、番号を`第`と`号`ではなく`―`でつなぐ種別かどうか
+  fn is_dash_numbered(&self) -> bool {
+    matches!(self, LawNumKind::Rule { issuer } if issuer == "人事院")
+  }
+
+  /// 法令の種類
+  pub fn law_type(&self) -> LawType {
+    match self {
+      LawNumKind::Constitution => LawType::Constitution,
+      LawNumKind::Act => LawType::Act,
+      LawNumKind::CabinetOrder => LawType::CabinetOrder,
+      LawNumKind::ImperialOrder => LawType::ImperialOrder,
+      LawNumKind::Ordinance { .. } => LawType::MinisterialOrdinance,
+      LawNumKind::Rule { .. } => LawType::Rule,
+      LawNumKind::Notice { .. } | LawNumKind::Other(_) => LawType::Misc,
+    }
+  }
+}
+
+/// 法令番号と法律の構造体の属性とで食い違っている項目
+#[derive(Debug, Clone, Copy, Hash, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LawNumMismatch {
+  /// 年号
+  Era,
+  /// 制定年
+  Year,
+  /// 法令の種類
+  LawType,
+  /// 法令の通し番号
+  Num,
+  /// 公布月
+  PromulgateMonth,
+  /// 公布日
+  PromulgateDay,
+}
+
+impl LawNum {
+  /// 法令の種類
+  pub fn law_type(&self) -> LawType {
+    self.kind.law_type()
+  }
+
+  /// 法律の構造体の`law_num`を解析する
+  pub fn from_law(law: &Law) -> Result<Self> {
+    LawNum::from_str(&law.law_num)
+  }
+
+  /// 法律の構造体の年号・制定年・種類・番号・公布日と食い違っている項目を返す
+  ///
+  /// 公布日は、法令番号と法律の構造体の両方にある場合だけ比べる。
+  pub fn check(&self, law: &Law) -> Vec<LawNumMismatch> {
+    let mut v = Vec::new();
+    if self.era != law.era {
+      v.push(LawNumMismatch::Era);
+    }
+    if self.year != law.year {
+      v.push(LawNumMismatch::Year);
+    }
+    if self.law_type() != law.law_type {
+      v.push(LawNumMismatch::LawType);
+    }
+    if self.num != law.num {
+      v.push(LawNumMismatch::Num);
+    }
+    if let (Some(a), Some(b)) = (self.promulgate_month, law.promulgate_month) {
+      if a != b {
+        v.push(LawNumMismatch::PromulgateMonth);
+      }
+    }
+    if let (Some(a), Some(b)) = (self.promulgate_day, law.promulgate_day) {
+      if a != b {
+        v.push(LawNumMismatch::PromulgateDay);
+      }
+    }
+    v
+  }
+
+  /// 法律の構造体の属性と食い違いがないかどうか
+  pub fn matches(&self, law: &Law) -> bool {
+    self.check(law).is_empty()
+  }
+
+  /// `AmendLawNum`属性と同じ、`令和五年六月一四日法律第五三号`のように数字を位取りで並べた形式の文字列
+  pub fn to_amend_law_num_string(&self) -> String {
+    self.format(positional_kansuji)
+  }
+
+  fn format(&self, num_to_str: fn(usize) -> String) -> String {
+    let mut s = era_name(&self.era).to_string();
+    if self.year == 1 {
+      s.push('元');
+    } else {
+      s.push_str(&num_to_str(self.year));
+    }
+    s.push('年');
+    if let Some(month) = self.promulgate_month {
+      s.push_str(&format!("{}月", num_to_str(month)));
+    }
+    if let Some(day) = self.promulgate_day {
+      s.push_str(&format!("{}日", num_to_str(day)));
+    }
+    s.push_str(&self.kind.name());
+    match &self.num {
+      Some(num) if self.kind.is_dash_numbered() => {
+        s.push_str(&num_to_str(num.base_number));
+        for n in num.eda_numbers.iter() {
+          s.push_str(&format!("―{}", num_to_str(*n)));
+        }
+      }
+      Some(num) => {
+        s.push_str(&format!("第{}号", num_to_str(num.base_number)));
+        for n in num.eda_numbers.iter() {
+          s.push_str(&format!("の{}", num_to_str(*n)));
+        }
+      }
+      None => (),
+    }
+    s
+  }
+}
+
+pub(crate) fn era_name(era: &Era) -> &'static str {
+  match era {
+    Era::Meiji => "明治",
+    Era::Taisho => "大正",
+    Era::Showa => "昭和",
+    Era::Heisei => "平成",
+    Era::Reiwa => "令和",
+  }
+}
+
+pub(crate) fn kansuji(n: usize) -> String {
+  Kansuji::from(n).to_string()
+}
+
+fn positional_kansuji(n: usize) -> String {
+  const DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+  n.to_string()
+    .chars()
+    .map(|c| DIGITS[c.to_digit(10).unwrap() as usize])
+    .collect()
+}
+
+impl FromStr for LawNum {
+  type Err = Error;
+  fn from_str(s: &str) -> Result<Self> {
+    let err = || Error::ParsingError("LawNum".to_string(), s.to_string());
+    let text = s.trim();
+    let (era, rest) = [Era::Meiji, Era::Taisho, Era::Showa, Era::Heisei, Era::Reiwa]
+      .into_iter()
+      .find_map(|era| {
+        text
+          .strip_prefix(era_name(&era))
+          .map(|rest| (era.clone(), rest))
+      })
+      .ok_or_else(err)?;
+    let (year, rest) = rest.split_once('年').ok_or_else(err)?;
+    let year = if year == "元" {
+      1
+    } else {
+      parse_kansuji(year).ok_or_else(err)?
+    };
+    let (promulgate_month, promulgate_day, rest) = match rest.split_once('月') {
+      Some((month, rest2)) if parse_kansuji(month).is_some() => {
+        let (day, rest2) = rest2.split_once('日').ok_or_else(err)?;
+        (
+          parse_kansuji(month),
+          Some(parse_kansuji(day).ok_or_else(err)?),
+          rest2,
+        )
+      }
+      _ => (None, None, rest),
+    };
+    // `人事院規則一―二`のように、規則の後に`第`を使わない番号が続く場合
+    if let Some((name, num)) = rest
+      .find("規則")
+      .map(|i| rest.split_at(i + "規則".len()))
+      .filter(|(_, num)| !num.is_empty() && !num.starts_with('第'))
+    {
+      let kind = LawNumKind::from_name(name).ok_or_else(err)?;
+      let mut nums = num.split(['―', '－', '‐', '-']).map(parse_kansuji);
+      let base_number = nums.next().flatten().ok_or_else(err)?;
+      let eda_numbers = nums.collect::<Option<Vec<_>>>().ok_or_else(err)?;
+      return Ok(LawNum {
+        era,
+        year,
+        promulgate_month,
+        promulgate_day,
+        kind,
+        num: Some(ArticleNumber {
+          base_number,
+          eda_numbers,
+          range_end_numbers: Vec::new(),
+        }),
+      });
+    }
+    let (name, num) = match rest.split_once('第') {
+      Some((name, num)) => (name, Some(num)),
+      None => (rest, None),
+    };
+    let kind = LawNumKind::from_name(name).ok_or_else(err)?;
+    let num = match num {
+      Some(num) => {
+        let (base, eda) = num.split_once('号').ok_or_else(err)?;
+        let base_number = parse_kansuji(base).ok_or_else(err)?;
+        let mut eda_numbers = Vec::new();
+        if !eda.is_empty() {
+          let eda = eda.strip_prefix('の').ok_or_else(err)?;
+          for n in eda.split('の') {
+            eda_numbers.push(parse_kansuji(n).ok_or_else(err)?);
+          }
+        }
+        Some(ArticleNumber {
+          base_number,
+          eda_numbers,
+          range_end_numbers: Vec::new(),
+        })
+      }
+      None => None,
+    };
+    Ok(LawNum {
+      era,
+      year,
+      promulgate_month,
+      promulgate_day,
+      kind,
+      num,
+    })
+  }
+}
+
+impl std::fmt::Display for LawNum {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.format(kansuji))
+  }
+}
+
+#[test]
+fn check_law_num() {
+  let cases = [
+    "明治二十九年法律第八十九号",
+    "昭和二十一年憲法",
+    "平成元年政令第三百二十号",
+    "平成十二年内閣府令第十号",
+    "昭和二十七年人事院規則一―二",
+    "令和五年六月十四日法律第五十三号",
+  ];
+  let law_num = LawNum::from_str(cases[0]).unwrap();
+  assert_eq!(law_num.era, Era::Meiji);
+  assert_eq!(law_num.year, 29);
+  assert_eq!(law_num.kind, LawNumKind::Act);
+  assert_eq!(law_num.num.as_ref().map(|n| n.base_number), Some(89));
+  assert_eq!(law_num.to_string(), cases[0]);
+  let law_num = LawNum::from_str(cases[1]).unwrap();
+  assert_eq!(law_num.kind, LawNumKind::Constitution);
+  assert_eq!(law_num.num, None);
+  assert_eq!(law_num.to_string(), cases[1]);
+  let law_num = LawNum::from_str(cases[2]).unwrap();
+  assert_eq!(law_num.year, 1);
+  assert_eq!(law_num.to_string(), cases[2]);
+  let law_num = LawNum::from_str(cases[3]).unwrap();
+  assert_eq!(
+    law_num.kind,
+    LawNumKind::Ordinance {
+      issuer: "内閣府".to_string()
+    }
+  );
+  assert_eq!(law_num.law_type(), LawType::MinisterialOrdinance);
+  assert_eq!(law_num.to_string(), cases[3]);
+  let law_num = LawNum::from_str(cases[4]).unwrap();
+  assert_eq!(
+    law_num.kind,
+    LawNumKind::Rule {
+      issuer: "人事院".to_string()
+    }
+  );
+  assert_eq!(law_num.law_type(), LawType::Rule);
+  assert_eq!(
+    law_num.num,
+    Some(ArticleNumber::from_num_str("1_2").unwrap())
+  );
+  assert_eq!(law_num.to_string(), cases[4]);
+  let law_num = LawNum::from_str("平成十年人事院規則九―八").unwrap();
+  assert_eq!(
+    law_num.num,
+    Some(ArticleNumber::from_num_str("9_8").unwrap())
+  );
+  let law_num = LawNum::from_str("平成二年最高裁判所規則第一号").unwrap();
+  assert_eq!(law_num.law_type(), LawType::Rule);
+  assert_eq!(law_num.to_string(), "平成二年最高裁判所規則第一号");
+  assert!(LawNum::from_str("法律第八十九号").is_err());
+  assert!(LawNum::from_str("明治二十九年法律第八十九").is_err());
+  let law_num = LawNum::from_str("令和五年六月一四日法律第五三号").unwrap();
+  assert_eq!(law_num.promulgate_month, Some(6));
+  assert_eq!(law_num.promulgate_day, Some(14));
+  assert_eq!(law_num.to_string(), cases[5]);
+  assert_eq!(law_num, LawNum::from_str(cases[5]).unwrap());
+  assert_eq!(
+    law_num.to_amend_law_num_string(),
+    "令和五年六月一四日法律第五三号"
+  );
+  let law_num = LawNum::from_str("令和四年一二月一六日法律第一〇二号").unwrap();
+  assert_eq!(law_num.num.map(|n| n.base_number), Some(102));
+  let law_num = LawNum::from_str("令和元年五月一七日財務省令第二号の二").unwrap();
+  assert_eq!(law_num.num.map(|n| n.eda_numbers), Some(vec![2]));
+}
@@ -20,21 +20,32 @@
 
 #![recursion_limit = "256"]
 
+pub mod anchor;
 pub mod appdx;
 pub mod article;
 pub mod article_number;
 pub mod class;
 pub mod contents;
+pub mod corpus;
 pub mod fig;
+pub mod fragment;
+pub(crate) mod input;
+pub mod item_like;
 pub mod law;
+pub mod law_id;
+pub mod law_num;
 pub mod line;
 pub mod list;
 pub mod paragraph;
 mod parse_from_text;
 pub(crate) mod parser;
+pub mod promulgation_date;
 pub mod remarks;
+pub mod resolve;
 pub mod result;
+pub mod schema;
 pub mod sentence;
+pub mod sentences;
 pub mod structs;
 pub mod suppl_provision;
 pub mod table;
@@ -43,31 +54,144 @@ mod tests;
 pub mod text;
 pub(crate) mod to_xml;
 pub mod utils;
+pub mod visit;
+pub mod visit_mut;
+pub mod writer;
 
-use crate::parser::Parser;
-use koyomi::{era, Date};
+use crate::parser::Tag;
 use result::*;
 use std::io::{Read, Write};
+use std::path::Path;
+use std::sync::Arc;
 use to_xml::ToXmlElement;
-use xmltree::Element;
+use writer::WriteOptions;
+use xml::common::Position as _;
 
 /// XML文字列を法律の構造体に変換する
+///
+/// gzipやzipで圧縮されたXMLも展開して読み込む。
+/// コメントは取り除き、CDATAは文字列として読む。
+/// コメントやCDATAで区切られていた文字列は、区切られずに一続きの文字列になる。
 pub fn parse_xml(xml: &[u8]) -> Result<law::Law> {
-  let element = Element::parse(xml).map_err(|_| Error::XMLParsing)?;
-  let law = law::Law::parser(&element)?;
+  parse_xml_reader(xml)
+}
+
+/// 入力から読み込んだXMLを法律の構造体に変換する
+///
+/// gzipやzipで圧縮されたXMLも展開して読み込む。
+/// zipの場合はアーカイブ中の拡張子が`.xml`のファイルのうち、名前順で最初のものを読み込む。
+pub fn parse_xml_reader<R: Read>(r: R) -> Result<law::Law> {
+  let law = parser::parse_reader(input::decompress(r)?)?;
   Ok(law)
 }
 
+/// XML文字列を法律の構造体に変換する
+///
+/// スキーマに合わない要素があっても最初のエラーで止まらず、その要素を読み飛ばして変換を続ける。
+/// 読み飛ばした箇所は要素のパスと深刻度を持つ診断情報として返す。
+pub fn parse_xml_lenient(xml: &[u8]) -> Result<(law::Law, Vec<Diagnostic>)> {
+  parse_xml_reader_lenient(xml)
+}
+
+/// 入力から読み込んだXMLを法律の構造体に変換する
+///
+/// [`parse_xml_lenient`]と同じく、スキーマに合わない要素を読み飛ばして診断情報として返す。
+pub fn parse_xml_reader_lenient<R: Read>(r: R) -> Result<(law::Law, Vec<Diagnostic>)> {
+  let (law, diagnostics) = parser::parse_reader_lenient(input::decompress(r)?)?;
+  Ok((law, diagnostics))
+}
+
 /// ファイルのパスから法律の構造体に変換する
-pub fn parse_xml_file(path: &str) -> Result<law::Law> {
-  let mut file = std::fs::File::open(path).map_err(|_| result::Error::Io)?;
-  let mut buf = Vec::new();
-  file.read_to_end(&mut buf).map_err(|_| result::Error::Io)?;
-  let law = parse_xml(&buf)?;
-  Ok(law)
+pub fn parse_xml_file<P: AsRef<Path>>(path: P) -> Result<law::Law> {
+  let path = path.as_ref();
+  let file = open_file(path)?;
+  parse_xml_reader(file).map_err(|e| with_path(e, path))
+}
+
+/// ファイルのパスから法律の構造体に変換する
+///
+/// [`parse_xml_lenient`]と同じく、スキーマに合わない要素を読み飛ばして診断情報として返す。
+pub fn parse_xml_file_lenient<P: AsRef<Path>>(path: P) -> Result<(law::Law, Vec<Diagnostic>)> {
+  let path = path.as_ref();
+  let file = open_file(path)?;
+  parse_xml_reader_lenient(file).map_err(|e| with_path(e, path))
+}
+
+fn open_file(path: &Path) -> Result<std::fs::File> {
+  std::fs::File::open(path).map_err(|error| Error::FileIoError {
+    path: path.to_path_buf(),
+    error: Arc::new(error),
+  })
+}
+
+/// 入出力のエラーにファイルのパスをつける
+fn with_path(e: Error, path: &Path) -> Error {
+  match e {
+    Error::IoError(error) => Error::FileIoError {
+      path: path.to_path_buf(),
+      error,
+    },
+    e => e,
+  }
+}
+
+/// 法律の構造体を法令標準XMLスキーマに照らして検査する
+///
+/// 違反がなければ空のリストを返す。
+pub fn validate(law: &law::Law) -> Vec<schema::SchemaViolation> {
+  schema::validate_element(&law.to_xml_element())
+}
+
+/// XML文字列を法令標準XMLスキーマに照らして検査する
+pub fn validate_xml(xml: &[u8]) -> Result<Vec<schema::SchemaViolation>> {
+  let element = xmltree::Element::parse(xml).map_err(|e| match e {
+    xmltree::ParseError::MalformedXml(e) => Error::XMLParsing {
+      message: e.msg().to_string(),
+      position: e.position().into(),
+    },
+    xmltree::ParseError::CannotParse => Error::XMLParsing {
+      message: "root element not found".to_string(),
+      position: Position::default(),
+    },
+  })?;
+  Ok(schema::validate_element(&element))
+}
+
+/// テキストを解析するときの設定
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ParseTextOptions {
+  /// 別表などの表の欄の区切り
+  pub table_delimiter: TableDelimiter,
+}
+
+/// 表の欄の区切り
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub enum TableDelimiter {
+  /// タブ
+  #[default]
+  Tab,
+  /// 一つ以上続く空白（全角空白を含む）
+  Whitespace,
+  /// 指定した文字列
+  Str(String),
+}
+
+impl TableDelimiter {
+  /// 行を欄に分ける
+  pub(crate) fn split<'a>(&self, line: &'a str) -> Vec<&'a str> {
+    match self {
+      TableDelimiter::Tab => line.split('\t').map(|s| s.trim()).collect(),
+      TableDelimiter::Whitespace => line.split_whitespace().collect(),
+      TableDelimiter::Str(delimiter) => line.split(delimiter.as_str()).map(|s| s.trim()).collect(),
+    }
+  }
 }
 
 /// テキスト情報を法律の構造体に変換する
+///
+/// `year`・`month`・`day`には西暦の公布日を与える。公布月・公布日がない場合は一月一日として年号を求める。
+/// 存在しない日付や明治より前の日付の場合はエラーを返す。
+/// どこにも配置できない行があった場合は、最初のその行の行番号つきのエラーを返す。
 #[allow(clippy::too_many_arguments)]
 pub fn parse_text(
   text: &str,
@@ -80,25 +204,105 @@ pub fn parse_text(
   law_num: String,
   title: &str,
 ) -> Result<law::Law> {
-  let date = Date::from_ymd(
-    year as i32,
-    month.unwrap_or(1) as u32,
-    day.unwrap_or(1) as u32,
+  parse_text_with(
+    text,
+    year,
+    month,
+    day,
+    law_type,
+    lang,
+    num,
+    law_num,
+    title,
+    &ParseTextOptions::default(),
   )
-  .unwrap();
-  let e = era(&date).unwrap();
-  let era = match e.name().as_str() {
-    "明治" => law::Era::Meiji,
-    "大正" => law::Era::Taisho,
-    "昭和" => law::Era::Showa,
-    "平成" => law::Era::Heisei,
-    "令和" => law::Era::Reiwa,
-    _ => unreachable!(),
-  };
-  let body = parse_from_text::parse_body(title, text)?;
-  Ok(law::Law {
-    era,
+}
+
+/// 設定に従ってテキスト情報を法律の構造体に変換する
+#[allow(clippy::too_many_arguments)]
+pub fn parse_text_with(
+  text: &str,
+  year: usize,
+  month: Option<usize>,
+  day: Option<usize>,
+  law_type: law::LawType,
+  lang: law::Lang,
+  num: Option<article_number::ArticleNumber>,
+  law_num: String,
+  title: &str,
+  options: &ParseTextOptions,
+) -> Result<law::Law> {
+  let (law, diagnostics) = parse_text_lenient_with(
+    text, year, month, day, law_type, lang, num, law_num, title, options,
+  )?;
+  match diagnostics
+    .into_iter()
+    .find(|d| d.severity == Severity::Error)
+  {
+    Some(d) => Err(Error::Located {
+      error: Box::new(d.error),
+      path: d.path,
+      position: d.position,
+    }),
+    None => Ok(law),
+  }
+}
+
+/// テキスト情報を法律の構造体に変換する
+///
+/// [`parse_text`]と異なり、どこにも配置できない行があっても読み飛ばして変換を続ける。
+/// 読み飛ばした行や、条につかなかった見出し、番号として読めなかった行頭などは、行番号つきの診断情報として返す。
+#[allow(clippy::too_many_arguments)]
+pub fn parse_text_lenient(
+  text: &str,
+  year: usize,
+  month: Option<usize>,
+  day: Option<usize>,
+  law_type: law::LawType,
+  lang: law::Lang,
+  num: Option<article_number::ArticleNumber>,
+  law_num: String,
+  title: &str,
+) -> Result<(law::Law, Vec<Diagnostic>)> {
+  parse_text_lenient_with(
+    text,
     year,
+    month,
+    day,
+    law_type,
+    lang,
+    num,
+    law_num,
+    title,
+    &ParseTextOptions::default(),
+  )
+}
+
+/// 設定に従ってテキスト情報を法律の構造体に変換する
+///
+/// [`parse_text_lenient`]と同じく、配置できなかった行などを診断情報として返す。
+#[allow(clippy::too_many_arguments)]
+pub fn parse_text_lenient_with(
+  text: &str,
+  year: usize,
+  month: Option<usize>,
+  day: Option<usize>,
+  law_type: law::LawType,
+  lang: law::Lang,
+  num: Option<article_number::ArticleNumber>,
+  law_num: String,
+  title: &str,
+  options: &ParseTextOptions,
+) -> Result<(law::Law, Vec<Diagnostic>)> {
+  let date = promulgation_date::PromulgationDate::from_gregorian(
+    year,
+    month.unwrap_or(1),
+    day.unwrap_or(1),
+  )?;
+  let (body, diagnostics) = parse_from_text::parse_body(title, text, options);
+  let law = law::Law {
+    era: date.era,
+    year: date.year,
     num,
     promulgate_month: month,
     promulgate_day: day,
@@ -106,7 +310,76 @@ pub fn parse_text(
     lang,
     law_num,
     law_body: body,
-  })
+  };
+  Ok((law, diagnostics))
+}
+
+/// 題名・法令番号・制定文から始まるテキストを法律の構造体に変換する
+///
+/// 一行目を題名、二行目の`（明治二十九年法律第八十九号）`のような括弧の中を法令番号とし、
+/// 法令番号から年号・制定年・公布日・法令の種類・番号を求める。
+/// 法令番号の後に続く、見出しや条でない行は制定文とする。
+/// 制定文と前文は書式では区別できないため、制定文は空行か`前文`の行までとし、その後の行を前文とする。
+/// 空行も`前文`の行もない場合、目次や条の前の行はすべて制定文になり、前文は作られない。
+/// これらを別に与える場合は[`parse_text`]を使う。
+///
+/// ```
+/// let text = "民法
+/// （明治二十九年法律第八十九号）
+/// 第一条　私権は、公共の福祉に適合しなければならない。";
+/// let law = japanese_law_xml_schema::parse_text_document(text).unwrap();
+/// assert_eq!(law.law_num, "明治二十九年法律第八十九号");
+/// assert_eq!(law.year, 29);
+/// ```
+pub fn parse_text_document(text: &str) -> Result<law::Law> {
+  parse_text_document_with(text, &ParseTextOptions::default())
+}
+
+/// 設定に従って、題名・法令番号・制定文から始まるテキストを法律の構造体に変換する
+pub fn parse_text_document_with(text: &str, options: &ParseTextOptions) -> Result<law::Law> {
+  let (law, diagnostics) = parse_text_document_lenient_with(text, options)?;
+  match diagnostics
+    .into_iter()
+    .find(|d| d.severity == Severity::Error)
+  {
+    Some(d) => Err(Error::Located {
+      error: Box::new(d.error),
+      path: d.path,
+      position: d.position,
+    }),
+    None => Ok(law),
+  }
+}
+
+/// 題名・法令番号・制定文から始まるテキストを法律の構造体に変換する
+///
+/// [`parse_text_document`]と異なり、どこにも配置できない行があっても読み飛ばして変換を続ける。
+/// 法令番号が読めない場合はエラーを返す。
+pub fn parse_text_document_lenient(text: &str) -> Result<(law::Law, Vec<Diagnostic>)> {
+  parse_text_document_lenient_with(text, &ParseTextOptions::default())
+}
+
+/// 設定に従って、題名・法令番号・制定文から始まるテキストを法律の構造体に変換する
+///
+/// [`parse_text_document_lenient`]と同じく、配置できなかった行などを診断情報として返す。
+pub fn parse_text_document_lenient_with(
+  text: &str,
+  options: &ParseTextOptions,
+) -> Result<(law::Law, Vec<Diagnostic>)> {
+  let (law_num, body, diagnostics) = parse_from_text::parse_document(text, options)?;
+  let n: law_num::LawNum = law_num.parse()?;
+  let law = law::Law {
+    law_type: n.law_type(),
+    era: n.era,
+    year: n.year,
+    num: n.num,
+    promulgate_month: n.promulgate_month,
+    promulgate_day: n.promulgate_day,
+    lang: law::Lang::Ja,
+    law_num,
+    law_body: body,
+  };
+  Ok((law, diagnostics))
 }
 
 struct WritableString(String);
@@ -134,31 +407,36 @@ impl Write for WritableString {
   }
 }
 
-fn gen_config() -> xmltree::EmitterConfig {
-  xmltree::EmitterConfig::new().perform_indent(true)
-}
-
 /// XML文字列に変換する
+///
+/// `parse_xml`で読み込んだ法律を書き出して再び`parse_xml`で読み込むと、元と等しい構造体になる。
+/// ただしコメントは読み込み時に取り除かれ、コメントやCDATAで区切られていた文字列は一続きの文字列として扱われる。
 pub fn to_xml(law: &law::Law) -> result::Result<String> {
+  to_xml_with(law, &WriteOptions::default())
+}
+
+/// 設定に従ってXML文字列に変換する
+pub fn to_xml_with(law: &law::Law, options: &WriteOptions) -> result::Result<String> {
   let mut s = WritableString::new();
-  let config = gen_config();
-  law
-    .to_xml_element()
-    .write_with_config(&mut s, config)
-    .map_err(|_| result::Error::Io)?;
+  write_file_with(law, &mut s, options)?;
   Ok(s.string())
 }
 
 /// XML文字列をファイルに書き出す
 pub fn write_file<W: Write>(law: &law::Law, w: &mut W) -> result::Result<()> {
-  let config = gen_config();
-  law
-    .to_xml_element()
-    .write_with_config(w, config)
-    .map_err(|_| result::Error::Io)
+  write_file_with(law, w, &WriteOptions::default())
+}
+
+/// 設定に従ってXML文字列をファイルに書き出す
+pub fn write_file_with<W: Write>(
+  law: &law::Law,
+  w: &mut W,
+  options: &WriteOptions,
+) -> result::Result<()> {
+  writer::write_element(&law.to_xml_element(), w, options)
 }
 
-pub(crate) fn get_attribute(element: &Element, name: &str) -> Result<String> {
+pub(crate) fn get_attribute(element: &Tag, name: &str) -> Result<String> {
   let att_opt = element.attributes.get(name);
   match att_opt {
     Some(s) => Ok(s.to_string()),
@@ -169,7 +447,7 @@ pub(crate) fn get_attribute(element: &Element, name: &str) -> Result<String> {
   }
 }
 
-pub(crate) fn get_attribute_with_parse<T>(element: &Element, name: &str) -> Result<T>
+pub(crate) fn get_attribute_with_parse<T>(element: &Tag, name: &str) -> Result<T>
 where
   T: std::str::FromStr,
 {
@@ -192,7 +470,7 @@ where
   }
 }
 
-pub(crate) fn get_attribute_opt_with_parse<T>(element: &Element, name: &str) -> Result<Option<T>>
+pub(crate) fn get_attribute_opt_with_parse<T>(element: &Tag, name: &str) -> Result<Option<T>>
 where
   T: std::str::FromStr,
 {
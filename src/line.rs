@@ -16,7 +16,7 @@ pub struct Line {
 }
 
 impl Parser for Line {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Line" {
       let style = LineStyle::from_attribute(element.attributes.get("Style")).ok_or(
         Error::AttributeParseError {
@@ -25,35 +25,36 @@ impl Parser for Line {
         },
       )?;
       let mut contents = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "QuoteStruct" => {
-              let v = QuoteStruct::parser(e)?;
+              let v = QuoteStruct::parser(reader, &e)?;
               contents.push(LineContents::QuoteStruct(v))
             }
             "ArithFormula" => {
-              let v = ArithFormula::parser(e)?;
+              let v = ArithFormula::parser(reader, &e)?;
               contents.push(LineContents::ArithFormula(v))
             }
             "Ruby" => {
-              let v = Ruby::parser(e)?;
+              let v = Ruby::parser(reader, &e)?;
               contents.push(LineContents::Ruby(v))
             }
             "Sup" => {
-              let v = Sup::parser(e)?;
+              let v = Sup::parser(reader, &e)?;
               contents.push(LineContents::Sup(v))
             }
             "Sub" => {
-              let v = Sub::parser(e)?;
+              let v = Sub::parser(reader, &e)?;
               contents.push(LineContents::Sub(v))
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
-        } else if let XMLNode::Text(s) = node {
-          contents.push(LineContents::String(s.clone()))
+        } else if let Node::Text(s) = node {
+          contents.push(LineContents::String(s))
         }
-      }
+        Ok(())
+      })?;
       Ok(Line { contents, style })
     } else {
       Err(Error::wrong_tag_name(element, "Line"))
@@ -105,7 +106,7 @@ impl LineStyle {
       Some("solid") => Some(LineStyle::Solid),
       Some("none") => Some(LineStyle::None),
       Some("dotted") => Some(LineStyle::Dotted),
-      Some("double") => Some(LineStyle::Dotted),
+      Some("double") => Some(LineStyle::Double),
       _ => None,
     }
   }
@@ -23,45 +23,44 @@ impl List {
 }
 
 impl Parser for List {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "List" {
-      let mut children = element.children.iter();
-      let list_sentence = children.next();
-      let mut sentence = Vec::new();
-      if let Some("ListSentence") = list_sentence.and_then(|node| {
-        if let XMLNode::Element(e) = node {
-          Some(e.name.as_str())
+      let list_sentence = reader.next_child(element)?.and_then(|node| {
+        if let Node::Element(e) = node {
+          Some(e)
         } else {
           None
         }
-      }) {
-        if let XMLNode::Element(e) = list_sentence.unwrap() {
-          for node in e.children.iter() {
-            if let XMLNode::Element(e) = node {
-              match e.name.as_str() {
-                "Sentence" => {
-                  let v = Sentence::parser(e)?;
-                  sentence.push(ListSentence::Sentence(v))
-                }
-                "Column" => {
-                  let v = Column::parser(e)?;
-                  sentence.push(ListSentence::Column(v))
-                }
-                s => return Err(Error::unexpected_tag(element, s)),
+      });
+      let mut sentence = Vec::new();
+      if let Some(e) = list_sentence.filter(|e| e.name.as_str() == "ListSentence") {
+        reader.each_child(&e, |reader, node| {
+          if let Node::Element(e) = node {
+            match e.name.as_str() {
+              "Sentence" => {
+                let v = Sentence::parser(reader, &e)?;
+                sentence.push(ListSentence::Sentence(v))
+              }
+              "Column" => {
+                let v = Column::parser(reader, &e)?;
+                sentence.push(ListSentence::Column(v))
               }
+              s => return Err(Error::unexpected_tag(element, s)),
             }
           }
-        }
+          Ok(())
+        })?;
       } else {
         return Err(Error::wrong_tag_name(element, "LiseSentence"));
       }
       let mut sublist = Vec::new();
-      for node in children {
-        if let XMLNode::Element(e) = node {
-          let v = Sublist1::parser(e)?;
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
+          let v = Sublist1::parser(reader, &e)?;
           sublist.push(v)
         }
-      }
+        Ok(())
+      })?;
       Ok(List::new(sentence, sublist))
     } else {
       Err(Error::wrong_tag_name(element, "List"))
@@ -106,45 +105,44 @@ impl Sublist1 {
 }
 
 impl Parser for Sublist1 {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Sublist1" {
-      let mut children = element.children.iter();
-      let list_sentence = children.next();
-      let mut sentence = Vec::new();
-      if let Some("Sublist1Sentence") = list_sentence.and_then(|node| {
-        if let XMLNode::Element(e) = node {
-          Some(e.name.as_str())
+      let list_sentence = reader.next_child(element)?.and_then(|node| {
+        if let Node::Element(e) = node {
+          Some(e)
         } else {
           None
         }
-      }) {
-        if let XMLNode::Element(e) = list_sentence.unwrap() {
-          for node in e.children.iter() {
-            if let XMLNode::Element(e) = node {
-              match e.name.as_str() {
-                "Sentence" => {
-                  let v = Sentence::parser(e)?;
-                  sentence.push(ListSentence::Sentence(v))
-                }
-                "Column" => {
-                  let v = Column::parser(e)?;
-                  sentence.push(ListSentence::Column(v))
-                }
-                s => return Err(Error::unexpected_tag(element, s)),
+      });
+      let mut sentence = Vec::new();
+      if let Some(e) = list_sentence.filter(|e| e.name.as_str() == "Sublist1Sentence") {
+        reader.each_child(&e, |reader, node| {
+          if let Node::Element(e) = node {
+            match e.name.as_str() {
+              "Sentence" => {
+                let v = Sentence::parser(reader, &e)?;
+                sentence.push(ListSentence::Sentence(v))
+              }
+              "Column" => {
+                let v = Column::parser(reader, &e)?;
+                sentence.push(ListSentence::Column(v))
               }
+              s => return Err(Error::unexpected_tag(element, s)),
             }
           }
-        }
+          Ok(())
+        })?;
       } else {
         return Err(Error::wrong_tag_name(element, "Sublist1Sentence"));
       }
       let mut sublist = Vec::new();
-      for node in children {
-        if let XMLNode::Element(e) = node {
-          let v = Sublist2::parser(e)?;
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
+          let v = Sublist2::parser(reader, &e)?;
           sublist.push(v)
         }
-      }
+        Ok(())
+      })?;
       Ok(Sublist1::new(sentence, sublist))
     } else {
       Err(Error::wrong_tag_name(element, "Sublist1"))
@@ -183,45 +181,44 @@ impl Sublist2 {
 }
 
 impl Parser for Sublist2 {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Sublist2" {
-      let mut children = element.children.iter();
-      let list_sentence = children.next();
-      let mut sentence = Vec::new();
-      if let Some("Sublist2Sentence") = list_sentence.and_then(|node| {
-        if let XMLNode::Element(e) = node {
-          Some(e.name.as_str())
+      let list_sentence = reader.next_child(element)?.and_then(|node| {
+        if let Node::Element(e) = node {
+          Some(e)
         } else {
           None
         }
-      }) {
-        if let XMLNode::Element(e) = list_sentence.unwrap() {
-          for node in e.children.iter() {
-            if let XMLNode::Element(e) = node {
-              match e.name.as_str() {
-                "Sentence" => {
-                  let v = Sentence::parser(e)?;
-                  sentence.push(ListSentence::Sentence(v))
-                }
-                "Column" => {
-                  let v = Column::parser(e)?;
-                  sentence.push(ListSentence::Column(v))
-                }
-                s => return Err(Error::unexpected_tag(element, s)),
+      });
+      let mut sentence = Vec::new();
+      if let Some(e) = list_sentence.filter(|e| e.name.as_str() == "Sublist2Sentence") {
+        reader.each_child(&e, |reader, node| {
+          if let Node::Element(e) = node {
+            match e.name.as_str() {
+              "Sentence" => {
+                let v = Sentence::parser(reader, &e)?;
+                sentence.push(ListSentence::Sentence(v))
+              }
+              "Column" => {
+                let v = Column::parser(reader, &e)?;
+                sentence.push(ListSentence::Column(v))
               }
+              s => return Err(Error::unexpected_tag(element, s)),
             }
           }
-        }
+          Ok(())
+        })?;
       } else {
         return Err(Error::wrong_tag_name(element, "Sublist2Sentence"));
       }
       let mut sublist = Vec::new();
-      for node in children {
-        if let XMLNode::Element(e) = node {
-          let v = Sublist3::parser(e)?;
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
+          let v = Sublist3::parser(reader, &e)?;
           sublist.push(v)
         }
-      }
+        Ok(())
+      })?;
       Ok(Sublist2::new(sentence, sublist))
     } else {
       Err(Error::wrong_tag_name(element, "Sublist2"))
@@ -259,35 +256,33 @@ impl Sublist3 {
 }
 
 impl Parser for Sublist3 {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Sublist3" {
-      let mut children = element.children.iter();
-      let list_sentence = children.next();
-      let mut sentence = Vec::new();
-      if let Some("Sublist3Sentence") = list_sentence.and_then(|node| {
-        if let XMLNode::Element(e) = node {
-          Some(e.name.as_str())
+      let list_sentence = reader.next_child(element)?.and_then(|node| {
+        if let Node::Element(e) = node {
+          Some(e)
         } else {
           None
         }
-      }) {
-        if let XMLNode::Element(e) = list_sentence.unwrap() {
-          for node in e.children.iter() {
-            if let XMLNode::Element(e) = node {
-              match e.name.as_str() {
-                "Sentence" => {
-                  let v = Sentence::parser(e)?;
-                  sentence.push(ListSentence::Sentence(v))
-                }
-                "Column" => {
-                  let v = Column::parser(e)?;
-                  sentence.push(ListSentence::Column(v))
-                }
-                s => return Err(Error::unexpected_tag(element, s)),
+      });
+      let mut sentence = Vec::new();
+      if let Some(e) = list_sentence.filter(|e| e.name.as_str() == "Sublist3Sentence") {
+        reader.each_child(&e, |reader, node| {
+          if let Node::Element(e) = node {
+            match e.name.as_str() {
+              "Sentence" => {
+                let v = Sentence::parser(reader, &e)?;
+                sentence.push(ListSentence::Sentence(v))
               }
+              "Column" => {
+                let v = Column::parser(reader, &e)?;
+                sentence.push(ListSentence::Column(v))
+              }
+              s => return Err(Error::unexpected_tag(element, s)),
             }
           }
-        }
+          Ok(())
+        })?;
       } else {
         return Err(Error::wrong_tag_name(element, "Sublist3Sentence"));
       }
@@ -30,7 +30,7 @@ pub struct Paragraph {
 }
 
 impl Parser for Paragraph {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Paragraph" {
       let num = ArticleNumber::from_num_str(&get_attribute_with_parse::<String>(element, "Num")?)?;
       let old_style = get_attribute_opt_with_parse(element, "OldStyle")?.unwrap_or(false);
@@ -43,56 +43,58 @@ impl Parser for Paragraph {
       let mut class = Vec::new();
       let mut struct_list = Vec::new();
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "ParagraphCaption" => {
-              let v = Caption::parser(e)?;
+              let v = Caption::parser(reader, &e)?;
               caption = Some(v);
             }
             "ParagraphNum" => {
-              paragraph_num = Text::from_children(&e.children);
+              paragraph_num = Text::from_reader(reader, &e)?;
             }
             "ParagraphSentence" => {
-              for node in e.children.iter() {
-                if let XMLNode::Element(e) = node {
-                  let v = Sentence::parser(e)?;
+              reader.each_child(&e, |reader, node| {
+                if let Node::Element(e) = node {
+                  let v = Sentence::parser(reader, &e)?;
                   sentence.push(v);
                 }
-              }
+                Ok(())
+              })?;
             }
             "AmendProvision" => {
-              let v = AmendProvision::parser(e)?;
+              let v = AmendProvision::parser(reader, &e)?;
               amend_provision.push(v);
             }
             "Class" => {
-              let v = Class::parser(e)?;
+              let v = Class::parser(reader, &e)?;
               class.push(v);
             }
             "TableStruct" => {
-              let v = TableStruct::parser(e)?;
+              let v = TableStruct::parser(reader, &e)?;
               struct_list.push(Struct::TableStruct(v));
             }
             "FigStruct" => {
-              let v = FigStruct::parser(e)?;
+              let v = FigStruct::parser(reader, &e)?;
               struct_list.push(Struct::FigStruct(v));
             }
             "StyleStruct" => {
-              let v = StyleStruct::parser(e)?;
+              let v = StyleStruct::parser(reader, &e)?;
               struct_list.push(Struct::StyleStruct(v));
             }
             "List" => {
-              let v = List::parser(e)?;
+              let v = List::parser(reader, &e)?;
               struct_list.push(Struct::List(v));
             }
             "Item" => {
-              let v = Item::parser(e)?;
+              let v = Item::parser(reader, &e)?;
               children.push(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(Paragraph {
         caption,
         paragraph_num,
@@ -194,7 +196,7 @@ impl Item {
 }
 
 impl Parser for Item {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Item" {
       let num =
         ArticleNumber::from_num_str(&get_attribute_with_parse::<String>(element, "Num")?).ok();
@@ -204,40 +206,41 @@ impl Parser for Item {
       let mut sentence_opt = None;
       let mut struct_list = Vec::new();
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "ItemTitle" => {
-              title = Some(Text::from_children(&e.children));
+              title = Some(Text::from_reader(reader, &e)?);
             }
             "ItemSentence" => {
-              let v = SentenceOrColumnOrTable::from_element(e)?;
+              let v = SentenceOrColumnOrTable::from_element(reader, &e)?;
               sentence_opt = Some(v);
             }
             "TableStruct" => {
-              let v = TableStruct::parser(e)?;
+              let v = TableStruct::parser(reader, &e)?;
               struct_list.push(Struct::TableStruct(v));
             }
             "FigStruct" => {
-              let v = FigStruct::parser(e)?;
+              let v = FigStruct::parser(reader, &e)?;
               struct_list.push(Struct::FigStruct(v));
             }
             "StyleStruct" => {
-              let v = StyleStruct::parser(e)?;
+              let v = StyleStruct::parser(reader, &e)?;
               struct_list.push(Struct::StyleStruct(v));
             }
             "List" => {
-              let v = List::parser(e)?;
+              let v = List::parser(reader, &e)?;
               struct_list.push(Struct::List(v));
             }
             "Subitem1" => {
-              let v = Subitem1::parser(e)?;
+              let v = Subitem1::parser(reader, &e)?;
               children.push(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(sentence) = sentence_opt {
         Ok(Item::new(
           title,
@@ -338,7 +341,7 @@ impl Subitem1 {
 }
 
 impl Parser for Subitem1 {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Subitem1" {
       let num =
         ArticleNumber::from_num_str(&get_attribute_with_parse::<String>(element, "Num")?).ok();
@@ -348,40 +351,41 @@ impl Parser for Subitem1 {
       let mut sentence_opt = None;
       let mut struct_list = Vec::new();
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Subitem1Title" => {
-              title = Some(Text::from_children(&e.children));
+              title = Some(Text::from_reader(reader, &e)?);
             }
             "Subitem1Sentence" => {
-              let v = SentenceOrColumnOrTable::from_element(e)?;
+              let v = SentenceOrColumnOrTable::from_element(reader, &e)?;
               sentence_opt = Some(v);
             }
             "TableStruct" => {
-              let v = TableStruct::parser(e)?;
+              let v = TableStruct::parser(reader, &e)?;
               struct_list.push(Struct::TableStruct(v));
             }
             "FigStruct" => {
-              let v = FigStruct::parser(e)?;
+              let v = FigStruct::parser(reader, &e)?;
               struct_list.push(Struct::FigStruct(v));
             }
             "StyleStruct" => {
-              let v = StyleStruct::parser(e)?;
+              let v = StyleStruct::parser(reader, &e)?;
               struct_list.push(Struct::StyleStruct(v));
             }
             "List" => {
-              let v = List::parser(e)?;
+              let v = List::parser(reader, &e)?;
               struct_list.push(Struct::List(v));
             }
             "Subitem2" => {
-              let v = Subitem2::parser(e)?;
+              let v = Subitem2::parser(reader, &e)?;
               children.push(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(sentence) = sentence_opt {
         Ok(Subitem1::new(
           title,
@@ -482,7 +486,7 @@ impl Subitem2 {
 }
 
 impl Parser for Subitem2 {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Subitem2" {
       let num =
         ArticleNumber::from_num_str(&get_attribute_with_parse::<String>(element, "Num")?).ok();
@@ -492,40 +496,41 @@ impl Parser for Subitem2 {
       let mut sentence_opt = None;
       let mut struct_list = Vec::new();
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Subitem2Title" => {
-              title = Some(Text::from_children(&e.children));
+              title = Some(Text::from_reader(reader, &e)?);
             }
             "Subitem2Sentence" => {
-              let v = SentenceOrColumnOrTable::from_element(e)?;
+              let v = SentenceOrColumnOrTable::from_element(reader, &e)?;
               sentence_opt = Some(v);
             }
             "TableStruct" => {
-              let v = TableStruct::parser(e)?;
+              let v = TableStruct::parser(reader, &e)?;
               struct_list.push(Struct::TableStruct(v));
             }
             "FigStruct" => {
-              let v = FigStruct::parser(e)?;
+              let v = FigStruct::parser(reader, &e)?;
               struct_list.push(Struct::FigStruct(v));
             }
             "StyleStruct" => {
-              let v = StyleStruct::parser(e)?;
+              let v = StyleStruct::parser(reader, &e)?;
               struct_list.push(Struct::StyleStruct(v));
             }
             "List" => {
-              let v = List::parser(e)?;
+              let v = List::parser(reader, &e)?;
               struct_list.push(Struct::List(v));
             }
             "Subitem3" => {
-              let v = Subitem3::parser(e)?;
+              let v = Subitem3::parser(reader, &e)?;
               children.push(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(sentence) = sentence_opt {
         Ok(Subitem2::new(
           title,
@@ -626,7 +631,7 @@ impl Subitem3 {
 }
 
 impl Parser for Subitem3 {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Subitem3" {
       let num =
         ArticleNumber::from_num_str(&get_attribute_with_parse::<String>(element, "Num")?).ok();
@@ -636,40 +641,41 @@ impl Parser for Subitem3 {
       let mut sentence_opt = None;
       let mut struct_list = Vec::new();
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Subitem3Title" => {
-              title = Some(Text::from_children(&e.children));
+              title = Some(Text::from_reader(reader, &e)?);
             }
             "Subitem3Sentence" => {
-              let v = SentenceOrColumnOrTable::from_element(e)?;
+              let v = SentenceOrColumnOrTable::from_element(reader, &e)?;
               sentence_opt = Some(v);
             }
             "TableStruct" => {
-              let v = TableStruct::parser(e)?;
+              let v = TableStruct::parser(reader, &e)?;
               struct_list.push(Struct::TableStruct(v));
             }
             "FigStruct" => {
-              let v = FigStruct::parser(e)?;
+              let v = FigStruct::parser(reader, &e)?;
               struct_list.push(Struct::FigStruct(v));
             }
             "StyleStruct" => {
-              let v = StyleStruct::parser(e)?;
+              let v = StyleStruct::parser(reader, &e)?;
               struct_list.push(Struct::StyleStruct(v));
             }
             "List" => {
-              let v = List::parser(e)?;
+              let v = List::parser(reader, &e)?;
               struct_list.push(Struct::List(v));
             }
             "Subitem4" => {
-              let v = Subitem4::parser(e)?;
+              let v = Subitem4::parser(reader, &e)?;
               children.push(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(sentence) = sentence_opt {
         Ok(Subitem3::new(
           title,
@@ -770,7 +776,7 @@ impl Subitem4 {
 }
 
 impl Parser for Subitem4 {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Subitem4" {
       let num =
         ArticleNumber::from_num_str(&get_attribute_with_parse::<String>(element, "Num")?).ok();
@@ -780,40 +786,41 @@ impl Parser for Subitem4 {
       let mut sentence_opt = None;
       let mut struct_list = Vec::new();
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Subitem4Title" => {
-              title = Some(Text::from_children(&e.children));
+              title = Some(Text::from_reader(reader, &e)?);
             }
             "Subitem4Sentence" => {
-              let v = SentenceOrColumnOrTable::from_element(e)?;
+              let v = SentenceOrColumnOrTable::from_element(reader, &e)?;
               sentence_opt = Some(v);
             }
             "TableStruct" => {
-              let v = TableStruct::parser(e)?;
+              let v = TableStruct::parser(reader, &e)?;
               struct_list.push(Struct::TableStruct(v));
             }
             "FigStruct" => {
-              let v = FigStruct::parser(e)?;
+              let v = FigStruct::parser(reader, &e)?;
               struct_list.push(Struct::FigStruct(v));
             }
             "StyleStruct" => {
-              let v = StyleStruct::parser(e)?;
+              let v = StyleStruct::parser(reader, &e)?;
               struct_list.push(Struct::StyleStruct(v));
             }
             "List" => {
-              let v = List::parser(e)?;
+              let v = List::parser(reader, &e)?;
               struct_list.push(Struct::List(v));
             }
             "Subitem5" => {
-              let v = Subitem5::parser(e)?;
+              let v = Subitem5::parser(reader, &e)?;
               children.push(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(sentence) = sentence_opt {
         Ok(Subitem4::new(
           title,
@@ -914,7 +921,7 @@ impl Subitem5 {
 }
 
 impl Parser for Subitem5 {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Subitem5" {
       let num =
         ArticleNumber::from_num_str(&get_attribute_with_parse::<String>(element, "Num")?).ok();
@@ -924,40 +931,41 @@ impl Parser for Subitem5 {
       let mut sentence_opt = None;
       let mut struct_list = Vec::new();
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Subitem5Title" => {
-              title = Some(Text::from_children(&e.children));
+              title = Some(Text::from_reader(reader, &e)?);
             }
             "Subitem5Sentence" => {
-              let v = SentenceOrColumnOrTable::from_element(e)?;
+              let v = SentenceOrColumnOrTable::from_element(reader, &e)?;
               sentence_opt = Some(v);
             }
             "TableStruct" => {
-              let v = TableStruct::parser(e)?;
+              let v = TableStruct::parser(reader, &e)?;
               struct_list.push(Struct::TableStruct(v));
             }
             "FigStruct" => {
-              let v = FigStruct::parser(e)?;
+              let v = FigStruct::parser(reader, &e)?;
               struct_list.push(Struct::FigStruct(v));
             }
             "StyleStruct" => {
-              let v = StyleStruct::parser(e)?;
+              let v = StyleStruct::parser(reader, &e)?;
               struct_list.push(Struct::StyleStruct(v));
             }
             "List" => {
-              let v = List::parser(e)?;
+              let v = List::parser(reader, &e)?;
               struct_list.push(Struct::List(v));
             }
             "Subitem6" => {
-              let v = Subitem6::parser(e)?;
+              let v = Subitem6::parser(reader, &e)?;
               children.push(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(sentence) = sentence_opt {
         Ok(Subitem5::new(
           title,
@@ -1058,7 +1066,7 @@ impl Subitem6 {
 }
 
 impl Parser for Subitem6 {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Subitem6" {
       let num =
         ArticleNumber::from_num_str(&get_attribute_with_parse::<String>(element, "Num")?).ok();
@@ -1068,40 +1076,41 @@ impl Parser for Subitem6 {
       let mut sentence_opt = None;
       let mut struct_list = Vec::new();
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Subitem6Title" => {
-              title = Some(Text::from_children(&e.children));
+              title = Some(Text::from_reader(reader, &e)?);
             }
             "Subitem6Sentence" => {
-              let v = SentenceOrColumnOrTable::from_element(e)?;
+              let v = SentenceOrColumnOrTable::from_element(reader, &e)?;
               sentence_opt = Some(v);
             }
             "TableStruct" => {
-              let v = TableStruct::parser(e)?;
+              let v = TableStruct::parser(reader, &e)?;
               struct_list.push(Struct::TableStruct(v));
             }
             "FigStruct" => {
-              let v = FigStruct::parser(e)?;
+              let v = FigStruct::parser(reader, &e)?;
               struct_list.push(Struct::FigStruct(v));
             }
             "StyleStruct" => {
-              let v = StyleStruct::parser(e)?;
+              let v = StyleStruct::parser(reader, &e)?;
               struct_list.push(Struct::StyleStruct(v));
             }
             "List" => {
-              let v = List::parser(e)?;
+              let v = List::parser(reader, &e)?;
               struct_list.push(Struct::List(v));
             }
             "Subitem7" => {
-              let v = Subitem7::parser(e)?;
+              let v = Subitem7::parser(reader, &e)?;
               children.push(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(sentence) = sentence_opt {
         Ok(Subitem6::new(
           title,
@@ -1202,7 +1211,7 @@ impl Subitem7 {
 }
 
 impl Parser for Subitem7 {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Subitem7" {
       let num =
         ArticleNumber::from_num_str(&get_attribute_with_parse::<String>(element, "Num")?).ok();
@@ -1212,40 +1221,41 @@ impl Parser for Subitem7 {
       let mut sentence_opt = None;
       let mut struct_list = Vec::new();
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Subitem7Title" => {
-              title = Some(Text::from_children(&e.children));
+              title = Some(Text::from_reader(reader, &e)?);
             }
             "Subitem7Sentence" => {
-              let v = SentenceOrColumnOrTable::from_element(e)?;
+              let v = SentenceOrColumnOrTable::from_element(reader, &e)?;
               sentence_opt = Some(v);
             }
             "TableStruct" => {
-              let v = TableStruct::parser(e)?;
+              let v = TableStruct::parser(reader, &e)?;
               struct_list.push(Struct::TableStruct(v));
             }
             "FigStruct" => {
-              let v = FigStruct::parser(e)?;
+              let v = FigStruct::parser(reader, &e)?;
               struct_list.push(Struct::FigStruct(v));
             }
             "StyleStruct" => {
-              let v = StyleStruct::parser(e)?;
+              let v = StyleStruct::parser(reader, &e)?;
               struct_list.push(Struct::StyleStruct(v));
             }
             "List" => {
-              let v = List::parser(e)?;
+              let v = List::parser(reader, &e)?;
               struct_list.push(Struct::List(v));
             }
             "Subitem8" => {
-              let v = Subitem8::parser(e)?;
+              let v = Subitem8::parser(reader, &e)?;
               children.push(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(sentence) = sentence_opt {
         Ok(Subitem7::new(
           title,
@@ -1346,7 +1356,7 @@ impl Subitem8 {
 }
 
 impl Parser for Subitem8 {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Subitem8" {
       let num =
         ArticleNumber::from_num_str(&get_attribute_with_parse::<String>(element, "Num")?).ok();
@@ -1356,40 +1366,41 @@ impl Parser for Subitem8 {
       let mut sentence_opt = None;
       let mut struct_list = Vec::new();
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Subitem8Title" => {
-              title = Some(Text::from_children(&e.children));
+              title = Some(Text::from_reader(reader, &e)?);
             }
             "Subitem8Sentence" => {
-              let v = SentenceOrColumnOrTable::from_element(e)?;
+              let v = SentenceOrColumnOrTable::from_element(reader, &e)?;
               sentence_opt = Some(v);
             }
             "TableStruct" => {
-              let v = TableStruct::parser(e)?;
+              let v = TableStruct::parser(reader, &e)?;
               struct_list.push(Struct::TableStruct(v));
             }
             "FigStruct" => {
-              let v = FigStruct::parser(e)?;
+              let v = FigStruct::parser(reader, &e)?;
               struct_list.push(Struct::FigStruct(v));
             }
             "StyleStruct" => {
-              let v = StyleStruct::parser(e)?;
+              let v = StyleStruct::parser(reader, &e)?;
               struct_list.push(Struct::StyleStruct(v));
             }
             "List" => {
-              let v = List::parser(e)?;
+              let v = List::parser(reader, &e)?;
               struct_list.push(Struct::List(v));
             }
             "Subitem9" => {
-              let v = Subitem9::parser(e)?;
+              let v = Subitem9::parser(reader, &e)?;
               children.push(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(sentence) = sentence_opt {
         Ok(Subitem8::new(
           title,
@@ -1490,7 +1501,7 @@ impl Subitem9 {
 }
 
 impl Parser for Subitem9 {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Subitem9" {
       let num =
         ArticleNumber::from_num_str(&get_attribute_with_parse::<String>(element, "Num")?).ok();
@@ -1500,40 +1511,41 @@ impl Parser for Subitem9 {
       let mut sentence_opt = None;
       let mut struct_list = Vec::new();
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Subitem9Title" => {
-              title = Some(Text::from_children(&e.children));
+              title = Some(Text::from_reader(reader, &e)?);
             }
             "Subitem9Sentence" => {
-              let v = SentenceOrColumnOrTable::from_element(e)?;
+              let v = SentenceOrColumnOrTable::from_element(reader, &e)?;
               sentence_opt = Some(v);
             }
             "TableStruct" => {
-              let v = TableStruct::parser(e)?;
+              let v = TableStruct::parser(reader, &e)?;
               struct_list.push(Struct::TableStruct(v));
             }
             "FigStruct" => {
-              let v = FigStruct::parser(e)?;
+              let v = FigStruct::parser(reader, &e)?;
               struct_list.push(Struct::FigStruct(v));
             }
             "StyleStruct" => {
-              let v = StyleStruct::parser(e)?;
+              let v = StyleStruct::parser(reader, &e)?;
               struct_list.push(Struct::StyleStruct(v));
             }
             "List" => {
-              let v = List::parser(e)?;
+              let v = List::parser(reader, &e)?;
               struct_list.push(Struct::List(v));
             }
             "Subitem10" => {
-              let v = Subitem10::parser(e)?;
+              let v = Subitem10::parser(reader, &e)?;
               children.push(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(sentence) = sentence_opt {
         Ok(Subitem9::new(
           title,
@@ -1631,7 +1643,7 @@ impl Subitem10 {
 }
 
 impl Parser for Subitem10 {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Subitem10" {
       let num =
         ArticleNumber::from_num_str(&get_attribute_with_parse::<String>(element, "Num")?).ok();
@@ -1640,36 +1652,37 @@ impl Parser for Subitem10 {
       let mut title = None;
       let mut sentence_opt = None;
       let mut struct_list = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Subitem10Title" => {
-              title = Some(Text::from_children(&e.children));
+              title = Some(Text::from_reader(reader, &e)?);
             }
             "Subitem10Sentence" => {
-              let v = SentenceOrColumnOrTable::from_element(e)?;
+              let v = SentenceOrColumnOrTable::from_element(reader, &e)?;
               sentence_opt = Some(v);
             }
             "TableStruct" => {
-              let v = TableStruct::parser(e)?;
+              let v = TableStruct::parser(reader, &e)?;
               struct_list.push(Struct::TableStruct(v));
             }
             "FigStruct" => {
-              let v = FigStruct::parser(e)?;
+              let v = FigStruct::parser(reader, &e)?;
               struct_list.push(Struct::FigStruct(v));
             }
             "StyleStruct" => {
-              let v = StyleStruct::parser(e)?;
+              let v = StyleStruct::parser(reader, &e)?;
               struct_list.push(Struct::StyleStruct(v));
             }
             "List" => {
-              let v = List::parser(e)?;
+              let v = List::parser(reader, &e)?;
               struct_list.push(Struct::List(v));
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(sentence) = sentence_opt {
         Ok(Subitem10::new(
           title,
@@ -4,13 +4,145 @@ use crate::*;
 use article_number::ItemPattern;
 use regex::Regex;
 
-fn parse_part(
-  n: &ArticleNumber,
-  title: &str,
-  lines: &mut std::iter::Peekable<std::slice::Iter<LineContents>>,
-) -> article::Part {
+/// 行番号のついた行
+struct NumberedLine<'a> {
+  /// 1始まりの行番号
+  number: u64,
+  /// 前後の空白を取り除く前の行
+  text: &'a str,
+  contents: LineContents,
+}
+
+/// 行を順に読み進めながら、配置できなかった行を診断情報として記録する
+struct Lines<'a> {
+  lines: &'a [NumberedLine<'a>],
+  pos: usize,
+  /// 今読んでいる本則・附則のパス
+  path: ElementPath,
+  diagnostics: Vec<Diagnostic>,
+  options: &'a ParseTextOptions,
+  patterns: &'a LinePatterns,
+}
+
+impl<'a> Lines<'a> {
+  fn peek(&self) -> Option<&'a LineContents> {
+    self.lines.get(self.pos).map(|l| &l.contents)
+  }
+
+  /// 次の行を、行の種類を判定する前の文字列とともに返す
+  fn peek_line(&self) -> Option<&'a NumberedLine<'a>> {
+    self.lines.get(self.pos)
+  }
+
+  fn next(&mut self) -> Option<&'a LineContents> {
+    let line = self.peek();
+    if line.is_some() {
+      self.pos += 1;
+    }
+    line
+  }
+
+  /// 直前に読んだ行の行番号
+  fn line(&self) -> u64 {
+    self
+      .pos
+      .checked_sub(1)
+      .and_then(|i| self.lines.get(i))
+      .map_or(0, |l| l.number)
+  }
+
+  fn report(&mut self, line: u64, severity: Severity, error: Error) {
+    self.diagnostics.push(Diagnostic {
+      path: self.path.clone(),
+      position: Position { line, column: 1 },
+      severity,
+      error,
+    });
+  }
+
+  /// 次の行を配置できない行として読み飛ばす
+  fn skip(&mut self) {
+    if let Some(line) = self.lines.get(self.pos) {
+      self.pos += 1;
+      self.report(
+        line.number,
+        Severity::Error,
+        Error::UnexpectedLine(line.text.trim().to_string()),
+      );
+    }
+  }
+
+  /// 条につかなかった見出しを警告として記録する
+  fn unused_caption(&mut self, caption: Option<(u64, &String)>) {
+    if let Some((line, caption)) = caption {
+      self.report(
+        line,
+        Severity::Warning,
+        Error::UnusedCaption(caption.to_string()),
+      );
+    }
+  }
+
+  /// 項や号の文の続きの行を読む
+  ///
+  /// 行頭が号の番号のように見えるのに番号として読めなかった行は、文の続きとしたうえで警告する。
+  fn continuation(&mut self) -> Option<&'a String> {
+    match self.peek() {
+      Some(LineContents::Text(s)) => {
+        self.next();
+        if let Some((label, _)) = split_number(s) {
+          if looks_like_item_number(&label) {
+            let line = self.line();
+            self.report(line, Severity::Warning, Error::UnreadableNumber(label));
+          }
+        }
+        Some(s)
+      }
+      _ => None,
+    }
+  }
+
+  /// 見出しの行を読み、それまでの見出しが使われていなければ警告する
+  fn caption(&mut self, caption_opt: &mut Option<(u64, &'a String)>, caption: &'a String) {
+    self.next();
+    self.unused_caption(caption_opt.take());
+    *caption_opt = Some((self.line(), caption));
+  }
+}
+
+/// 括弧を除いて、いろは二文字以内、または漢数字・全角英数字と`の`だけからなる文字列かどうか
+fn looks_like_item_number(label: &str) -> bool {
+  let s = label.trim_start_matches('（').trim_end_matches('）');
+  let is_kana = |c: char| ('ぁ'..='ん').contains(&c) || ('ァ'..='ン').contains(&c);
+  let is_number = |c: char| {
+    "〇一二三四五六七八九十百千".contains(c)
+      || ('０'..='９').contains(&c)
+      || ('Ａ'..='Ｚ').contains(&c)
+      || ('ａ'..='ｚ').contains(&c)
+  };
+  !s.is_empty()
+    && s.chars().all(|c| is_kana(c) || is_number(c))
+    && s.chars().filter(|c| is_kana(*c) && *c != 'の').count() <= 2
+}
+
+fn body_path(name: &str, index: usize) -> ElementPath {
+  let segment = |name: &str, index| PathSegment {
+    name: name.to_string(),
+    index,
+    num: None,
+  };
+  ElementPath {
+    segments: vec![
+      segment("Law", 1),
+      segment("LawBody", 1),
+      segment(name, index),
+    ],
+  }
+}
+
+fn parse_part(n: &ArticleNumber, title: &str, lines: &mut Lines) -> article::Part {
   let part_title = text::Text::from_value(title);
-  let delete = title.trim() == "削除" || title.trim() == "（削除）";
+  let delete = is_deleted(title);
   let hide = false;
   let mut children = Vec::new();
   let mut caption_opt = None;
@@ -18,24 +150,23 @@ fn parse_part(
     let next = lines.peek();
     match next {
       Some(LineContents::Caption(cap)) => {
-        lines.next();
-        caption_opt = Some(cap);
+        lines.caption(&mut caption_opt, cap);
       }
       Some(LineContents::Article(n, text)) => {
         lines.next();
-        let article = parse_article(caption_opt, n, text, lines);
+        let article = parse_article(caption_opt.take(), n, text, lines);
         children.push(article::PartContents::Article(article));
-        caption_opt = None;
       }
       Some(LineContents::Chapter(n, title)) => {
         lines.next();
+        lines.unused_caption(caption_opt.take());
         let chap = parse_chapter(n, title, lines);
         children.push(article::PartContents::Chapter(chap));
-        caption_opt = None;
       }
       _ => break,
     }
   }
+  lines.unused_caption(caption_opt);
   article::Part {
     part_title,
     children,
@@ -45,13 +176,9 @@ fn parse_part(
   }
 }
 
-fn parse_chapter(
-  n: &ArticleNumber,
-  title: &str,
-  lines: &mut std::iter::Peekable<std::slice::Iter<LineContents>>,
-) -> article::Chapter {
+fn parse_chapter(n: &ArticleNumber, title: &str, lines: &mut Lines) -> article::Chapter {
   let chapter_title = text::Text::from_value(title);
-  let delete = title.trim() == "削除" || title.trim() == "（削除）";
+  let delete = is_deleted(title);
   let hide = false;
   let mut children = Vec::new();
   let mut caption_opt = None;
@@ -59,24 +186,23 @@ fn parse_chapter(
     let next = lines.peek();
     match next {
       Some(LineContents::Caption(cap)) => {
-        lines.next();
-        caption_opt = Some(cap);
+        lines.caption(&mut caption_opt, cap);
       }
       Some(LineContents::Article(n, text)) => {
         lines.next();
-        let article = parse_article(caption_opt, n, text, lines);
+        let article = parse_article(caption_opt.take(), n, text, lines);
         children.push(article::ChapterContents::Article(article));
-        caption_opt = None;
       }
       Some(LineContents::Section(n, title)) => {
         lines.next();
+        lines.unused_caption(caption_opt.take());
         let sec = parse_section(n, title, lines);
         children.push(article::ChapterContents::Section(sec));
-        caption_opt = None;
       }
       _ => break,
     }
   }
+  lines.unused_caption(caption_opt);
   article::Chapter {
     chapter_title,
     children,
@@ -86,13 +212,9 @@ fn parse_chapter(
   }
 }
 
-fn parse_section(
-  n: &ArticleNumber,
-  title: &str,
-  lines: &mut std::iter::Peekable<std::slice::Iter<LineContents>>,
-) -> article::Section {
+fn parse_section(n: &ArticleNumber, title: &str, lines: &mut Lines) -> article::Section {
   let section_title = text::Text::from_value(title);
-  let delete = title.trim() == "削除" || title.trim() == "（削除）";
+  let delete = is_deleted(title);
   let hide = false;
   let mut children = Vec::new();
   let mut caption_opt = None;
@@ -100,24 +222,29 @@ fn parse_section(
     let next = lines.peek();
     match next {
       Some(LineContents::Caption(cap)) => {
-        lines.next();
-        caption_opt = Some(cap);
+        lines.caption(&mut caption_opt, cap);
       }
       Some(LineContents::Article(n, text)) => {
         lines.next();
-        let article = parse_article(caption_opt, n, text, lines);
+        let article = parse_article(caption_opt.take(), n, text, lines);
         children.push(article::SectionContents::Article(article));
-        caption_opt = None;
       }
       Some(LineContents::Subsection(n, title)) => {
         lines.next();
+        lines.unused_caption(caption_opt.take());
         let subsec = parse_subsection(n, title, lines);
         children.push(article::SectionContents::Subsection(subsec));
-        caption_opt = None;
+      }
+      Some(LineContents::Division(n, title)) => {
+        lines.next();
+        lines.unused_caption(caption_opt.take());
+        let div = parse_division(n, title, lines);
+        children.push(article::SectionContents::Division(div));
       }
       _ => break,
     }
   }
+  lines.unused_caption(caption_opt);
   article::Section {
     section_title,
     children,
@@ -127,13 +254,9 @@ fn parse_section(
   }
 }
 
-fn parse_subsection(
-  n: &ArticleNumber,
-  title: &str,
-  lines: &mut std::iter::Peekable<std::slice::Iter<LineContents>>,
-) -> article::Subsection {
+fn parse_subsection(n: &ArticleNumber, title: &str, lines: &mut Lines) -> article::Subsection {
   let subsection_title = text::Text::from_value(title);
-  let delete = title.trim() == "削除" || title.trim() == "（削除）";
+  let delete = is_deleted(title);
   let hide = false;
   let mut children = Vec::new();
   let mut caption_opt = None;
@@ -141,24 +264,23 @@ fn parse_subsection(
     let next = lines.peek();
     match next {
       Some(LineContents::Caption(cap)) => {
-        caption_opt = Some(cap);
-        lines.next();
+        lines.caption(&mut caption_opt, cap);
       }
       Some(LineContents::Article(n, text)) => {
         lines.next();
-        let article = parse_article(caption_opt, n, text, lines);
+        let article = parse_article(caption_opt.take(), n, text, lines);
         children.push(article::SubsectionContents::Article(article));
-        caption_opt = None;
       }
       Some(LineContents::Division(n, title)) => {
         lines.next();
+        lines.unused_caption(caption_opt.take());
         let div = parse_division(n, title, lines);
         children.push(article::SubsectionContents::Division(div));
-        caption_opt = None;
       }
       _ => break,
     }
   }
+  lines.unused_caption(caption_opt);
   article::Subsection {
     subsection_title,
     children,
@@ -168,13 +290,9 @@ fn parse_subsection(
   }
 }
 
-fn parse_division(
-  n: &ArticleNumber,
-  title: &str,
-  lines: &mut std::iter::Peekable<std::slice::Iter<LineContents>>,
-) -> article::Division {
+fn parse_division(n: &ArticleNumber, title: &str, lines: &mut Lines) -> article::Division {
   let division_title = text::Text::from_value(title);
-  let delete = title.trim() == "削除" || title.trim() == "（削除）";
+  let delete = is_deleted(title);
   let hide = false;
   let mut children = Vec::new();
   let mut caption_opt = None;
@@ -182,18 +300,17 @@ fn parse_division(
     let next = lines.peek();
     match next {
       Some(LineContents::Caption(cap)) => {
-        caption_opt = Some(cap);
-        lines.next();
+        lines.caption(&mut caption_opt, cap);
       }
       Some(LineContents::Article(n, text)) => {
         lines.next();
-        let article = parse_article(caption_opt, n, text, lines);
+        let article = parse_article(caption_opt.take(), n, text, lines);
         children.push(article);
-        caption_opt = None;
       }
       _ => break,
     }
   }
+  lines.unused_caption(caption_opt);
   article::Division {
     division_title,
     children,
@@ -204,42 +321,38 @@ fn parse_division(
 }
 
 fn parse_article(
-  caption_opt: Option<&String>,
+  caption_opt: Option<(u64, &String)>,
   n: &ArticleNumber,
   text: &str,
-  lines: &mut std::iter::Peekable<std::slice::Iter<LineContents>>,
+  lines: &mut Lines,
 ) -> article::Article {
-  let caption = caption_opt.map(|s| class::Caption {
+  let caption = caption_opt.map(|(_, s)| class::Caption {
     text: text::Text::from_value(s),
     common_caption: None,
   });
-  let para = parse_paragraph(&ArticleNumber::from_num_str("1").unwrap(), text, lines);
+  let mut para = parse_paragraph(&ArticleNumber::from_num_str("1").unwrap(), text, lines);
+  // 条の最初の項には項番号を書かない
+  para.paragraph_num = text::Text::new();
   let mut paragraph = vec![para];
   while let Some(LineContents::Paragraph(n, text)) = lines.peek() {
     lines.next();
     let para = parse_paragraph(n, text, lines);
     paragraph.push(para)
   }
-  let article = article::Article {
+  article::Article {
     caption,
-    title: text::Text::from_value(String::new()), // TODO 「第○条」に生成
+    title: text::Text::from_value(n.article_text()),
     paragraph,
     suppl_note: None,
     num: n.clone(),
-    delete: text.trim() == "削除" || text.trim() == "（削除）",
+    delete: is_deleted(text),
     hide: false,
-  };
-  article
+  }
 }
 
-fn parse_paragraph(
-  n: &ArticleNumber,
-  text: &str,
-  lines: &mut std::iter::Peekable<std::slice::Iter<LineContents>>,
-) -> paragraph::Paragraph {
+fn parse_paragraph(n: &ArticleNumber, text: &str, lines: &mut Lines) -> paragraph::Paragraph {
   let mut sentence_text = vec![text];
-  while let Some(LineContents::Text(s)) = lines.peek() {
-    lines.next();
+  while let Some(s) = lines.continuation() {
     sentence_text.push(s);
   }
   let sentence = sentence_text
@@ -248,41 +361,21 @@ fn parse_paragraph(
     .map(|(n, s)| text_to_sentence(n + 1, s))
     .collect::<Vec<_>>();
 
-  let mut children = Vec::new();
-  while let Some(LineContents::Item(item_number, text)) = lines.peek() {
-    lines.next();
-    let mut sentence_text = vec![text];
-    while let Some(LineContents::Text(s)) = lines.peek() {
-      lines.next();
-      sentence_text.push(s);
-    }
-    let mut subitem1 = Vec::new();
-    if let Some(LineContents::Item(item_number_sub1, _)) = lines.peek() {
-      if item_number_sub1.pattern != item_number.pattern {
-        subitem1 = parse_subitem1(item_number.pattern, item_number_sub1.pattern, lines);
+  let children = parse_items(&[], None, lines)
+    .into_iter()
+    .filter_map(|tree| match tree.into_item() {
+      Ok(item) => Some(item),
+      Err(e) => {
+        let line = lines.line();
+        lines.report(line, Severity::Error, e);
+        None
       }
-    }
-    let item = paragraph::Item {
-      title: None,
-      sentence: class::SentenceOrColumnOrTable::Sentence(
-        sentence_text
-          .iter()
-          .enumerate()
-          .map(|(n, s)| text_to_sentence(n + 1, s))
-          .collect(),
-      ),
-      children: subitem1,
-      struct_list: Vec::new(),
-      num: Some(ArticleNumber::from_item_number(item_number)),
-      delete: text.trim() == "削除" || text.trim() == "（削除）",
-      hide: false,
-    };
-    children.push(item);
-  }
+    })
+    .collect();
 
   paragraph::Paragraph {
     caption: None,
-    paragraph_num: text::Text::new(), // TODO 謎
+    paragraph_num: text::Text::from_value(n.paragraph_text()),
     amend_provision: Vec::new(),
     class: Vec::new(),
     sentence,
@@ -295,536 +388,724 @@ fn parse_paragraph(
   }
 }
 
-fn parse_subitem1(
-  parent_pattern: ItemPattern,
-  now_pat: ItemPattern,
-  lines: &mut std::iter::Peekable<std::slice::Iter<LineContents>>,
-) -> Vec<paragraph::Subitem1> {
+/// 号とその下の細分を読み込む
+///
+/// `ancestors`は上の階層の番号の種類で、その種類の行が現れたら上の階層に戻る。
+/// 号の階層では行ごとの番号の種類を、細分の階層では`now_pat`をその階層の番号の種類とする。
+/// 細分10より深い階層の行は読み飛ばしてエラーとして記録する。
+fn parse_items(
+  ancestors: &[ItemPattern],
+  now_pat: Option<ItemPattern>,
+  lines: &mut Lines,
+) -> Vec<item_like::ItemTree> {
   let mut v = Vec::new();
   while let Some(LineContents::Item(item_number, text)) = lines.peek() {
-    if item_number.pattern == parent_pattern {
+    if ancestors.contains(&item_number.pattern) {
       break;
-    } else if item_number.pattern == now_pat {
-      lines.next();
-      let mut sentence_text = vec![text];
-      while let Some(LineContents::Text(s)) = lines.peek() {
-        lines.next();
-        sentence_text.push(s);
-      }
-      let mut subitem2 = Vec::new();
-      if let Some(LineContents::Item(item_number, _)) = lines.peek() {
-        if item_number.pattern != now_pat && item_number.pattern != parent_pattern {
-          subitem2 = parse_subitem2(now_pat, item_number.pattern, lines);
+    }
+    let now_pat = now_pat.unwrap_or(item_number.pattern);
+    if item_number.pattern != now_pat {
+      lines.skip();
+      continue;
+    }
+    lines.next();
+    let mut sentence_text = vec![text];
+    while let Some(s) = lines.continuation() {
+      sentence_text.push(s);
+    }
+    let mut children = Vec::new();
+    if ancestors.len() < 10 {
+      if let Some(LineContents::Item(next_number, _)) = lines.peek() {
+        if next_number.pattern != now_pat && !ancestors.contains(&next_number.pattern) {
+          let mut ancestors = ancestors.to_vec();
+          ancestors.push(now_pat);
+          children = parse_items(&ancestors, Some(next_number.pattern), lines);
         }
       }
-      v.push(paragraph::Subitem1 {
-        title: None,
-        sentence: class::SentenceOrColumnOrTable::Sentence(
-          sentence_text
-            .iter()
-            .enumerate()
-            .map(|(n, s)| text_to_sentence(n + 1, s))
-            .collect(),
-        ),
-        children: subitem2,
-        struct_list: Vec::new(),
-        num: Some(ArticleNumber::from_item_number(item_number)),
-        delete: text.trim() == "削除" || text.trim() == "（削除）",
-        hide: false,
-      })
-    } else {
-      unreachable!()
     }
+    v.push(item_like::ItemTree {
+      title: Some(text::Text::from_value(&item_number.str)),
+      sentence: class::SentenceOrColumnOrTable::Sentence(
+        sentence_text
+          .iter()
+          .enumerate()
+          .map(|(n, s)| text_to_sentence(n + 1, s))
+          .collect(),
+      ),
+      struct_list: Vec::new(),
+      num: Some(ArticleNumber::from_item_number(item_number)),
+      delete: is_deleted(text),
+      hide: false,
+      children,
+    })
   }
   v
 }
 
-fn parse_subitem2(
-  parent_pattern: ItemPattern,
-  now_pat: ItemPattern,
-  lines: &mut std::iter::Peekable<std::slice::Iter<LineContents>>,
-) -> Vec<paragraph::Subitem2> {
-  let mut v = Vec::new();
-  while let Some(LineContents::Item(item_number, text)) = lines.peek() {
-    if item_number.pattern == parent_pattern {
-      break;
-    } else if item_number.pattern == now_pat {
-      lines.next();
-      let mut sentence_text = vec![text];
-      while let Some(LineContents::Text(s)) = lines.peek() {
-        lines.next();
-        sentence_text.push(s);
-      }
-      let mut subitem3 = Vec::new();
-      if let Some(LineContents::Item(item_number, _)) = lines.peek() {
-        if item_number.pattern != now_pat && item_number.pattern != parent_pattern {
-          subitem3 = parse_subitem3(now_pat, item_number.pattern, lines);
-        }
-      }
-      v.push(paragraph::Subitem2 {
-        title: None,
-        sentence: class::SentenceOrColumnOrTable::Sentence(
-          sentence_text
-            .iter()
-            .enumerate()
-            .map(|(n, s)| text_to_sentence(n + 1, s))
-            .collect(),
-        ),
-        children: subitem3,
-        struct_list: Vec::new(),
-        num: Some(ArticleNumber::from_item_number(item_number)),
-        delete: text.trim() == "削除" || text.trim() == "（削除）",
-        hide: false,
-      })
-    } else {
-      unreachable!()
-    }
+fn text_to_sentence(num: usize, text: &str) -> sentence::Sentence {
+  sentence::Sentence {
+    contents: vec![sentence::SentenceElement::String(text.to_string())],
+    num: Some(num),
+    function: None,
+    indent: None,
+    writing_mode: text::WritingMode::Vertical,
   }
-  v
 }
 
-fn parse_subitem3(
-  parent_pattern: ItemPattern,
-  now_pat: ItemPattern,
-  lines: &mut std::iter::Peekable<std::slice::Iter<LineContents>>,
-) -> Vec<paragraph::Subitem3> {
-  let mut v = Vec::new();
-  while let Some(LineContents::Item(item_number, text)) = lines.peek() {
-    if item_number.pattern == parent_pattern {
-      break;
-    } else if item_number.pattern == now_pat {
-      lines.next();
-      let mut sentence_text = vec![text];
-      while let Some(LineContents::Text(s)) = lines.peek() {
-        lines.next();
-        sentence_text.push(s);
-      }
-      let mut subitem4 = Vec::new();
-      if let Some(LineContents::Item(item_number, _)) = lines.peek() {
-        if item_number.pattern != now_pat && item_number.pattern != parent_pattern {
-          subitem4 = parse_subitem4(now_pat, item_number.pattern, lines);
-        }
-      }
-      v.push(paragraph::Subitem3 {
-        title: None,
-        sentence: class::SentenceOrColumnOrTable::Sentence(
-          sentence_text
-            .iter()
-            .enumerate()
-            .map(|(n, s)| text_to_sentence(n + 1, s))
-            .collect(),
-        ),
-        children: subitem4,
-        struct_list: Vec::new(),
-        num: Some(ArticleNumber::from_item_number(item_number)),
-        delete: text.trim() == "削除" || text.trim() == "（削除）",
-        hide: false,
-      })
-    } else {
-      unreachable!()
-    }
+/// 附則の見出し
+struct SupplProvisionHeader<'a> {
+  line: u64,
+  label: &'a str,
+  amend_law_num: &'a Option<String>,
+  extract: bool,
+}
+
+/// 目次の項目の見出しと条の範囲を分ける：総則（第一条―第三条）
+fn split_article_range(title: &str, lines: &Lines) -> (text::Text, Option<text::Text>) {
+  match lines.patterns.article_range.captures(title) {
+    Some(caps) => (
+      text::Text::from_value(&caps["title"]),
+      Some(text::Text::from_value(&caps["range"])),
+    ),
+    None => (text::Text::from_value(title), None),
   }
-  v
 }
 
-fn parse_subitem4(
-  parent_pattern: ItemPattern,
-  now_pat: ItemPattern,
-  lines: &mut std::iter::Peekable<std::slice::Iter<LineContents>>,
-) -> Vec<paragraph::Subitem4> {
-  let mut v = Vec::new();
-  while let Some(LineContents::Item(item_number, text)) = lines.peek() {
-    if item_number.pattern == parent_pattern {
-      break;
-    } else if item_number.pattern == now_pat {
-      lines.next();
-      let mut sentence_text = vec![text];
-      while let Some(LineContents::Text(s)) = lines.peek() {
-        lines.next();
-        sentence_text.push(s);
-      }
-      let mut subitem5 = Vec::new();
-      if let Some(LineContents::Item(item_number, _)) = lines.peek() {
-        if item_number.pattern != now_pat && item_number.pattern != parent_pattern {
-          subitem5 = parse_subitem5(now_pat, item_number.pattern, lines);
-        }
-      }
-      v.push(paragraph::Subitem4 {
-        title: None,
-        sentence: class::SentenceOrColumnOrTable::Sentence(
-          sentence_text
-            .iter()
-            .enumerate()
-            .map(|(n, s)| text_to_sentence(n + 1, s))
-            .collect(),
-        ),
-        children: subitem5,
-        struct_list: Vec::new(),
-        num: Some(ArticleNumber::from_item_number(item_number)),
-        delete: text.trim() == "削除" || text.trim() == "（削除）",
-        hide: false,
-      })
-    } else {
-      unreachable!()
-    }
+/// 見出しや本文が`削除`または`（削除）`だけかどうか
+fn is_deleted(title: &str) -> bool {
+  title.trim() == "削除" || title.trim() == "（削除）"
+}
+
+/// 同じ種類・同じ番号の見出しかどうか
+fn same_heading(a: &LineContents, b: &LineContents) -> bool {
+  use LineContents::*;
+  match (a, b) {
+    (Part(a, _), Part(b, _))
+    | (Chapter(a, _), Chapter(b, _))
+    | (Section(a, _), Section(b, _))
+    | (Subsection(a, _), Subsection(b, _))
+    | (Division(a, _), Division(b, _)) => a == b,
+    _ => false,
   }
-  v
 }
 
-fn parse_subitem5(
-  parent_pattern: ItemPattern,
-  now_pat: ItemPattern,
-  lines: &mut std::iter::Peekable<std::slice::Iter<LineContents>>,
-) -> Vec<paragraph::Subitem5> {
-  let mut v = Vec::new();
-  while let Some(LineContents::Item(item_number, text)) = lines.peek() {
-    if item_number.pattern == parent_pattern {
-      break;
-    } else if item_number.pattern == now_pat {
-      lines.next();
-      let mut sentence_text = vec![text];
-      while let Some(LineContents::Text(s)) = lines.peek() {
+/// 目次の次の行を返す
+///
+/// 目次の最初の項目と同じ見出しが再び現れたときは、本文が始まったとみなして`None`を返す。
+fn toc_next<'a>(lines: &Lines<'a>, start: Option<&LineContents>) -> Option<&'a LineContents> {
+  lines
+    .peek()
+    .filter(|line| start.map_or(true, |start| !same_heading(start, line)))
+}
+
+/// 目次を読み込む
+///
+/// 「目次」の行の次から、目次の最初の項目と同じ見出しが再び現れるまでを目次とする。
+fn parse_toc(label: &str, lines: &mut Lines) -> table_of_contents::TOC {
+  use table_of_contents::*;
+  let mut toc_preamble_label = None;
+  let mut toc_main_contents = Vec::new();
+  let mut toc_suppl_provision = None;
+  let mut toc_appdx_table_label = Vec::new();
+  let mut start = None;
+  while let Some(line) = toc_next(lines, start) {
+    match line {
+      LineContents::Text(s)
+        if s == "前文" && toc_preamble_label.is_none() && toc_main_contents.is_empty() =>
+      {
         lines.next();
-        sentence_text.push(s);
+        toc_preamble_label = Some(text::Text::from_value(s));
       }
-      let mut subitem6 = Vec::new();
-      if let Some(LineContents::Item(item_number, _)) = lines.peek() {
-        if item_number.pattern != now_pat && item_number.pattern != parent_pattern {
-          subitem6 = parse_subitem6(now_pat, item_number.pattern, lines);
-        }
+      LineContents::Part(n, title) if toc_suppl_provision.is_none() => {
+        lines.next();
+        start = start.or(Some(line));
+        let part = toc_part(n, title, lines, start);
+        toc_main_contents.push(TOCMainContents::TOCPart(part));
       }
-      v.push(paragraph::Subitem5 {
-        title: None,
-        sentence: class::SentenceOrColumnOrTable::Sentence(
-          sentence_text
-            .iter()
-            .enumerate()
-            .map(|(n, s)| text_to_sentence(n + 1, s))
-            .collect(),
-        ),
-        children: subitem6,
-        struct_list: Vec::new(),
-        num: Some(ArticleNumber::from_item_number(item_number)),
-        delete: text.trim() == "削除" || text.trim() == "（削除）",
-        hide: false,
-      })
-    } else {
-      unreachable!()
-    }
-  }
-  v
-}
-
-fn parse_subitem6(
-  parent_pattern: ItemPattern,
-  now_pat: ItemPattern,
-  lines: &mut std::iter::Peekable<std::slice::Iter<LineContents>>,
-) -> Vec<paragraph::Subitem6> {
-  let mut v = Vec::new();
-  while let Some(LineContents::Item(item_number, text)) = lines.peek() {
-    if item_number.pattern == parent_pattern {
-      break;
-    } else if item_number.pattern == now_pat {
-      lines.next();
-      let mut sentence_text = vec![text];
-      while let Some(LineContents::Text(s)) = lines.peek() {
+      LineContents::Chapter(n, title) if toc_suppl_provision.is_none() => {
         lines.next();
-        sentence_text.push(s);
+        start = start.or(Some(line));
+        let chapter = toc_chapter(n, title, lines, start);
+        toc_main_contents.push(TOCMainContents::TOCChapter(chapter));
       }
-      let mut subitem7 = Vec::new();
-      if let Some(LineContents::Item(item_number, _)) = lines.peek() {
-        if item_number.pattern != now_pat && item_number.pattern != parent_pattern {
-          subitem7 = parse_subitem7(now_pat, item_number.pattern, lines);
-        }
+      LineContents::Section(n, title) if toc_suppl_provision.is_none() => {
+        lines.next();
+        start = start.or(Some(line));
+        let section = toc_section(n, title, lines, start);
+        toc_main_contents.push(TOCMainContents::TOCSection(section));
       }
-      v.push(paragraph::Subitem6 {
-        title: None,
-        sentence: class::SentenceOrColumnOrTable::Sentence(
-          sentence_text
-            .iter()
-            .enumerate()
-            .map(|(n, s)| text_to_sentence(n + 1, s))
-            .collect(),
-        ),
-        children: subitem7,
-        struct_list: Vec::new(),
-        num: Some(ArticleNumber::from_item_number(item_number)),
-        delete: text.trim() == "削除" || text.trim() == "（削除）",
-        hide: false,
-      })
-    } else {
-      unreachable!()
+      LineContents::SupplProvision {
+        label,
+        amend_law_num,
+        extract: false,
+      } if toc_suppl_provision.is_none() => {
+        lines.next();
+        toc_suppl_provision = Some(TOCSupplProvision {
+          suppl_provision_label: text::Text::from_value(label),
+          article_range: amend_law_num
+            .as_ref()
+            .map(|range| text::Text::from_value(format!("（{range}）"))),
+          children: Vec::new(),
+        });
+      }
+      LineContents::AppdxTable(..) => {
+        let title = lines.peek_line().map_or("", |l| l.text.trim());
+        lines.next();
+        toc_appdx_table_label.push(text::Text::from_value(title));
+      }
+      _ => break,
     }
   }
-  v
+  TOC {
+    toc_label: Some(text::Text::from_value(label)),
+    toc_preamble_label,
+    toc_main_contents,
+    toc_suppl_provision,
+    toc_appdx_table_label,
+  }
 }
 
-fn parse_subitem7(
-  parent_pattern: ItemPattern,
-  now_pat: ItemPattern,
-  lines: &mut std::iter::Peekable<std::slice::Iter<LineContents>>,
-) -> Vec<paragraph::Subitem7> {
-  let mut v = Vec::new();
-  while let Some(LineContents::Item(item_number, text)) = lines.peek() {
-    if item_number.pattern == parent_pattern {
-      break;
-    } else if item_number.pattern == now_pat {
-      lines.next();
-      let mut sentence_text = vec![text];
-      while let Some(LineContents::Text(s)) = lines.peek() {
+fn toc_part(
+  n: &ArticleNumber,
+  title: &str,
+  lines: &mut Lines,
+  start: Option<&LineContents>,
+) -> table_of_contents::TOCPart {
+  let (part_title, article_range) = split_article_range(title, lines);
+  let mut children = Vec::new();
+  while let Some(LineContents::Chapter(n, title)) = toc_next(lines, start) {
+    lines.next();
+    children.push(toc_chapter(n, title, lines, start));
+  }
+  table_of_contents::TOCPart {
+    delete: is_deleted(title),
+    part_title,
+    article_range,
+    num: n.clone(),
+    children,
+  }
+}
+
+fn toc_chapter(
+  n: &ArticleNumber,
+  title: &str,
+  lines: &mut Lines,
+  start: Option<&LineContents>,
+) -> table_of_contents::TOCChapter {
+  let (chapter_title, article_range) = split_article_range(title, lines);
+  let mut children = Vec::new();
+  while let Some(LineContents::Section(n, title)) = toc_next(lines, start) {
+    lines.next();
+    children.push(toc_section(n, title, lines, start));
+  }
+  table_of_contents::TOCChapter {
+    delete: is_deleted(title),
+    chapter_title,
+    article_range,
+    num: n.clone(),
+    children,
+  }
+}
+
+fn toc_section(
+  n: &ArticleNumber,
+  title: &str,
+  lines: &mut Lines,
+  start: Option<&LineContents>,
+) -> table_of_contents::TOCSection {
+  use table_of_contents::TOCSectionContents;
+  let (section_title, article_range) = split_article_range(title, lines);
+  let mut children = Vec::new();
+  loop {
+    match toc_next(lines, start) {
+      Some(LineContents::Subsection(n, title)) => {
         lines.next();
-        sentence_text.push(s);
+        let subsection = toc_subsection(n, title, lines, start);
+        children.push(TOCSectionContents::TOCSubsection(subsection));
       }
-      let mut subitem8 = Vec::new();
-      if let Some(LineContents::Item(item_number, _)) = lines.peek() {
-        if item_number.pattern != now_pat && item_number.pattern != parent_pattern {
-          subitem8 = parse_subitem8(now_pat, item_number.pattern, lines);
-        }
+      Some(LineContents::Division(n, title)) => {
+        lines.next();
+        children.push(TOCSectionContents::TOCDivision(toc_division(
+          n, title, lines,
+        )));
       }
-      v.push(paragraph::Subitem7 {
-        title: None,
-        sentence: class::SentenceOrColumnOrTable::Sentence(
-          sentence_text
-            .iter()
-            .enumerate()
-            .map(|(n, s)| text_to_sentence(n + 1, s))
-            .collect(),
-        ),
-        children: subitem8,
-        struct_list: Vec::new(),
-        num: Some(ArticleNumber::from_item_number(item_number)),
-        delete: text.trim() == "削除" || text.trim() == "（削除）",
-        hide: false,
-      })
-    } else {
-      unreachable!()
+      _ => break,
     }
   }
-  v
+  table_of_contents::TOCSection {
+    delete: is_deleted(title),
+    section_title,
+    article_range,
+    num: n.clone(),
+    children,
+  }
 }
 
-fn parse_subitem8(
-  parent_pattern: ItemPattern,
-  now_pat: ItemPattern,
-  lines: &mut std::iter::Peekable<std::slice::Iter<LineContents>>,
-) -> Vec<paragraph::Subitem8> {
-  let mut v = Vec::new();
-  while let Some(LineContents::Item(item_number, text)) = lines.peek() {
-    if item_number.pattern == parent_pattern {
-      break;
-    } else if item_number.pattern == now_pat {
-      lines.next();
-      let mut sentence_text = vec![text];
-      while let Some(LineContents::Text(s)) = lines.peek() {
-        lines.next();
-        sentence_text.push(s);
-      }
-      let mut subitem9 = Vec::new();
-      if let Some(LineContents::Item(item_number, _)) = lines.peek() {
-        if item_number.pattern != now_pat && item_number.pattern != parent_pattern {
-          subitem9 = parse_subitem9(now_pat, item_number.pattern, lines);
-        }
-      }
-      v.push(paragraph::Subitem8 {
-        title: None,
-        sentence: class::SentenceOrColumnOrTable::Sentence(
-          sentence_text
-            .iter()
-            .enumerate()
-            .map(|(n, s)| text_to_sentence(n + 1, s))
-            .collect(),
-        ),
-        children: subitem9,
-        struct_list: Vec::new(),
-        num: Some(ArticleNumber::from_item_number(item_number)),
-        delete: text.trim() == "削除" || text.trim() == "（削除）",
-        hide: false,
-      })
-    } else {
-      unreachable!()
+fn toc_subsection(
+  n: &ArticleNumber,
+  title: &str,
+  lines: &mut Lines,
+  start: Option<&LineContents>,
+) -> table_of_contents::TOCSubsection {
+  let (subsection_title, article_range) = split_article_range(title, lines);
+  let mut children = Vec::new();
+  while let Some(LineContents::Division(n, title)) = toc_next(lines, start) {
+    lines.next();
+    children.push(toc_division(n, title, lines));
+  }
+  table_of_contents::TOCSubsection {
+    delete: is_deleted(title),
+    subsection_title,
+    article_range,
+    num: n.clone(),
+    children,
+  }
+}
+
+fn toc_division(n: &ArticleNumber, title: &str, lines: &Lines) -> table_of_contents::TOCDivision {
+  let (division_title, article_range) = split_article_range(title, lines);
+  table_of_contents::TOCDivision {
+    delete: is_deleted(title),
+    division_title,
+    article_range,
+    num: n.clone(),
+  }
+}
+
+/// 附則を追加する
+///
+/// 中身のない附則も追加するが、警告として記録する。
+/// テキストには附則の種類が書かれないため、e-Govのデータと同じく改正附則でも種類は設定しない。
+fn push_suppl_provision(
+  lines: &mut Lines,
+  suppl_provision: &mut Vec<suppl_provision::SupplProvision>,
+  header: Option<SupplProvisionHeader>,
+  children: Vec<suppl_provision::SupplProvisionChildrenElement>,
+) {
+  if let Some(header) = header {
+    if children.is_empty() {
+      let label = match header.amend_law_num {
+        Some(law_num) => format!("{}（{law_num}）", header.label),
+        None => header.label.to_string(),
+      };
+      lines.report(
+        header.line,
+        Severity::Warning,
+        Error::EmptySupplProvision(label),
+      );
     }
+    suppl_provision.push(suppl_provision::SupplProvision {
+      label: text::Text::from_value(header.label),
+      children,
+      suppl_provision_type: None,
+      amend_law_num: header.amend_law_num.clone(),
+      extract: header.extract.then_some(true),
+    })
   }
-  v
 }
 
-fn parse_subitem9(
-  parent_pattern: ItemPattern,
-  now_pat: ItemPattern,
-  lines: &mut std::iter::Peekable<std::slice::Iter<LineContents>>,
-) -> Vec<paragraph::Subitem9> {
-  let mut v = Vec::new();
-  while let Some(LineContents::Item(item_number, text)) = lines.peek() {
-    if item_number.pattern == parent_pattern {
+/// 別表などの表の中身を読み込む
+///
+/// 附則や別表の見出しの行が現れるまでの各行を、設定された区切りで欄を分けた表の一行として読む。
+fn parse_table(lines: &mut Lines) -> Option<structs::TableStruct> {
+  let mut table_row = Vec::new();
+  while let Some(line) = lines.peek_line() {
+    if line.contents.is_appdx_heading() {
       break;
-    } else if item_number.pattern == now_pat {
-      lines.next();
-      let mut sentence_text = vec![text];
-      while let Some(LineContents::Text(s)) = lines.peek() {
-        lines.next();
-        sentence_text.push(s);
-      }
-      let mut subitem10 = Vec::new();
-      if let Some(LineContents::Item(item_number, _)) = lines.peek() {
-        if item_number.pattern != now_pat && item_number.pattern != parent_pattern {
-          subitem10 = parse_subitem10(now_pat, item_number.pattern, lines);
-        }
-      }
-      v.push(paragraph::Subitem9 {
-        title: None,
-        sentence: class::SentenceOrColumnOrTable::Sentence(
-          sentence_text
-            .iter()
-            .enumerate()
-            .map(|(n, s)| text_to_sentence(n + 1, s))
-            .collect(),
-        ),
-        children: subitem10,
-        struct_list: Vec::new(),
-        num: Some(ArticleNumber::from_item_number(item_number)),
-        delete: text.trim() == "削除" || text.trim() == "（削除）",
-        hide: false,
-      })
-    } else {
-      unreachable!()
     }
+    lines.next();
+    let columns = lines
+      .options
+      .table_delimiter
+      .split(line.text.trim_end_matches(['\r', '\n']))
+      .into_iter()
+      .map(table_column)
+      .collect();
+    table_row.push(table::TableRow { columns });
   }
-  v
+  if table_row.is_empty() {
+    return None;
+  }
+  Some(structs::TableStruct {
+    title: None,
+    title_remarks: Vec::new(),
+    table: table::Table {
+      table_header_row: Vec::new(),
+      table_row,
+      writing_mode: text::WritingMode::Vertical,
+    },
+    table_remarks: Vec::new(),
+  })
 }
 
-fn parse_subitem10(
-  parent_pattern: ItemPattern,
-  now_pat: ItemPattern,
-  lines: &mut std::iter::Peekable<std::slice::Iter<LineContents>>,
-) -> Vec<paragraph::Subitem10> {
-  let mut v = Vec::new();
-  while let Some(LineContents::Item(item_number, text)) = lines.peek() {
-    if item_number.pattern == parent_pattern {
+/// 様式の中身を読み込む
+///
+/// 附則や別表の見出しの行が現れるまでの各行を、一つの文として読む。
+fn parse_style(lines: &mut Lines) -> Option<structs::StyleStruct> {
+  let mut contents = Vec::new();
+  while let Some(line) = lines.peek_line() {
+    if line.contents.is_appdx_heading() {
       break;
-    } else if item_number.pattern == now_pat {
-      lines.next();
-      let mut sentence_text = vec![text];
-      while let Some(LineContents::Text(s)) = lines.peek() {
-        lines.next();
-        sentence_text.push(s);
-      }
-      v.push(paragraph::Subitem10 {
-        title: None,
-        sentence: class::SentenceOrColumnOrTable::Sentence(
-          sentence_text
-            .iter()
-            .enumerate()
-            .map(|(n, s)| text_to_sentence(n + 1, s))
-            .collect(),
-        ),
-        struct_list: Vec::new(),
-        num: Some(ArticleNumber::from_item_number(item_number)),
-        delete: text.trim() == "削除" || text.trim() == "（削除）",
-        hide: false,
-      })
-    } else {
-      unreachable!()
     }
+    lines.next();
+    contents.push(contents::ContentsElement::Sentence(text_to_sentence(
+      1,
+      line.text.trim(),
+    )));
   }
-  v
+  if contents.is_empty() {
+    return None;
+  }
+  Some(structs::StyleStruct {
+    title: None,
+    title_remarks: Vec::new(),
+    style: contents::Style {
+      contentes: contents::Contents { contents },
+    },
+    style_remarks: Vec::new(),
+  })
 }
 
-fn text_to_sentence(num: usize, text: &str) -> sentence::Sentence {
-  sentence::Sentence {
-    contents: vec![sentence::SentenceElement::String(text.to_string())],
-    num: Some(num),
-    function: None,
-    indent: None,
+fn title_with_writing_mode(title: &str) -> text::TextWithWritingMode {
+  text::TextWithWritingMode {
+    contents: vec![text::TextElement::Text(title.to_string())],
     writing_mode: text::WritingMode::Vertical,
   }
 }
 
-pub(crate) fn parse_body(title: &str, text: &str) -> Result<law::LawBody> {
-  let mut lines = Vec::new();
-  for line in text.lines().map(|s| s.trim()) {
-    let line_contents = parse_line_contents(line);
-    lines.push(line_contents)
+fn table_column(cell: &str) -> table::TableColumn {
+  // 空欄も空の文として残す
+  let mut sentence = text_to_sentence(1, cell);
+  if cell.is_empty() {
+    sentence.contents.clear();
+  }
+  let contents = vec![table::TableColumnContents::Sentence(sentence)];
+  table::TableColumn {
+    contents,
+    border_top: line::LineStyle::Solid,
+    border_bottom: line::LineStyle::Solid,
+    border_left: line::LineStyle::Solid,
+    border_right: line::LineStyle::Solid,
+    rowspan: None,
+    colspan: None,
+    align: None,
+    valign: None,
+  }
+}
+
+/// `第一`や`第二の二`のような別表の番号を`Num`属性の形式にする
+fn appdx_num(title: &str) -> Option<String> {
+  let (_, num) = title.split_once('第')?;
+  let nums = num
+    .split('の')
+    .map(parse_kansuji)
+    .collect::<Option<Vec<_>>>()?;
+  Some(
+    nums
+      .iter()
+      .map(|n| n.to_string())
+      .collect::<Vec<_>>()
+      .join("_"),
+  )
+}
+
+/// 空行を除いた各行に行番号をつける
+fn numbered_lines<'a>(text: &'a str, patterns: &LinePatterns) -> Vec<NumberedLine<'a>> {
+  text
+    .lines()
+    .enumerate()
+    .map(|(i, line)| (i as u64 + 1, line))
+    .filter(|(_, line)| !line.trim().is_empty())
+    .map(|(number, text)| NumberedLine {
+      number,
+      text,
+      contents: patterns.line_contents(text),
+    })
+    .collect()
+}
+
+/// テキストを法令本体に変換する
+///
+/// どこにも配置できなかった行はエラー、使われなかった見出しや中身のない附則は警告として、行番号つきの診断情報を返す。
+pub(crate) fn parse_body(
+  title: &str,
+  text: &str,
+  options: &ParseTextOptions,
+) -> (law::LawBody, Vec<Diagnostic>) {
+  let patterns = LinePatterns::new();
+  let numbered_lines = numbered_lines(text, &patterns);
+  let lines = Lines {
+    lines: &numbered_lines,
+    pos: 0,
+    path: body_path("MainProvision", 1),
+    diagnostics: Vec::new(),
+    options,
+    patterns: &patterns,
+  };
+  parse_lines(title, Vec::new(), lines)
+}
+
+/// 題名・法令番号・制定文から始まるテキストを法令本体に変換する
+///
+/// 一行目を題名、二行目の括弧の中を法令番号とし、その後に続く見出しや条でない行を制定文とする。
+/// 制定文は空行か`前文`の行で終わり、その後の行は前文とする。
+/// 法令番号の文字列と、法令本体と診断情報を返す。
+pub(crate) fn parse_document(
+  text: &str,
+  options: &ParseTextOptions,
+) -> Result<(String, law::LawBody, Vec<Diagnostic>)> {
+  let patterns = LinePatterns::new();
+  let numbered_lines = numbered_lines(text, &patterns);
+  let mut lines = Lines {
+    lines: &numbered_lines,
+    pos: 0,
+    path: body_path("LawTitle", 1),
+    diagnostics: Vec::new(),
+    options,
+    patterns: &patterns,
+  };
+  let title = lines.peek_line().map_or("", |l| l.text.trim());
+  lines.next();
+  let law_num = match lines.peek_line() {
+    Some(NumberedLine {
+      contents: LineContents::Caption(law_num),
+      ..
+    }) => law_num,
+    line => {
+      let error = Error::ParsingError(
+        "LawNum".to_string(),
+        line.map_or(String::new(), |l| l.text.trim().to_string()),
+      );
+      return Err(Error::Located {
+        error: Box::new(error),
+        path: ElementPath {
+          segments: ["Law", "LawNum"]
+            .into_iter()
+            .map(|name| PathSegment {
+              name: name.to_string(),
+              index: 1,
+              num: None,
+            })
+            .collect(),
+        },
+        position: Position {
+          line: line.map_or(lines.line() + 1, |l| l.number),
+          column: 1,
+        },
+      });
+    }
+  };
+  lines.next();
+  let mut enact_statement = Vec::new();
+  while let Some(line) = lines.peek_line() {
+    match &line.contents {
+      // 空行の後は前文とする
+      LineContents::Text(_) if line.number > lines.line() + 1 => break,
+      LineContents::Text(s) if s == "前文" => {
+        lines.next();
+        break;
+      }
+      LineContents::Text(s) => {
+        lines.next();
+        enact_statement.push(text::Text::from_value(s));
+      }
+      _ => break,
+    }
   }
+  lines.path = body_path("MainProvision", 1);
+  let (body, diagnostics) = parse_lines(title, enact_statement, lines);
+  Ok((law_num.clone(), body, diagnostics))
+}
+
+fn parse_lines(
+  title: &str,
+  enact_statement: Vec<text::Text>,
+  mut lines: Lines,
+) -> (law::LawBody, Vec<Diagnostic>) {
   let mut preamble_text_list: Vec<String> = Vec::new();
   let mut is_preamble = true;
   let mut main_provision_children = Vec::new();
   let mut suppl_provision = Vec::new();
   let mut suppl_provision_children = Vec::new();
-  let mut suppl_provision_law_num_opt: Option<Option<String>> = None;
+  let mut suppl_provision_header: Option<SupplProvisionHeader> = None;
+  let mut suppl_provision_count = 0;
+  let mut appdx_table = Vec::new();
+  let mut appdx_style = Vec::new();
+  let mut toc = None;
 
   let mut caption_opt = None;
-  let mut lines = lines.iter().peekable();
 
   while let Some(line_contents) = lines.peek() {
     match line_contents {
-      LineContents::Part(n, text) => {
+      LineContents::TOCLabel(label)
+        if toc.is_none() && is_preamble && preamble_text_list.is_empty() =>
+      {
         lines.next();
+        lines.unused_caption(caption_opt.take());
+        lines.path = body_path("TOC", 1);
+        toc = Some(parse_toc(label, &mut lines));
+        lines.path = body_path("MainProvision", 1);
+      }
+      LineContents::Part(n, text) if suppl_provision_header.is_none() => {
+        lines.next();
+        lines.unused_caption(caption_opt.take());
         let part = parse_part(n, text, &mut lines);
         main_provision_children.push(law::MainProvisionContents::Part(part));
-        caption_opt = None;
         is_preamble = false;
       }
       LineContents::Chapter(n, text) => {
         lines.next();
+        lines.unused_caption(caption_opt.take());
         let chap = parse_chapter(n, text, &mut lines);
-        if suppl_provision_law_num_opt.is_some() {
+        if suppl_provision_header.is_some() {
           suppl_provision_children.push(suppl_provision::SupplProvisionChildrenElement::Chapter(
             chap,
           ))
         } else {
           main_provision_children.push(law::MainProvisionContents::Chapter(chap));
         }
-        caption_opt = None;
         is_preamble = false;
       }
-      LineContents::Section(n, text) => {
+      LineContents::Section(n, text) if suppl_provision_header.is_none() => {
         lines.next();
+        lines.unused_caption(caption_opt.take());
         let sec = parse_section(n, text, &mut lines);
         main_provision_children.push(law::MainProvisionContents::Section(sec));
-        caption_opt = None;
         is_preamble = false;
       }
       LineContents::Caption(cap) => {
-        caption_opt = Some(cap);
+        lines.caption(&mut caption_opt, cap);
         is_preamble = false;
-        lines.next();
       }
       LineContents::Article(n, text) => {
         lines.next();
-        let article = parse_article(caption_opt, n, text, &mut lines);
-        if suppl_provision_law_num_opt.is_some() {
+        let article = parse_article(caption_opt.take(), n, text, &mut lines);
+        if suppl_provision_header.is_some() {
           suppl_provision_children.push(suppl_provision::SupplProvisionChildrenElement::Article(
             article,
           ))
         } else {
           main_provision_children.push(law::MainProvisionContents::Article(article));
         }
-        caption_opt = None;
         is_preamble = false;
       }
-      LineContents::Text(s) => {
-        if is_preamble {
-          preamble_text_list.push(s.to_string());
-          lines.next();
-        }
+      LineContents::Text(s) if is_preamble => {
+        preamble_text_list.push(s.to_string());
+        lines.next();
       }
-      LineContents::SupplProvision(law_num) => {
-        if let Some(amend_law_num) = suppl_provision_law_num_opt {
-          if !suppl_provision_children.is_empty() {
-            suppl_provision.push(suppl_provision::SupplProvision {
-              label: text::Text::from_value("附則"),
-              children: suppl_provision_children,
-              suppl_provision_type: None,
-              amend_law_num,
-              extract: None,
-            })
-          }
-        }
-        suppl_provision_law_num_opt = Some(law_num.clone());
-        suppl_provision_children = Vec::new();
+      // 項だけからなる附則
+      LineContents::Text(text)
+        if suppl_provision_header.is_some() && suppl_provision_children.is_empty() =>
+      {
+        lines.next();
+        lines.unused_caption(caption_opt.take());
+        let mut para =
+          parse_paragraph(&ArticleNumber::from_num_str("1").unwrap(), text, &mut lines);
+        para.paragraph_num = text::Text::new();
+        suppl_provision_children.push(suppl_provision::SupplProvisionChildrenElement::Paragraph(
+          para,
+        ));
+      }
+      LineContents::Paragraph(n, text)
+        if suppl_provision_header.is_some()
+          && suppl_provision_children.iter().all(|c| {
+            matches!(
+              c,
+              suppl_provision::SupplProvisionChildrenElement::Paragraph(_)
+            )
+          }) =>
+      {
+        lines.next();
+        lines.unused_caption(caption_opt.take());
+        let para = parse_paragraph(n, text, &mut lines);
+        suppl_provision_children.push(suppl_provision::SupplProvisionChildrenElement::Paragraph(
+          para,
+        ));
+      }
+      LineContents::SupplProvision {
+        label,
+        amend_law_num,
+        extract,
+      } => {
+        lines.next();
+        lines.unused_caption(caption_opt.take());
+        push_suppl_provision(
+          &mut lines,
+          &mut suppl_provision,
+          suppl_provision_header.take(),
+          std::mem::take(&mut suppl_provision_children),
+        );
+        suppl_provision_count += 1;
+        lines.path = body_path("SupplProvision", suppl_provision_count);
+        suppl_provision_header = Some(SupplProvisionHeader {
+          line: lines.line(),
+          label,
+          amend_law_num,
+          extract: *extract,
+        });
+        is_preamble = false;
+      }
+      LineContents::SupplProvisionAppdxTable(title, related_article_num)
+        if suppl_provision_header.is_some() =>
+      {
+        lines.next();
+        lines.unused_caption(caption_opt.take());
+        let table_struct = parse_table(&mut lines).into_iter().collect();
+        suppl_provision_children.push(
+          suppl_provision::SupplProvisionChildrenElement::SupplProvisionAppdxTable(
+            suppl_provision::SupplProvisionAppdxTable {
+              title: title_with_writing_mode(title),
+              related_article_num: related_article_num.as_ref().map(text::Text::from_value),
+              table_struct,
+              num: appdx_num(title),
+            },
+          ),
+        );
+      }
+      LineContents::AppdxTable(title, related_article_num) => {
+        lines.next();
+        lines.unused_caption(caption_opt.take());
+        lines.path = body_path("AppdxTable", appdx_table.len() + 1);
+        let children = parse_table(&mut lines)
+          .map(appdx::AppdxTableContents::TableStruct)
+          .into_iter()
+          .collect();
+        appdx_table.push(appdx::AppdxTable {
+          title: Some(title_with_writing_mode(title)),
+          related_article_num: related_article_num.as_ref().map(text::Text::from_value),
+          children,
+          remarks: None,
+          num: appdx_num(title),
+        });
+        is_preamble = false;
+      }
+      LineContents::AppdxStyle(title, related_article_num) => {
+        lines.next();
+        lines.unused_caption(caption_opt.take());
+        lines.path = body_path("AppdxStyle", appdx_style.len() + 1);
+        let children = parse_style(&mut lines).into_iter().collect();
+        appdx_style.push(appdx::AppdxStyle {
+          title: Some(title_with_writing_mode(title)),
+          related_article_num: related_article_num.as_ref().map(text::Text::from_value),
+          children,
+          remarks: None,
+          num: appdx_num(title),
+        });
+        is_preamble = false;
+      }
+      _ => {
+        lines.unused_caption(caption_opt.take());
+        lines.skip();
       }
-      _ => break,
     }
   }
+  lines.unused_caption(caption_opt.take());
+  push_suppl_provision(
+    &mut lines,
+    &mut suppl_provision,
+    suppl_provision_header.take(),
+    suppl_provision_children,
+  );
 
   let preamble = if preamble_text_list.is_empty() {
     None
@@ -863,39 +1144,28 @@ pub(crate) fn parse_body(title: &str, text: &str) -> Result<law::LawBody> {
     extract: None,
   };
 
-  if let Some(amend_law_num) = suppl_provision_law_num_opt {
-    if !suppl_provision_children.is_empty() {
-      suppl_provision.push(suppl_provision::SupplProvision {
-        label: text::Text::from_value("附則"),
-        children: suppl_provision_children,
-        suppl_provision_type: None,
-        amend_law_num,
-        extract: None,
-      })
-    }
-  }
-
   let law_title = law::LawTitle {
     kana: None,
     abbrev: None,
     abbrev_kana: None,
     text: text::Text::from_value(title),
   };
-  Ok(law::LawBody {
+  let body = law::LawBody {
     law_title: Some(law_title),
-    enact_statement: Vec::new(),
+    enact_statement,
     subject: None,
-    toc: None,
+    toc,
     preamble,
     main_provision,
     suppl_provision,
-    appdx_table: Vec::new(),
+    appdx_table,
     appdx_note: Vec::new(),
-    appdx_style: Vec::new(),
+    appdx_style,
     appdx: Vec::new(),
     appdx_fig: Vec::new(),
     appdx_format: Vec::new(),
-  })
+  };
+  (body, lines.diagnostics)
 }
 
 /// 各行が何に当てはまるのかの種類
@@ -903,6 +1173,8 @@ pub(crate) fn parse_body(title: &str, text: &str) -> Result<law::LawBody> {
 enum LineContents {
   /// 見出し：（見出し）
   Caption(String),
+  /// 目次：目次
+  TOCLabel(String),
   /// 編：第一編　タイトル
   Part(article_number::ArticleNumber, String),
   /// 章：第一章　タイトル
@@ -930,49 +1202,129 @@ enum LineContents {
   /// - <https://elaws.e-gov.go.jp/document?lawid=403M50000400049#Mp-At_9>
   /// - <https://note.com/lawyer_alpaca/n/ne09c189e813b#Gcaq9>
   Item(article_number::ItemNumber, String),
-  /// 附則：附則（昭和三一年四月二日法律第六三号）
-  SupplProvision(Option<String>),
+  /// 附則：附則（昭和三一年四月二日法律第六三号）　抄
+  SupplProvision {
+    /// `附則`や`附　則`
+    label: String,
+    /// 改正法令の法令番号
+    amend_law_num: Option<String>,
+    /// `抄`がついているかどうか
+    extract: bool,
+  },
+  /// 附則別表：附則別表第一（第三条関係）
+  SupplProvisionAppdxTable(String, Option<String>),
+  /// 別表：別表第一（第三条関係）
+  AppdxTable(String, Option<String>),
+  /// 様式：様式第一（第五条関係）
+  AppdxStyle(String, Option<String>),
   /// その他テキスト
   Text(String),
 }
 
-fn parse_line_contents(line: &str) -> LineContents {
-  use LineContents::*;
-  let line = line.trim();
-  let re_caption = Regex::new("^（(?<caption>[^）]+)）$").unwrap();
-  let re_suppl_provision =
-    Regex::new(r"^附([　\s]*)則([　\s]*)(（(?<law_num>.+)）)?[^（）]*$").unwrap();
-  if let Some(caps) = re_caption.captures(line) {
-    Caption(caps["caption"].to_string())
-  } else if let Some((s, text)) = split_number(line) {
-    println!("{s}: {text}");
-    if let Some(article_number) = article_number::parse_article_number(&s) {
-      if s.contains('編') {
-        Part(article_number, text)
-      } else if s.contains('章') {
-        Chapter(article_number, text)
-      } else if s.contains('節') {
-        Section(article_number, text)
-      } else if s.contains('款') {
-        Subsection(article_number, text)
-      } else if s.contains('目') {
-        Division(article_number, text)
-      } else if s.contains('条') {
-        Article(article_number, text)
+impl LineContents {
+  /// 附則や別表などの見出しかどうか
+  fn is_appdx_heading(&self) -> bool {
+    matches!(
+      self,
+      LineContents::SupplProvision { .. }
+        | LineContents::SupplProvisionAppdxTable(..)
+        | LineContents::AppdxTable(..)
+        | LineContents::AppdxStyle(..)
+    )
+  }
+}
+
+/// 行の種類の判定に使う正規表現
+///
+/// 行ごとに作り直さないよう、テキストを読み始めるときに一度だけ作る。
+struct LinePatterns {
+  caption: Regex,
+  toc_label: Regex,
+  suppl_provision: Regex,
+  suppl_provision_appdx_table: Regex,
+  appdx_table: Regex,
+  appdx_style: Regex,
+  /// 目次の項目の条の範囲
+  article_range: Regex,
+}
+
+impl LinePatterns {
+  fn new() -> Self {
+    LinePatterns {
+      caption: Regex::new("^（(?<caption>[^）]+)）$").unwrap(),
+      toc_label: Regex::new(r"^目[　\s]*次$").unwrap(),
+      suppl_provision: Regex::new(
+        r"^(?<label>附[　\s]*則)[　\s]*(（(?<law_num>.+)）)?[　\s]*(?<extract>抄)?$",
+      )
+      .unwrap(),
+      suppl_provision_appdx_table: Regex::new(
+        r"^(?<title>附則別表[^（　\s]*)[　\s]*(?<related>（[^）]+関係）)?$",
+      )
+      .unwrap(),
+      appdx_table: Regex::new(r"^(?<title>別表[^（　\s]*)[　\s]*(?<related>（[^）]+関係）)?$")
+        .unwrap(),
+      appdx_style: Regex::new(
+        r"^(?<title>(別記)?様式[^（　\s]*)[　\s]*(?<related>（[^）]+関係）)?$",
+      )
+      .unwrap(),
+      article_range: Regex::new(r"^(?<title>.*?)[　\s]*(?<range>（第[^（）]*条[^（）]*）)$")
+        .unwrap(),
+    }
+  }
+
+  fn line_contents(&self, line: &str) -> LineContents {
+    use LineContents::*;
+    let line = line.trim();
+    if let Some(caps) = self.caption.captures(line) {
+      Caption(caps["caption"].to_string())
+    } else if self.toc_label.is_match(line) {
+      TOCLabel(line.to_string())
+    } else if let Some(caps) = self.suppl_provision.captures(line) {
+      SupplProvision {
+        label: caps["label"].to_string(),
+        amend_law_num: caps.name("law_num").map(|m| m.as_str().to_string()),
+        extract: caps.name("extract").is_some(),
+      }
+    } else if let Some(caps) = self.suppl_provision_appdx_table.captures(line) {
+      SupplProvisionAppdxTable(
+        caps["title"].to_string(),
+        caps.name("related").map(|m| m.as_str().to_string()),
+      )
+    } else if let Some(caps) = self.appdx_table.captures(line) {
+      AppdxTable(
+        caps["title"].to_string(),
+        caps.name("related").map(|m| m.as_str().to_string()),
+      )
+    } else if let Some(caps) = self.appdx_style.captures(line) {
+      AppdxStyle(
+        caps["title"].to_string(),
+        caps.name("related").map(|m| m.as_str().to_string()),
+      )
+    } else if let Some((s, text)) = split_number(line) {
+      if let Some(article_number) = article_number::parse_article_number(&s) {
+        if s.contains('編') {
+          Part(article_number, text)
+        } else if s.contains('章') {
+          Chapter(article_number, text)
+        } else if s.contains('節') {
+          Section(article_number, text)
+        } else if s.contains('款') {
+          Subsection(article_number, text)
+        } else if s.contains('目') {
+          Division(article_number, text)
+        } else if s.contains('条') {
+          Article(article_number, text)
+        } else {
+          Paragraph(article_number, text)
+        }
+      } else if let Some(item_number) = article_number::parse_item_number(&s) {
+        Item(item_number, text)
       } else {
-        Paragraph(article_number, text)
+        Text(line.to_string())
       }
-    } else if let Some(item_number) = article_number::parse_item_number(&s) {
-      Item(item_number, text)
     } else {
-      println!("text!!!{s}: {text}");
       Text(line.to_string())
     }
-  } else if let Some(caps) = re_suppl_provision.captures(line) {
-    let law_num = caps.name("law_num").map(|m| m.as_str().to_string());
-    SupplProvision(law_num)
-  } else {
-    Text(line.to_string())
   }
 }
 
@@ -995,7 +1347,11 @@ fn check_parse_line_contents_1() {
     イ　主たる債務者の総株主の議決権（株主総会において決議をすることができる事項の全部につき議決権を行使することができない株式についての議決権を除く。以下この号において同じ。）の過半数を有する者
   三　不動産その他重要な財産に関する権利の得喪を目的とする行為をすること。
   ２　家庭裁判所は、第十一条本文に規定する者又は保佐人若しくは保佐監督人の請求により、被保佐人が前項各号に掲げる行為以外の行為をする場合であってもその保佐人の同意を得なければならない旨の審判をすることができる。ただし、第九条ただし書に規定する行為については、この限りでない。";
-  let r = s.lines().map(parse_line_contents).collect::<Vec<_>>();
+  let patterns = LinePatterns::new();
+  let r = s
+    .lines()
+    .map(|line| patterns.line_contents(line))
+    .collect::<Vec<_>>();
   assert_eq!(
     r,
     vec![
@@ -1045,7 +1401,9 @@ fn check_parse_body_1() {
     イ　主たる債務者の総株主の議決権（株主総会において決議をすることができる事項の全部につき議決権を行使することができない株式についての議決権を除く。以下この号において同じ。）の過半数を有する者
   三　不動産その他重要な財産に関する権利の得喪を目的とする行為をすること。
   ２　家庭裁判所は、第十一条本文に規定する者又は保佐人若しくは保佐監督人の請求により、被保佐人が前項各号に掲げる行為以外の行為をする場合であってもその保佐人の同意を得なければならない旨の審判をすることができる。ただし、第九条ただし書に規定する行為については、この限りでない。";
-  let main_provision = parse_body("title", s).unwrap().main_provision.children;
+  let (body, diagnostics) = parse_body("title", s, &ParseTextOptions::default());
+  assert!(diagnostics.is_empty());
+  let main_provision = body.main_provision.children;
   assert_eq!(
     main_provision,
     vec![law::MainProvisionContents::Part(article::Part {
@@ -1058,7 +1416,7 @@ fn check_parse_body_1() {
               text: text::Text::from_value("基本原則"),
               common_caption: None
             }),
-            title: text::Text::from_value(""),
+            title: text::Text::from_value("第一条"),
             paragraph: vec![
               paragraph::Paragraph {
                 caption: None,
@@ -1083,7 +1441,7 @@ fn check_parse_body_1() {
               },
               paragraph::Paragraph {
                 caption: None,
-                paragraph_num: text::Text::new(),
+                paragraph_num: text::Text::from_value("２"),
                 amend_provision: Vec::new(),
                 class: Vec::new(),
                 sentence: vec![sentence::Sentence {
@@ -1105,7 +1463,7 @@ fn check_parse_body_1() {
               },
               paragraph::Paragraph {
                 caption: None,
-                paragraph_num: text::Text::new(),
+                paragraph_num: text::Text::from_value("３"),
                 amend_provision: Vec::new(),
                 class: Vec::new(),
                 sentence: vec![sentence::Sentence {
@@ -1135,7 +1493,7 @@ fn check_parse_body_1() {
               text: text::Text::from_value("解釈の基準"),
               common_caption: None
             }),
-            title: text::Text::from_value(""),
+            title: text::Text::from_value("第二条"),
             paragraph: vec![paragraph::Paragraph {
               caption: None,
               paragraph_num: text::Text::new(),
@@ -1165,7 +1523,7 @@ fn check_parse_body_1() {
           }),
           article::ChapterContents::Article(article::Article {
             caption: None,
-            title: text::Text::from_value(""),
+            title: text::Text::from_value("第十三条"),
             paragraph: vec![paragraph::Paragraph {
               caption: None,
               paragraph_num: text::Text::new(),
@@ -1184,7 +1542,7 @@ fn check_parse_body_1() {
               struct_list: Vec::new(),
               children: vec![
                 paragraph::Item {
-                  title: None,
+                  title: Some(text::Text::from_value("一")),
                   sentence: class::SentenceOrColumnOrTable::Sentence(vec![text_to_sentence(1, "元本を領収し、又は利用すること。")]),
                   children: Vec::new(),
                   struct_list: Vec::new(),
@@ -1193,11 +1551,11 @@ fn check_parse_body_1() {
                   hide: false
                 },
                 paragraph::Item {
-                  title: None,
+                  title: Some(text::Text::from_value("二")),
                   sentence: class::SentenceOrColumnOrTable::Sentence(vec![text_to_sentence(1, "主たる債務者が法人である場合の次に掲げる者")]),
                   children: vec![
                     paragraph::Subitem1 {
-                      title: None,
+                      title: Some(text::Text::from_value("イ")),
                       sentence: class::SentenceOrColumnOrTable::Sentence(vec![text_to_sentence(1, "主たる債務者の総株主の議決権（株主総会において決議をすることができる事項の全部につき議決権を行使することができない株式についての議決権を除く。以下この号において同じ。）の過半数を有する者")]),
                       children: Vec::new(),
                       struct_list: Vec::new(),
@@ -1212,7 +1570,7 @@ fn check_parse_body_1() {
                   hide: false
                 },
                 paragraph::Item {
-                  title: None,
+                  title: Some(text::Text::from_value("三")),
                   sentence: class::SentenceOrColumnOrTable::Sentence(vec![text_to_sentence(1, "不動産その他重要な財産に関する権利の得喪を目的とする行為をすること。")]),
                   children: Vec::new(),
                   struct_list: Vec::new(),
@@ -1227,7 +1585,7 @@ fn check_parse_body_1() {
               hide: false,
             },paragraph::Paragraph {
               caption: None,
-              paragraph_num: text::Text::new(),
+              paragraph_num: text::Text::from_value("２"),
               amend_provision: Vec::new(),
               class: Vec::new(),
               sentence: vec![sentence::Sentence {
@@ -1,10 +1,316 @@
 //! XML形式を法律構造に変換するトレイト
+//!
+//! XMLの木構造を一度に構築せず、イベント列を先頭から読み進めながら法律構造を組み立てる。
 
-use crate::result::Result;
-use xmltree::Element;
+use crate::input::KeepIoError;
+use crate::result::{Diagnostic, ElementPath, Error, PathSegment, Position, Result, Severity};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::{self, Read};
+use std::rc::Rc;
+use std::sync::Arc;
+use xml::common::Position as _;
+use xml::reader::{EventReader, ParserConfig, XmlEvent};
 
 pub(crate) trait Parser {
-  fn parser(node: &Element) -> Result<Self>
+  fn parser(reader: &mut XmlReader, element: &Tag) -> Result<Self>
   where
     Self: Sized;
 }
+
+/// 開始タグの情報
+#[derive(Debug, Clone)]
+pub(crate) struct Tag {
+  pub(crate) name: String,
+  pub(crate) attributes: HashMap<String, String>,
+  /// このタグの内側にいるときの深さ
+  depth: usize,
+  /// 同じ名前の兄弟要素の中で何番目か
+  index: usize,
+}
+
+impl Tag {
+  fn segment(&self) -> PathSegment {
+    PathSegment {
+      name: self.name.clone(),
+      index: self.index,
+      num: self.attributes.get("Num").cloned(),
+    }
+  }
+}
+
+/// 子ノード
+#[derive(Debug, Clone)]
+pub(crate) enum Node {
+  Element(Tag),
+  Text(String),
+}
+
+/// イベント列を読み進めるXMLリーダー
+pub(crate) struct XmlReader<'a> {
+  reader: EventReader<Box<dyn Read + 'a>>,
+  /// 入力の読み込みで起きたエラー
+  io_error: Rc<RefCell<Option<io::Error>>>,
+  depth: usize,
+  /// 現在読んでいる要素までのパス
+  path: Vec<PathSegment>,
+  /// 現在読んでいる要素それぞれの開始タグの位置
+  positions: Vec<Position>,
+  /// 最後に閉じた要素
+  last_closed: Option<(PathSegment, Position)>,
+  /// 最後に読んだイベントが閉じタグかどうか
+  after_end: bool,
+  /// 深さごとの子要素の名前の出現回数
+  counts: Vec<HashMap<String, usize>>,
+  /// エラーがあっても読み進めるかどうか
+  lenient: bool,
+  diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> XmlReader<'a> {
+  pub(crate) fn new<R: Read + 'a>(r: R) -> Self {
+    // 書き出したXMLを読み直して元と等しくなるよう、コメントを取り除き隣り合う文字列をまとめる
+    let config = ParserConfig::new()
+      .ignore_comments(true)
+      .cdata_to_characters(true)
+      .coalesce_characters(true);
+    let (source, io_error) = KeepIoError::new(r);
+    let source: Box<dyn Read + 'a> = Box::new(source);
+    XmlReader {
+      reader: EventReader::new_with_config(source, config),
+      io_error,
+      depth: 0,
+      path: Vec::new(),
+      positions: Vec::new(),
+      last_closed: None,
+      after_end: false,
+      counts: vec![HashMap::new()],
+      lenient: false,
+      diagnostics: Vec::new(),
+    }
+  }
+
+  /// エラーのあった要素を読み飛ばして診断情報に記録するリーダー
+  pub(crate) fn new_lenient<R: Read + 'a>(r: R) -> Self {
+    XmlReader {
+      lenient: true,
+      ..XmlReader::new(r)
+    }
+  }
+
+  /// 記録した診断情報を取り出す
+  pub(crate) fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+    std::mem::take(&mut self.diagnostics)
+  }
+
+  fn start_tag(&mut self, name: String, attributes: HashMap<String, String>) -> Tag {
+    self.depth += 1;
+    let index = {
+      let counts = self.counts.last_mut().unwrap();
+      let count = counts.entry(name.clone()).or_insert(0);
+      *count += 1;
+      *count
+    };
+    let tag = Tag {
+      name,
+      attributes,
+      depth: self.depth,
+      index,
+    };
+    self.path.push(tag.segment());
+    self.positions.push(self.reader.position().into());
+    self.counts.push(HashMap::new());
+    self.after_end = false;
+    tag
+  }
+
+  fn end_tag(&mut self) {
+    self.depth -= 1;
+    self.last_closed = self.path.pop().zip(self.positions.pop());
+    self.counts.pop();
+    self.after_end = true;
+  }
+
+  fn next_event(&mut self) -> Result<XmlEvent> {
+    self
+      .reader
+      .next()
+      .map_err(|e| match self.io_error.borrow_mut().take() {
+        Some(err) => Error::IoError(Arc::new(err)),
+        None => Error::XMLParsing {
+          message: e.msg().to_string(),
+          position: e.position().into(),
+        },
+      })
+  }
+
+  /// 直前に読んだ要素のパスと位置
+  ///
+  /// 閉じタグを読んだ直後であれば閉じた要素を、そうでなければ読んでいる途中の要素を指す。
+  fn location(&self) -> (ElementPath, Position) {
+    let mut segments = self.path.clone();
+    let mut position = self.positions.last().copied().unwrap_or_default();
+    if self.after_end {
+      if let Some((segment, pos)) = &self.last_closed {
+        segments.push(segment.clone());
+        position = *pos;
+      }
+    }
+    (ElementPath { segments }, position)
+  }
+
+  /// エラーに位置情報をつける
+  pub(crate) fn locate(&self, err: Error) -> Error {
+    match err {
+      Error::Located { .. } | Error::XMLParsing { .. } | Error::IoError(_) => err,
+      err => {
+        let (path, position) = self.location();
+        Error::Located {
+          error: Box::new(err),
+          path,
+          position,
+        }
+      }
+    }
+  }
+
+  fn push_diagnostic(&mut self, err: Error, severity: Severity) {
+    let diagnostic = match self.locate(err) {
+      Error::Located {
+        error,
+        path,
+        position,
+      } => Diagnostic {
+        path,
+        position,
+        severity,
+        error: *error,
+      },
+      err => {
+        let (path, position) = self.location();
+        Diagnostic {
+          path,
+          position,
+          severity,
+          error: err,
+        }
+      }
+    };
+    self.diagnostics.push(diagnostic);
+  }
+
+  /// ルート要素の開始タグまで読み進める
+  pub(crate) fn root(&mut self) -> Result<Tag> {
+    loop {
+      match self.next_event()? {
+        XmlEvent::StartElement {
+          name, attributes, ..
+        } => {
+          let attributes = attributes
+            .into_iter()
+            .map(|att| (att.name.local_name, att.value))
+            .collect();
+          return Ok(self.start_tag(name.local_name, attributes));
+        }
+        XmlEvent::EndDocument => {
+          return Err(Error::XMLParsing {
+            message: "root element not found".to_string(),
+            position: self.reader.position().into(),
+          })
+        }
+        _ => (),
+      }
+    }
+  }
+
+  /// `element`の次の子ノードを読み出す。閉じタグに到達したら`None`を返す
+  ///
+  /// 前回返した子要素の中身が読み残されていた場合は読み飛ばす。
+  pub(crate) fn next_child(&mut self, element: &Tag) -> Result<Option<Node>> {
+    if self.depth < element.depth {
+      return Ok(None);
+    }
+    loop {
+      match self.next_event()? {
+        XmlEvent::StartElement {
+          name, attributes, ..
+        } => {
+          let attributes = attributes
+            .into_iter()
+            .map(|att| (att.name.local_name, att.value))
+            .collect();
+          let tag = self.start_tag(name.local_name, attributes);
+          if tag.depth == element.depth + 1 {
+            return Ok(Some(Node::Element(tag)));
+          }
+        }
+        XmlEvent::EndElement { .. } => {
+          self.end_tag();
+          if self.depth < element.depth {
+            return Ok(None);
+          }
+        }
+        XmlEvent::Characters(s) | XmlEvent::CData(s) if self.depth == element.depth => {
+          self.after_end = false;
+          return Ok(Some(Node::Text(s)));
+        }
+        XmlEvent::EndDocument => return Ok(None),
+        _ => (),
+      }
+    }
+  }
+
+  /// `element`の子ノードを順に`f`で処理する
+  ///
+  /// 寛容モードでは`f`が失敗した子ノードを読み飛ばし、診断情報に記録して続行する。
+  pub(crate) fn each_child<F>(&mut self, element: &Tag, mut f: F) -> Result<()>
+  where
+    F: FnMut(&mut Self, Node) -> Result<()>,
+  {
+    while let Some(node) = self.next_child(element)? {
+      if let Err(err) = f(self, node) {
+        if !self.lenient || matches!(err, Error::XMLParsing { .. } | Error::IoError(_)) {
+          return Err(self.locate(err));
+        }
+        self.push_diagnostic(err, Severity::Error);
+      }
+    }
+    Ok(())
+  }
+
+  /// 読み飛ばした要素を警告として記録する
+  pub(crate) fn warn(&mut self, err: Error) {
+    self.push_diagnostic(err, Severity::Warning);
+  }
+}
+
+/// 入力から要素を一つ読み出して変換する
+pub(crate) fn parse_reader<T: Parser, R: Read>(r: R) -> Result<T> {
+  parse_reader_with(r, T::parser)
+}
+
+/// 入力から要素を一つ読み出し、与えた関数で変換する
+pub(crate) fn parse_reader_with<T, R: Read>(
+  r: R,
+  f: fn(&mut XmlReader, &Tag) -> Result<T>,
+) -> Result<T> {
+  let mut reader = XmlReader::new(r);
+  let tag = reader.root()?;
+  f(&mut reader, &tag).map_err(|err| reader.locate(err))
+}
+
+/// 入力から要素を一つ読み出して変換し、読み飛ばした箇所の診断情報と一緒に返す
+pub(crate) fn parse_reader_lenient<T: Parser, R: Read>(r: R) -> Result<(T, Vec<Diagnostic>)> {
+  parse_reader_lenient_with(r, T::parser)
+}
+
+/// 入力から要素を一つ読み出して与えた関数で変換し、読み飛ばした箇所の診断情報と一緒に返す
+pub(crate) fn parse_reader_lenient_with<T, R: Read>(
+  r: R,
+  f: fn(&mut XmlReader, &Tag) -> Result<T>,
+) -> Result<(T, Vec<Diagnostic>)> {
+  let mut reader = XmlReader::new_lenient(r);
+  let tag = reader.root()?;
+  let t = f(&mut reader, &tag).map_err(|err| reader.locate(err))?;
+  Ok((t, reader.take_diagnostics()))
+}
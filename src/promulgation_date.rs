@@ -0,0 +1,192 @@
+//! 公布日
+//!
+//! 和暦の年月日と西暦の日付とを相互に変換する。
+
+use crate::article_number::parse_kansuji;
+use crate::law::{Era, Law};
+use crate::law_num::{era_name, kansuji};
+use crate::result::{Error, Result};
+use koyomi::Date;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// 和暦で表した公布日
+#[derive(Debug, Clone, Hash, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PromulgationDate {
+  /// 年号
+  pub era: Era,
+  /// 年号での年
+  pub year: usize,
+  /// 月
+  pub month: usize,
+  /// 日
+  pub day: usize,
+}
+
+/// 元年の西暦年
+fn first_year(era: &Era) -> usize {
+  match era {
+    Era::Meiji => 1868,
+    Era::Taisho => 1912,
+    Era::Showa => 1926,
+    Era::Heisei => 1989,
+    Era::Reiwa => 2019,
+  }
+}
+
+fn era_from_name(name: &str) -> Option<Era> {
+  match name {
+    "明治" => Some(Era::Meiji),
+    "大正" => Some(Era::Taisho),
+    "昭和" => Some(Era::Showa),
+    "平成" => Some(Era::Heisei),
+    "令和" => Some(Era::Reiwa),
+    _ => None,
+  }
+}
+
+impl PromulgationDate {
+  /// 和暦の年月日から生成する
+  ///
+  /// `昭和六十四年一月八日`のように、その年号の期間に含まれない日付はエラーになる。
+  pub fn new(era: Era, year: usize, month: usize, day: usize) -> Result<Self> {
+    let date = PromulgationDate {
+      era,
+      year,
+      month,
+      day,
+    };
+    date.to_date()?;
+    Ok(date)
+  }
+
+  /// 西暦の年月日から生成する
+  pub fn from_gregorian(year: usize, month: usize, day: usize) -> Result<Self> {
+    let err = || Error::InvalidDate(format!("{year:04}-{month:02}-{day:02}"));
+    let date = Date::from_ymd(year as i32, month as u32, day as u32).map_err(|_| err())?;
+    PromulgationDate::from_date(&date).ok_or_else(err)
+  }
+
+  /// 西暦の日付から生成する
+  ///
+  /// 明治より前の日付の場合は`None`を返す。
+  pub fn from_date(date: &Date) -> Option<Self> {
+    let e = koyomi::era(date)?;
+    Some(PromulgationDate {
+      era: era_from_name(&e.name())?,
+      year: e.year() as usize,
+      month: date.month() as usize,
+      day: date.day() as usize,
+    })
+  }
+
+  /// 西暦の日付に変換する
+  pub fn to_date(&self) -> Result<Date> {
+    let err = || Error::InvalidDate(self.to_string());
+    if self.year == 0 {
+      return Err(err());
+    }
+    let date = Date::from_ymd(
+      self.gregorian_year() as i32,
+      self.month as u32,
+      self.day as u32,
+    )
+    .map_err(|_| err())?;
+    match koyomi::era(&date) {
+      Some(e) if e.name() == era_name(&self.era) => Ok(date),
+      _ => Err(err()),
+    }
+  }
+
+  /// 西暦年
+  pub fn gregorian_year(&self) -> usize {
+    first_year(&self.era) + self.year - 1
+  }
+
+  /// 法律の構造体の年号・制定年・公布月・公布日から生成する
+  ///
+  /// 公布月か公布日がない場合は`None`を返す。
+  pub fn from_law(law: &Law) -> Result<Option<Self>> {
+    match (law.promulgate_month, law.promulgate_day) {
+      (Some(month), Some(day)) => {
+        PromulgationDate::new(law.era.clone(), law.year, month, day).map(Some)
+      }
+      _ => Ok(None),
+    }
+  }
+
+  /// 法律の構造体の年号・制定年・公布月・公布日を設定する
+  pub fn apply_to(&self, law: &mut Law) {
+    law.era = self.era.clone();
+    law.year = self.year;
+    law.promulgate_month = Some(self.month);
+    law.promulgate_day = Some(self.day);
+  }
+}
+
+impl FromStr for PromulgationDate {
+  type Err = Error;
+  fn from_str(s: &str) -> Result<Self> {
+    let err = || Error::ParsingError("PromulgationDate".to_string(), s.to_string());
+    let text = s.trim();
+    let era_end = text.char_indices().nth(2).map_or(text.len(), |(i, _)| i);
+    let era = era_from_name(&text[..era_end]).ok_or_else(err)?;
+    let rest = &text[era_end..];
+    let (year, rest) = rest.split_once('年').ok_or_else(err)?;
+    let year = if year == "元" {
+      1
+    } else {
+      parse_kansuji(year).ok_or_else(err)?
+    };
+    let (month, rest) = rest.split_once('月').ok_or_else(err)?;
+    let (day, rest) = rest.split_once('日').ok_or_else(err)?;
+    if !rest.is_empty() {
+      return Err(err());
+    }
+    let month = parse_kansuji(month).ok_or_else(err)?;
+    let day = parse_kansuji(day).ok_or_else(err)?;
+    PromulgationDate::new(era, year, month, day)
+  }
+}
+
+impl std::fmt::Display for PromulgationDate {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", era_name(&self.era))?;
+    if self.year == 1 {
+      write!(f, "元年")?;
+    } else {
+      write!(f, "{}年", kansuji(self.year))?;
+    }
+    write!(f, "{}月{}日", kansuji(self.month), kansuji(self.day))
+  }
+}
+
+#[test]
+fn check_promulgation_date() {
+  let date = PromulgationDate::from_gregorian(2023, 6, 14).unwrap();
+  assert_eq!(date, PromulgationDate::new(Era::Reiwa, 5, 6, 14).unwrap());
+  assert_eq!(date.to_string(), "令和五年六月十四日");
+  assert_eq!(
+    PromulgationDate::from_str("令和五年六月十四日").unwrap(),
+    date
+  );
+  assert_eq!(
+    date.to_date().unwrap(),
+    Date::from_ymd(2023, 6, 14).unwrap()
+  );
+
+  let date = PromulgationDate::from_gregorian(1989, 1, 7).unwrap();
+  assert_eq!((date.era.clone(), date.year), (Era::Showa, 64));
+  let date = PromulgationDate::from_gregorian(1989, 1, 8).unwrap();
+  assert_eq!((date.era.clone(), date.year), (Era::Heisei, 1));
+  assert_eq!(date.to_string(), "平成元年一月八日");
+  assert_eq!(
+    PromulgationDate::from_str("平成元年一月八日").unwrap(),
+    date
+  );
+  assert!(PromulgationDate::new(Era::Showa, 64, 1, 8).is_err());
+  assert!(PromulgationDate::new(Era::Heisei, 1, 1, 7).is_err());
+  assert!(PromulgationDate::new(Era::Reiwa, 5, 2, 30).is_err());
+  assert!(PromulgationDate::from_gregorian(1800, 1, 1).is_err());
+  assert!(PromulgationDate::from_str("令和五年六月").is_err());
+}
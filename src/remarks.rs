@@ -17,29 +17,30 @@ pub struct Remarks {
 }
 
 impl Parser for Remarks {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Remarks" {
       let mut label_opt = None;
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "RemarksLabel" => {
-              let v = RemarksLabel::parser(e)?;
+              let v = RemarksLabel::parser(reader, &e)?;
               label_opt = Some(v);
             }
             "Item" => {
-              let v = Item::parser(e)?;
+              let v = Item::parser(reader, &e)?;
               children.push(RemarksContents::Item(v));
             }
             "Sentence" => {
-              let v = Sentence::parser(e)?;
+              let v = Sentence::parser(reader, &e)?;
               children.push(RemarksContents::Sentence(v));
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(label) = label_opt {
         Ok(Remarks { label, children })
       } else {
@@ -56,6 +57,8 @@ impl Parser for Remarks {
 impl ToXmlElement for Remarks {
   fn to_xml_element(&self) -> Element {
     let mut e = Element::new("Remarks");
+    e.children
+      .push(XMLNode::Element(self.label.to_xml_element()));
     for n in self.children.iter() {
       match n {
         RemarksContents::Item(v) => {
@@ -77,10 +80,10 @@ pub struct RemarksLabel {
 }
 
 impl Parser for RemarksLabel {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "RemarksLabel" {
       let line_break = get_attribute_opt_with_parse(element, "LineBreak")?.unwrap_or(false);
-      let text = Text::from_children(&element.children);
+      let text = Text::from_reader(reader, element)?;
       Ok(RemarksLabel { text, line_break })
     } else {
       Err(Error::wrong_tag_name(element, "RemarksLabel"))
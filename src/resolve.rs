@@ -0,0 +1,376 @@
+//! 条文の引用の解決
+//!
+//! `第十三条第一項第二号イ`や`附則第三条`のような引用の文字列から、法令中の条や項などを探す。
+//!
+//! ```no_run
+//! use japanese_law_xml_schema::{parse_xml_file, resolve::Provision};
+//!
+//! let law = parse_xml_file("129AC0000000089_20230614_505AC0000000053.xml").unwrap();
+//! if let Provision::Item(item) = law.resolve("第十三条第一項第二号").unwrap() {
+//!   println!("{:?}", item.title);
+//! }
+//! ```
+
+use crate::appdx::*;
+use crate::article::*;
+use crate::article_number::{
+  parse_article_path_prefix, ArticleLevel, ArticleNumber, CitationSegment,
+};
+use crate::law::{Law, MainProvision, MainProvisionContents};
+use crate::law_num::LawNum;
+use crate::paragraph::*;
+use crate::result::{Error, Result};
+use crate::suppl_provision::{SupplProvision, SupplProvisionChildrenElement};
+use crate::text::Text;
+use std::str::FromStr;
+
+/// 引用によって指し示される要素
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Provision<'a> {
+  /// 本則
+  MainProvision(&'a MainProvision),
+  /// 附則
+  SupplProvision(&'a SupplProvision),
+  /// 編
+  Part(&'a Part),
+  /// 章
+  Chapter(&'a Chapter),
+  /// 節
+  Section(&'a Section),
+  /// 款
+  Subsection(&'a Subsection),
+  /// 目
+  Division(&'a Division),
+  /// 条
+  Article(&'a Article),
+  /// 項
+  Paragraph(&'a Paragraph),
+  /// 号
+  Item(&'a Item),
+  Subitem1(&'a Subitem1),
+  Subitem2(&'a Subitem2),
+  Subitem3(&'a Subitem3),
+  Subitem4(&'a Subitem4),
+  Subitem5(&'a Subitem5),
+  Subitem6(&'a Subitem6),
+  Subitem7(&'a Subitem7),
+  Subitem8(&'a Subitem8),
+  Subitem9(&'a Subitem9),
+  Subitem10(&'a Subitem10),
+  /// 別表
+  AppdxTable(&'a AppdxTable),
+  /// 付録記載
+  AppdxNote(&'a AppdxNote),
+  /// 別記様式
+  AppdxStyle(&'a AppdxStyle),
+  /// 別記書式
+  AppdxFormat(&'a AppdxFormat),
+  /// 付録
+  Appdx(&'a Appdx),
+  /// 別図
+  AppdxFig(&'a AppdxFig),
+}
+
+impl<'a> Provision<'a> {
+  /// 編から号までの階層
+  ///
+  /// 本則・附則と号の細分は`None`になる。
+  pub fn level(&self) -> Option<ArticleLevel> {
+    match self {
+      Provision::Part(_) => Some(ArticleLevel::Part),
+      Provision::Chapter(_) => Some(ArticleLevel::Chapter),
+      Provision::Section(_) => Some(ArticleLevel::Section),
+      Provision::Subsection(_) => Some(ArticleLevel::Subsection),
+      Provision::Division(_) => Some(ArticleLevel::Division),
+      Provision::Article(_) => Some(ArticleLevel::Article),
+      Provision::Paragraph(_) => Some(ArticleLevel::Paragraph),
+      Provision::Item(_) => Some(ArticleLevel::Item),
+      _ => None,
+    }
+  }
+
+  /// 番号
+  pub fn num(&self) -> Option<&'a ArticleNumber> {
+    match self {
+      Provision::MainProvision(_)
+      | Provision::SupplProvision(_)
+      | Provision::AppdxTable(_)
+      | Provision::AppdxNote(_)
+      | Provision::AppdxStyle(_)
+      | Provision::AppdxFormat(_)
+      | Provision::Appdx(_)
+      | Provision::AppdxFig(_) => None,
+      Provision::Part(t) => Some(&t.num),
+      Provision::Chapter(t) => Some(&t.num),
+      Provision::Section(t) => Some(&t.num),
+      Provision::Subsection(t) => Some(&t.num),
+      Provision::Division(t) => Some(&t.num),
+      Provision::Article(t) => Some(&t.num),
+      Provision::Paragraph(t) => Some(&t.num),
+      Provision::Item(t) => t.num.as_ref(),
+      Provision::Subitem1(t) => t.num.as_ref(),
+      Provision::Subitem2(t) => t.num.as_ref(),
+      Provision::Subitem3(t) => t.num.as_ref(),
+      Provision::Subitem4(t) => t.num.as_ref(),
+      Provision::Subitem5(t) => t.num.as_ref(),
+      Provision::Subitem6(t) => t.num.as_ref(),
+      Provision::Subitem7(t) => t.num.as_ref(),
+      Provision::Subitem8(t) => t.num.as_ref(),
+      Provision::Subitem9(t) => t.num.as_ref(),
+      Provision::Subitem10(t) => t.num.as_ref(),
+    }
+  }
+
+  /// 号か号の細分かどうか
+  fn is_item_like(&self) -> bool {
+    matches!(
+      self,
+      Provision::Item(_)
+        | Provision::Subitem1(_)
+        | Provision::Subitem2(_)
+        | Provision::Subitem3(_)
+        | Provision::Subitem4(_)
+        | Provision::Subitem5(_)
+        | Provision::Subitem6(_)
+        | Provision::Subitem7(_)
+        | Provision::Subitem8(_)
+        | Provision::Subitem9(_)
+        | Provision::Subitem10(_)
+    )
+  }
+
+  /// 直下の子要素
+  ///
+  /// 号とその細分は、子要素の号の細分とその項目名の組を返す。
+  pub(crate) fn children(&self) -> Vec<(Option<&'a Text>, Provision<'a>)> {
+    fn no_title<'a>(
+      v: impl Iterator<Item = Provision<'a>>,
+    ) -> Vec<(Option<&'a Text>, Provision<'a>)> {
+      v.map(|p| (None, p)).collect()
+    }
+    match self {
+      Provision::MainProvision(t) => no_title(t.children.iter().map(|c| match c {
+        MainProvisionContents::Part(t) => Provision::Part(t),
+        MainProvisionContents::Chapter(t) => Provision::Chapter(t),
+        MainProvisionContents::Section(t) => Provision::Section(t),
+        MainProvisionContents::Article(t) => Provision::Article(t),
+        MainProvisionContents::Paragraph(t) => Provision::Paragraph(t),
+      })),
+      Provision::SupplProvision(t) => no_title(t.children.iter().filter_map(|c| match c {
+        SupplProvisionChildrenElement::Chapter(t) => Some(Provision::Chapter(t)),
+        SupplProvisionChildrenElement::Article(t) => Some(Provision::Article(t)),
+        SupplProvisionChildrenElement::Paragraph(t) => Some(Provision::Paragraph(t)),
+        _ => None,
+      })),
+      Provision::Part(t) => no_title(t.children.iter().map(|c| match c {
+        PartContents::Article(t) => Provision::Article(t),
+        PartContents::Chapter(t) => Provision::Chapter(t),
+      })),
+      Provision::Chapter(t) => no_title(t.children.iter().map(|c| match c {
+        ChapterContents::Article(t) => Provision::Article(t),
+        ChapterContents::Section(t) => Provision::Section(t),
+      })),
+      Provision::Section(t) => no_title(t.children.iter().map(|c| match c {
+        SectionContents::Article(t) => Provision::Article(t),
+        SectionContents::Subsection(t) => Provision::Subsection(t),
+        SectionContents::Division(t) => Provision::Division(t),
+      })),
+      Provision::Subsection(t) => no_title(t.children.iter().map(|c| match c {
+        SubsectionContents::Article(t) => Provision::Article(t),
+        SubsectionContents::Division(t) => Provision::Division(t),
+      })),
+      Provision::Division(t) => no_title(t.children.iter().map(Provision::Article)),
+      Provision::Article(t) => no_title(t.paragraph.iter().map(Provision::Paragraph)),
+      Provision::Paragraph(t) => no_title(t.children.iter().map(Provision::Item)),
+      Provision::Item(t) => t
+        .children
+        .iter()
+        .map(|c| (c.title.as_ref(), Provision::Subitem1(c)))
+        .collect(),
+      Provision::Subitem1(t) => t
+        .children
+        .iter()
+        .map(|c| (c.title.as_ref(), Provision::Subitem2(c)))
+        .collect(),
+      Provision::Subitem2(t) => t
+        .children
+        .iter()
+        .map(|c| (c.title.as_ref(), Provision::Subitem3(c)))
+        .collect(),
+      Provision::Subitem3(t) => t
+        .children
+        .iter()
+        .map(|c| (c.title.as_ref(), Provision::Subitem4(c)))
+        .collect(),
+      Provision::Subitem4(t) => t
+        .children
+        .iter()
+        .map(|c| (c.title.as_ref(), Provision::Subitem5(c)))
+        .collect(),
+      Provision::Subitem5(t) => t
+        .children
+        .iter()
+        .map(|c| (c.title.as_ref(), Provision::Subitem6(c)))
+        .collect(),
+      Provision::Subitem6(t) => t
+        .children
+        .iter()
+        .map(|c| (c.title.as_ref(), Provision::Subitem7(c)))
+        .collect(),
+      Provision::Subitem7(t) => t
+        .children
+        .iter()
+        .map(|c| (c.title.as_ref(), Provision::Subitem8(c)))
+        .collect(),
+      Provision::Subitem8(t) => t
+        .children
+        .iter()
+        .map(|c| (c.title.as_ref(), Provision::Subitem9(c)))
+        .collect(),
+      Provision::Subitem9(t) => t
+        .children
+        .iter()
+        .map(|c| (c.title.as_ref(), Provision::Subitem10(c)))
+        .collect(),
+      Provision::Subitem10(_)
+      | Provision::AppdxTable(_)
+      | Provision::AppdxNote(_)
+      | Provision::AppdxStyle(_)
+      | Provision::AppdxFormat(_)
+      | Provision::Appdx(_)
+      | Provision::AppdxFig(_) => Vec::new(),
+    }
+  }
+
+  /// 子孫の中から、階層と番号が一致する最初の要素を探す
+  ///
+  /// 条や項の中には入らない。
+  fn find(&self, segment: &CitationSegment) -> Option<Provision<'a>> {
+    for (_, child) in self.children() {
+      if child.level() == Some(segment.level) && child.num() == Some(&segment.number) {
+        return Some(child);
+      }
+      let is_container = matches!(
+        child,
+        Provision::Part(_)
+          | Provision::Chapter(_)
+          | Provision::Section(_)
+          | Provision::Subsection(_)
+          | Provision::Division(_)
+      );
+      if is_container && child.level() < Some(segment.level) {
+        if let Some(p) = child.find(segment) {
+          return Some(p);
+        }
+      }
+    }
+    None
+  }
+
+  /// 番号で子要素を探す
+  fn child(&self, segment: &CitationSegment) -> Option<Provision<'a>> {
+    match (self, segment.level) {
+      // 項が一つしかない条では項を省略して号を引用する
+      (Provision::Article(_), ArticleLevel::Item) => self.children().first()?.1.child(segment),
+      (Provision::MainProvision(_) | Provision::SupplProvision(_), ArticleLevel::Item) => {
+        let (_, paragraph) = self
+          .children()
+          .into_iter()
+          .find(|(_, p)| matches!(p, Provision::Paragraph(_)))?;
+        paragraph.child(segment)
+      }
+      (Provision::Article(_) | Provision::Paragraph(_), _) => self
+        .children()
+        .into_iter()
+        .map(|(_, p)| p)
+        .find(|p| p.level() == Some(segment.level) && p.num() == Some(&segment.number)),
+      _ => self.find(segment),
+    }
+  }
+}
+
+/// `(1)`や`ｉ`のような号の細分の項目名を、法令XMLと同じ全角の文字にそろえる
+fn normalize_title(s: &str) -> String {
+  s.chars()
+    .map(|c| match c {
+      '(' => '（',
+      ')' => '）',
+      '!'..='~' => char::from_u32(c as u32 - 0x21 + 0xFF01).unwrap_or(c),
+      c => c,
+    })
+    .collect()
+}
+
+/// 附則の`AmendLawNum`属性が一致するかどうか
+///
+/// 法令番号として解釈できる場合は、漢数字の書き方の違いを無視して比べる。
+fn same_law_num(a: &str, b: &str) -> bool {
+  match (LawNum::from_str(a), LawNum::from_str(b)) {
+    (Ok(a), Ok(b)) => a == b,
+    _ => a.trim() == b.trim(),
+  }
+}
+
+impl Law {
+  /// `第十三条第一項第二号イ`や`附則第三条`のような引用が指し示す要素を探す
+  ///
+  /// 附則は`附則（平成一一年一二月八日法律第一四九号）第三条`のように改正法令の法令番号を括弧で添えて選ぶ。
+  /// 法令番号を添えない場合は、法令番号のない附則（制定時の附則）を探す。
+  pub fn resolve(&self, citation: &str) -> Result<Provision<'_>> {
+    let not_found = || Error::ProvisionNotFound(citation.to_string());
+    let parse_err = || Error::ParsingError("Citation".to_string(), citation.to_string());
+    let text = citation.trim();
+    let (mut current, rest) = match text.strip_prefix("附則") {
+      Some(rest) => {
+        let (amend_law_num, rest) = match rest.strip_prefix('（') {
+          Some(rest) => {
+            let (num, rest) = rest.split_once('）').ok_or_else(parse_err)?;
+            (Some(num), rest)
+          }
+          None => (None, rest),
+        };
+        let suppl_provision = self
+          .law_body
+          .suppl_provision
+          .iter()
+          .find(|s| match (&s.amend_law_num, amend_law_num) {
+            (Some(a), Some(b)) => same_law_num(a, b),
+            (None, None) => true,
+            _ => false,
+          })
+          .ok_or_else(not_found)?;
+        (Provision::SupplProvision(suppl_provision), rest)
+      }
+      None => (
+        Provision::MainProvision(&self.law_body.main_provision),
+        text,
+      ),
+    };
+    let (segments, rest) = parse_article_path_prefix(rest);
+    for segment in segments.iter() {
+      if segment.number.is_range() {
+        return Err(parse_err());
+      }
+      current = current.child(segment).ok_or_else(not_found)?;
+    }
+    let mut rest = normalize_title(rest);
+    while !rest.is_empty() {
+      if !current.is_item_like() {
+        return Err(parse_err());
+      }
+      let (len, child) = current
+        .children()
+        .into_iter()
+        .filter_map(|(title, p)| {
+          let title = title?.to_string();
+          let title = title.trim();
+          (!title.is_empty() && rest.starts_with(title)).then_some((title.len(), p))
+        })
+        .max_by_key(|(len, _)| *len)
+        .ok_or_else(not_found)?;
+      current = child;
+      rest = rest[len..].to_string();
+    }
+    Ok(current)
+  }
+}
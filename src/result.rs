@@ -1,5 +1,7 @@
 //! エラーハンドリング
 
+use std::path::PathBuf;
+use std::sync::Arc;
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -29,32 +31,198 @@ pub enum Error {
   },
   #[error("Unexpected {wrong_name} tag at {tag}")]
   UnexpectedTag { wrong_name: String, tag: String },
-  #[error("xml parsing error")]
-  XMLParsing,
+  #[error("xml parsing error at {position}: {message}")]
+  XMLParsing { message: String, position: Position },
   #[error("{0} parsing error: {1}")]
   ParsingError(String, String),
   #[error("write error")]
   Io,
+  #[error("Unsupported encoding {0}")]
+  UnsupportedEncoding(String),
+  /// 入力を読み込むときのエラー
+  #[error("io error: {0}")]
+  IoError(Arc<std::io::Error>),
+  /// ファイルを読み込むときのエラー
+  #[error("io error at {}: {error}", .path.display())]
+  FileIoError {
+    path: PathBuf,
+    error: Arc<std::io::Error>,
+  },
+  /// 圧縮ファイルを展開するときのエラー
+  #[error("archive error: {0}")]
+  Archive(String),
+  /// 参照された添付ファイルが見つからない
+  #[error("attachment {0} not found")]
+  AttachmentNotFound(String),
+  /// 引用された条などが見つからない
+  #[error("provision not found: {0}")]
+  ProvisionNotFound(String),
+  /// 存在しない日付
+  #[error("invalid date: {0}")]
+  InvalidDate(String),
+  /// 細分10より深い階層の号
+  #[error("item nested deeper than Subitem10")]
+  ItemTooDeep,
+  /// テキストの解析で、どこにも配置できなかった行
+  #[error("unexpected line: {0}")]
+  UnexpectedLine(String),
+  /// テキストの解析で、条につかなかった見出し
+  #[error("caption not followed by an article: {0}")]
+  UnusedCaption(String),
+  /// テキストの解析で、号の番号のように見えるが番号として読めなかった行頭
+  #[error("unreadable number: {0}")]
+  UnreadableNumber(String),
+  /// テキストの解析で、中身のない附則
+  #[error("empty supplementary provision: {0}")]
+  EmptySupplProvision(String),
+  /// 入力中の位置と要素のパスがついたエラー
+  #[error("{error} (in {path}, {position})")]
+  Located {
+    error: Box<Error>,
+    path: ElementPath,
+    position: Position,
+  },
 }
 
 impl Error {
+  /// 位置情報を取り除いたエラー
+  pub fn inner(&self) -> &Error {
+    match self {
+      Error::Located { error, .. } => error.inner(),
+      e => e,
+    }
+  }
+
+  /// エラーの起きた要素のパス
+  pub fn path(&self) -> Option<&ElementPath> {
+    match self {
+      Error::Located { path, .. } => Some(path),
+      _ => None,
+    }
+  }
+
+  /// エラーの起きた入力中の位置
+  pub fn position(&self) -> Option<Position> {
+    match self {
+      Error::Located { position, .. } | Error::XMLParsing { position, .. } => Some(*position),
+      _ => None,
+    }
+  }
+
   pub(crate) fn missing_required_tag(tag_name: &str) -> Self {
     Error::MissingRequiredTag {
       tag_name: tag_name.to_string(),
     }
   }
 
-  pub(crate) fn wrong_tag_name(element: &xmltree::Element, expected: &str) -> Self {
+  pub(crate) fn wrong_tag_name(element: &crate::parser::Tag, expected: &str) -> Self {
     Error::WrongTagName {
       wrong_name: element.name.to_string(),
       expected_name: expected.to_string(),
     }
   }
 
-  pub(crate) fn unexpected_tag(element: &xmltree::Element, wrong: &str) -> Self {
+  pub(crate) fn unexpected_tag(element: &crate::parser::Tag, wrong: &str) -> Self {
     Error::UnexpectedTag {
       wrong_name: wrong.to_string(),
       tag: element.name.to_string(),
     }
   }
 }
+
+/// 入力中の位置（1始まりの行と列）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Position {
+  pub line: u64,
+  pub column: u64,
+}
+
+impl From<xml::common::TextPosition> for Position {
+  fn from(pos: xml::common::TextPosition) -> Self {
+    Position {
+      line: pos.row + 1,
+      column: pos.column + 1,
+    }
+  }
+}
+
+impl std::fmt::Display for Position {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "line {}, column {}", self.line, self.column)
+  }
+}
+
+/// 要素のパス
+///
+/// `Law/LawBody/MainProvision/Part[2]/Chapter[1]/Article[Num=13]/Paragraph[2]`のように表示される
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ElementPath {
+  pub segments: Vec<PathSegment>,
+}
+
+impl std::fmt::Display for ElementPath {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for (i, segment) in self.segments.iter().enumerate() {
+      if i == 0 {
+        write!(f, "{}", segment.name)?;
+      } else {
+        write!(f, "/{segment}")?;
+      }
+    }
+    Ok(())
+  }
+}
+
+/// パスを構成する要素
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathSegment {
+  /// タグ名
+  pub name: String,
+  /// 同じ名前の兄弟要素の中で何番目か（1始まり）
+  pub index: usize,
+  /// Num属性の値
+  pub num: Option<String>,
+}
+
+impl std::fmt::Display for PathSegment {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match &self.num {
+      Some(num) if self.name == "Article" => write!(f, "{}[Num={}]", self.name, num),
+      Some(_) => write!(f, "{}[{}]", self.name, self.index),
+      None if self.index > 1 => write!(f, "{}[{}]", self.name, self.index),
+      None => write!(f, "{}", self.name),
+    }
+  }
+}
+
+/// 寛容モードでの解析中に見つかった問題
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+  /// 問題のあった要素のパス
+  pub path: ElementPath,
+  /// 問題のあった要素の入力中の位置
+  pub position: Position,
+  /// 深刻度
+  pub severity: Severity,
+  /// 問題の内容
+  pub error: Error,
+}
+
+impl std::fmt::Display for Diagnostic {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{:?}: {} (in {}, {})",
+      self.severity, self.error, self.path, self.position
+    )
+  }
+}
+
+/// 診断情報の深刻度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+  /// 要素を読み飛ばした（通常の解析ではエラーになる）
+  Error,
+  /// 要素を読み飛ばした（通常の解析でも黙って読み飛ばされる）
+  Warning,
+}
@@ -0,0 +1,1178 @@
+//! 法令標準XMLスキーマによる検査
+//!
+//! [XMLSchemaForJapaneseLaw_v3.xsd](https://elaws.e-gov.go.jp/file/XMLSchemaForJapaneseLaw_v3.xsd)
+//! で定められた要素の出現順序・出現回数・属性の有無と値を検査する。
+//! スキーマの定義はXSDファイルを読み込まずにこのモジュールへ書き写してあるため、検査時にネットワークへは接続しない。
+
+use crate::result::{ElementPath, PathSegment};
+use std::collections::{BTreeSet, HashMap};
+use xmltree::{Element, XMLNode};
+
+/// スキーマに違反している箇所
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaViolation {
+  /// 違反のあった要素のパス
+  pub path: ElementPath,
+  /// 違反の内容
+  pub kind: ViolationKind,
+}
+
+impl std::fmt::Display for SchemaViolation {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} (in {})", self.kind, self.path)
+  }
+}
+
+/// 違反の内容
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViolationKind {
+  /// スキーマで定義されていない要素
+  UnknownElement,
+  /// 出現順序または出現回数の誤りによって現れてはいけない子要素
+  UnexpectedElement { name: String },
+  /// 必須の子要素が足りない
+  IncompleteContent,
+  /// 文字列を持てない要素に文字列がある
+  UnexpectedText,
+  /// 必須の属性がない
+  MissingAttribute { name: String },
+  /// 定義されていない属性
+  UnknownAttribute { name: String },
+  /// 属性の値が定義に合わない
+  InvalidAttributeValue { name: String, value: String },
+}
+
+impl std::fmt::Display for ViolationKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ViolationKind::UnknownElement => write!(f, "unknown element"),
+      ViolationKind::UnexpectedElement { name } => write!(f, "unexpected {name} element"),
+      ViolationKind::IncompleteContent => write!(f, "missing required element"),
+      ViolationKind::UnexpectedText => write!(f, "unexpected text"),
+      ViolationKind::MissingAttribute { name } => write!(f, "missing required attribute {name}"),
+      ViolationKind::UnknownAttribute { name } => write!(f, "unknown attribute {name}"),
+      ViolationKind::InvalidAttributeValue { name, value } => {
+        write!(f, "invalid value {value:?} of attribute {name}")
+      }
+    }
+  }
+}
+
+thread_local! {
+  /// 検査のたびに作り直さないよう、スレッドごとに一度だけ作るスキーマの定義
+  static SCHEMA: Schema = Schema::new();
+}
+
+/// 要素をスキーマに照らして検査する
+pub(crate) fn validate_element(element: &Element) -> Vec<SchemaViolation> {
+  let mut violations = Vec::new();
+  let mut path = vec![PathSegment {
+    name: element.name.clone(),
+    index: 1,
+    num: element.attributes.get("Num").cloned(),
+  }];
+  SCHEMA.with(|schema| schema.validate(element, &mut path, &mut violations));
+  violations
+}
+
+struct Schema {
+  declarations: HashMap<&'static str, Declaration>,
+}
+
+impl Schema {
+  fn validate(
+    &self,
+    element: &Element,
+    path: &mut Vec<PathSegment>,
+    violations: &mut Vec<SchemaViolation>,
+  ) {
+    let mut report = |kind| {
+      violations.push(SchemaViolation {
+        path: ElementPath {
+          segments: path.clone(),
+        },
+        kind,
+      })
+    };
+    let children = element
+      .children
+      .iter()
+      .filter_map(|node| {
+        if let XMLNode::Element(e) = node {
+          Some(e)
+        } else {
+          None
+        }
+      })
+      .collect::<Vec<_>>();
+    let has_text = element.children.iter().any(|node| match node {
+      XMLNode::Text(s) | XMLNode::CData(s) => !s.trim().is_empty(),
+      _ => false,
+    });
+    let lax = match self.declarations.get(element.name.as_str()) {
+      None => {
+        report(ViolationKind::UnknownElement);
+        return;
+      }
+      Some(declaration) => {
+        for attribute in declaration.attributes.iter() {
+          match element.attributes.get(attribute.name) {
+            Some(value) if !attribute.kind.accepts(value) => {
+              report(ViolationKind::InvalidAttributeValue {
+                name: attribute.name.to_string(),
+                value: value.clone(),
+              })
+            }
+            Some(_) => (),
+            None if attribute.required => report(ViolationKind::MissingAttribute {
+              name: attribute.name.to_string(),
+            }),
+            None => (),
+          }
+        }
+        let mut names = element.attributes.keys().collect::<Vec<_>>();
+        names.sort();
+        for name in names {
+          if !declaration.attributes.iter().any(|a| a.name == name) {
+            report(ViolationKind::UnknownAttribute { name: name.clone() })
+          }
+        }
+        let names = children.iter().map(|e| e.name.as_str()).collect::<Vec<_>>();
+        match &declaration.content {
+          Content::Empty => {
+            if let Some(name) = names.first() {
+              report(ViolationKind::UnexpectedElement {
+                name: name.to_string(),
+              })
+            }
+            if has_text {
+              report(ViolationKind::UnexpectedText)
+            }
+          }
+          Content::Elements(particle) | Content::Mixed(particle) => {
+            if let Content::Elements(_) = declaration.content {
+              if has_text {
+                report(ViolationKind::UnexpectedText)
+              }
+            }
+            if let Err(kind) = particle.check(&names) {
+              report(kind)
+            }
+          }
+          Content::Any => (),
+        }
+        matches!(declaration.content, Content::Any)
+      }
+    };
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for child in children {
+      let index = counts.entry(child.name.as_str()).or_insert(0);
+      *index += 1;
+      if lax && !self.declarations.contains_key(child.name.as_str()) {
+        // 任意の要素を持てる箇所では、定義のある要素だけを検査する
+        continue;
+      }
+      path.push(PathSegment {
+        name: child.name.clone(),
+        index: *index,
+        num: child.attributes.get("Num").cloned(),
+      });
+      self.validate(child, path, violations);
+      path.pop();
+    }
+  }
+}
+
+/// 要素の宣言
+struct Declaration {
+  content: Content,
+  attributes: Vec<Attribute>,
+}
+
+/// 要素の中身
+enum Content {
+  /// 空要素
+  Empty,
+  /// 子要素のみ
+  Elements(Particle),
+  /// 文字列と子要素
+  Mixed(Particle),
+  /// 任意の文字列と要素
+  Any,
+}
+
+/// 属性の宣言
+struct Attribute {
+  name: &'static str,
+  kind: AttributeKind,
+  required: bool,
+}
+
+/// 属性の値の型
+enum AttributeKind {
+  String,
+  Boolean,
+  PositiveInteger,
+  Enumeration(&'static [&'static str]),
+}
+
+impl AttributeKind {
+  fn accepts(&self, value: &str) -> bool {
+    match self {
+      AttributeKind::String => true,
+      AttributeKind::Boolean => matches!(value, "true" | "false" | "1" | "0"),
+      AttributeKind::PositiveInteger => {
+        let s = value.strip_prefix('+').unwrap_or(value);
+        !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()) && s.chars().any(|c| c != '0')
+      }
+      AttributeKind::Enumeration(values) => values.contains(&value),
+    }
+  }
+}
+
+/// 内容モデルの構成要素
+#[derive(Debug, Clone)]
+struct Particle {
+  term: Term,
+  min: usize,
+  max: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+enum Term {
+  Element(&'static str),
+  Sequence(Vec<Particle>),
+  Choice(Vec<Particle>),
+}
+
+impl Particle {
+  /// 子要素の名前の列が内容モデルに合うかを調べる
+  fn check(&self, names: &[&str]) -> Result<(), ViolationKind> {
+    let mut furthest = 0;
+    let starts = BTreeSet::from([0]);
+    let ends = self.ends(names, &starts, &mut furthest);
+    if ends.contains(&names.len()) {
+      Ok(())
+    } else if furthest < names.len() {
+      Err(ViolationKind::UnexpectedElement {
+        name: names[furthest].to_string(),
+      })
+    } else {
+      Err(ViolationKind::IncompleteContent)
+    }
+  }
+
+  /// `starts`の各位置から読み始めたときに読み終わることのできる位置の集合
+  fn ends(
+    &self,
+    names: &[&str],
+    starts: &BTreeSet<usize>,
+    furthest: &mut usize,
+  ) -> BTreeSet<usize> {
+    let mut result = BTreeSet::new();
+    if self.min == 0 {
+      result.extend(starts.iter().copied());
+    }
+    let mut current = starts.clone();
+    let mut count = 0;
+    while !current.is_empty() && self.max.map_or(true, |max| count < max) {
+      current = self.term.ends(names, &current, furthest);
+      count += 1;
+      if count >= self.min {
+        let len = result.len();
+        result.extend(current.iter().copied());
+        if result.len() == len {
+          break;
+        }
+      }
+      if count > names.len() + self.min {
+        break;
+      }
+    }
+    result
+  }
+}
+
+impl Term {
+  fn ends(
+    &self,
+    names: &[&str],
+    starts: &BTreeSet<usize>,
+    furthest: &mut usize,
+  ) -> BTreeSet<usize> {
+    match self {
+      Term::Element(name) => {
+        let mut result = BTreeSet::new();
+        for &p in starts.iter() {
+          if names.get(p) == Some(name) {
+            result.insert(p + 1);
+            *furthest = (*furthest).max(p + 1);
+          }
+        }
+        result
+      }
+      Term::Sequence(particles) => {
+        let mut current = starts.clone();
+        for particle in particles.iter() {
+          current = particle.ends(names, &current, furthest);
+          if current.is_empty() {
+            break;
+          }
+        }
+        current
+      }
+      Term::Choice(particles) => {
+        let mut result = BTreeSet::new();
+        for particle in particles.iter() {
+          result.extend(particle.ends(names, starts, furthest));
+        }
+        result
+      }
+    }
+  }
+}
+
+fn e(name: &'static str) -> Particle {
+  Particle {
+    term: Term::Element(name),
+    min: 1,
+    max: Some(1),
+  }
+}
+
+fn opt(p: Particle) -> Particle {
+  Particle {
+    min: 0,
+    max: Some(1),
+    ..p
+  }
+}
+
+fn many(p: Particle) -> Particle {
+  Particle {
+    min: 0,
+    max: None,
+    ..p
+  }
+}
+
+fn some(p: Particle) -> Particle {
+  Particle {
+    min: 1,
+    max: None,
+    ..p
+  }
+}
+
+fn seq(particles: Vec<Particle>) -> Particle {
+  Particle {
+    term: Term::Sequence(particles),
+    min: 1,
+    max: Some(1),
+  }
+}
+
+fn choice(particles: Vec<Particle>) -> Particle {
+  Particle {
+    term: Term::Choice(particles),
+    min: 1,
+    max: Some(1),
+  }
+}
+
+fn att(name: &'static str, kind: AttributeKind) -> Attribute {
+  Attribute {
+    name,
+    kind,
+    required: false,
+  }
+}
+
+fn req(name: &'static str, kind: AttributeKind) -> Attribute {
+  Attribute {
+    name,
+    kind,
+    required: true,
+  }
+}
+
+const ERA: &[&str] = &["Meiji", "Taisho", "Showa", "Heisei", "Reiwa"];
+const LAW_TYPE: &[&str] = &[
+  "Constitution",
+  "Act",
+  "CabinetOrder",
+  "ImperialOrder",
+  "MinisterialOrdinance",
+  "Rule",
+  "Misc",
+];
+const LANG: &[&str] = &["ja", "en"];
+const WRITING_MODE: &[&str] = &["vertical", "horizontal"];
+const LINE_STYLE: &[&str] = &["dotted", "double", "none", "solid"];
+const ALIGN: &[&str] = &["left", "center", "right", "justify"];
+const VALIGN: &[&str] = &["top", "middle", "bottom"];
+const FUNCTION: &[&str] = &["main", "proviso"];
+const INDENT: &[&str] = &[
+  "Paragraph",
+  "Item",
+  "Subitem1",
+  "Subitem2",
+  "Subitem3",
+  "Subitem4",
+  "Subitem5",
+  "Subitem6",
+  "Subitem7",
+  "Subitem8",
+  "Subitem9",
+  "Subitem10",
+];
+const SUPPL_PROVISION_TYPE: &[&str] = &["New", "Amend"];
+const SUBITEMS: [&str; 10] = [
+  "Subitem1",
+  "Subitem2",
+  "Subitem3",
+  "Subitem4",
+  "Subitem5",
+  "Subitem6",
+  "Subitem7",
+  "Subitem8",
+  "Subitem9",
+  "Subitem10",
+];
+
+/// Ruby・Line・Sup・Subを含む文字列
+fn text() -> Content {
+  Content::Mixed(many(choice(vec![e("Line"), e("Ruby"), e("Sup"), e("Sub")])))
+}
+
+/// 子要素を持たない文字列
+fn string() -> Content {
+  Content::Mixed(seq(Vec::new()))
+}
+
+/// 段落・号などの文
+fn sentence_or_column_or_table() -> Content {
+  Content::Elements(choice(vec![
+    some(e("Sentence")),
+    some(e("Column")),
+    e("Table"),
+  ]))
+}
+
+fn sentence_or_column() -> Content {
+  Content::Elements(choice(vec![some(e("Sentence")), some(e("Column"))]))
+}
+
+fn structs() -> Particle {
+  many(choice(vec![
+    e("TableStruct"),
+    e("FigStruct"),
+    e("StyleStruct"),
+    e("List"),
+  ]))
+}
+
+fn num_delete_hide() -> Vec<Attribute> {
+  vec![
+    req("Num", AttributeKind::String),
+    att("Delete", AttributeKind::Boolean),
+    att("Hide", AttributeKind::Boolean),
+  ]
+}
+
+fn num_delete() -> Vec<Attribute> {
+  vec![
+    req("Num", AttributeKind::String),
+    att("Delete", AttributeKind::Boolean),
+  ]
+}
+
+fn writing_mode() -> Vec<Attribute> {
+  vec![att("WritingMode", AttributeKind::Enumeration(WRITING_MODE))]
+}
+
+fn common_caption() -> Vec<Attribute> {
+  vec![att("CommonCaption", AttributeKind::Boolean)]
+}
+
+fn num_opt() -> Vec<Attribute> {
+  vec![att("Num", AttributeKind::String)]
+}
+
+impl Schema {
+  fn new() -> Self {
+    let mut declarations = HashMap::new();
+    let mut add = |name: &'static str, content: Content, attributes: Vec<Attribute>| {
+      declarations.insert(
+        name,
+        Declaration {
+          content,
+          attributes,
+        },
+      );
+    };
+
+    add(
+      "Law",
+      Content::Elements(seq(vec![e("LawNum"), e("LawBody")])),
+      vec![
+        req("Era", AttributeKind::Enumeration(ERA)),
+        req("Year", AttributeKind::PositiveInteger),
+        req("Num", AttributeKind::PositiveInteger),
+        att("PromulgateMonth", AttributeKind::PositiveInteger),
+        att("PromulgateDay", AttributeKind::PositiveInteger),
+        req("LawType", AttributeKind::Enumeration(LAW_TYPE)),
+        req("Lang", AttributeKind::Enumeration(LANG)),
+      ],
+    );
+    add("LawNum", string(), Vec::new());
+    add(
+      "LawBody",
+      Content::Elements(seq(vec![
+        opt(e("LawTitle")),
+        many(e("EnactStatement")),
+        opt(e("TOC")),
+        opt(e("Preamble")),
+        e("MainProvision"),
+        many(e("SupplProvision")),
+        many(e("AppdxTable")),
+        many(e("AppdxNote")),
+        many(e("AppdxStyle")),
+        many(e("Appdx")),
+        many(e("AppdxFig")),
+        many(e("AppdxFormat")),
+      ])),
+      vec![att("Subject", AttributeKind::String)],
+    );
+    add(
+      "LawTitle",
+      text(),
+      vec![
+        att("Kana", AttributeKind::String),
+        att("Abbrev", AttributeKind::String),
+        att("AbbrevKana", AttributeKind::String),
+      ],
+    );
+    add("EnactStatement", text(), Vec::new());
+
+    // 目次
+    add(
+      "TOC",
+      Content::Elements(seq(vec![
+        opt(e("TOCLabel")),
+        opt(e("TOCPreambleLabel")),
+        opt(choice(vec![
+          some(e("TOCPart")),
+          some(e("TOCChapter")),
+          some(e("TOCSection")),
+          some(e("TOCArticle")),
+        ])),
+        opt(e("TOCSupplProvision")),
+        many(e("TOCAppdxTableLabel")),
+      ])),
+      Vec::new(),
+    );
+    add("TOCLabel", text(), Vec::new());
+    add("TOCPreambleLabel", text(), Vec::new());
+    add(
+      "TOCPart",
+      Content::Elements(seq(vec![
+        e("PartTitle"),
+        opt(e("ArticleRange")),
+        many(e("TOCChapter")),
+      ])),
+      num_delete(),
+    );
+    add(
+      "TOCChapter",
+      Content::Elements(seq(vec![
+        e("ChapterTitle"),
+        opt(e("ArticleRange")),
+        many(e("TOCSection")),
+      ])),
+      num_delete(),
+    );
+    add(
+      "TOCSection",
+      Content::Elements(seq(vec![
+        e("SectionTitle"),
+        opt(e("ArticleRange")),
+        many(choice(vec![e("TOCSubsection"), e("TOCDivision")])),
+      ])),
+      num_delete(),
+    );
+    add(
+      "TOCSubsection",
+      Content::Elements(seq(vec![
+        e("SubsectionTitle"),
+        opt(e("ArticleRange")),
+        many(e("TOCDivision")),
+      ])),
+      num_delete(),
+    );
+    add(
+      "TOCDivision",
+      Content::Elements(seq(vec![e("DivisionTitle"), opt(e("ArticleRange"))])),
+      num_delete(),
+    );
+    add(
+      "TOCArticle",
+      Content::Elements(seq(vec![e("ArticleTitle"), e("ArticleCaption")])),
+      num_delete(),
+    );
+    add(
+      "TOCSupplProvision",
+      Content::Elements(seq(vec![
+        e("SupplProvisionLabel"),
+        opt(e("ArticleRange")),
+        opt(choice(vec![some(e("TOCArticle")), some(e("TOCChapter"))])),
+      ])),
+      Vec::new(),
+    );
+    add("TOCAppdxTableLabel", text(), Vec::new());
+    add("ArticleRange", text(), Vec::new());
+
+    // 本文
+    add(
+      "Preamble",
+      Content::Elements(some(e("Paragraph"))),
+      Vec::new(),
+    );
+    add(
+      "MainProvision",
+      Content::Elements(choice(vec![
+        some(e("Part")),
+        some(e("Chapter")),
+        some(e("Section")),
+        some(e("Article")),
+        some(e("Paragraph")),
+      ])),
+      vec![att("Extract", AttributeKind::Boolean)],
+    );
+    add(
+      "Part",
+      Content::Elements(seq(vec![
+        e("PartTitle"),
+        many(e("Article")),
+        many(e("Chapter")),
+      ])),
+      num_delete_hide(),
+    );
+    add("PartTitle", text(), Vec::new());
+    add(
+      "Chapter",
+      Content::Elements(seq(vec![
+        e("ChapterTitle"),
+        many(e("Article")),
+        many(e("Section")),
+      ])),
+      num_delete_hide(),
+    );
+    add("ChapterTitle", text(), Vec::new());
+    add(
+      "Section",
+      Content::Elements(seq(vec![
+        e("SectionTitle"),
+        many(e("Article")),
+        opt(choice(vec![some(e("Subsection")), some(e("Division"))])),
+      ])),
+      num_delete_hide(),
+    );
+    add("SectionTitle", text(), Vec::new());
+    add(
+      "Subsection",
+      Content::Elements(seq(vec![
+        e("SubsectionTitle"),
+        many(e("Article")),
+        many(e("Division")),
+      ])),
+      num_delete_hide(),
+    );
+    add("SubsectionTitle", text(), Vec::new());
+    add(
+      "Division",
+      Content::Elements(seq(vec![e("DivisionTitle"), some(e("Article"))])),
+      num_delete_hide(),
+    );
+    add("DivisionTitle", text(), Vec::new());
+    add(
+      "Article",
+      Content::Elements(seq(vec![
+        opt(e("ArticleCaption")),
+        e("ArticleTitle"),
+        some(e("Paragraph")),
+        opt(e("SupplNote")),
+      ])),
+      num_delete_hide(),
+    );
+    add("ArticleTitle", text(), Vec::new());
+    add("ArticleCaption", text(), common_caption());
+    add(
+      "Paragraph",
+      Content::Elements(seq(vec![
+        opt(e("ParagraphCaption")),
+        e("ParagraphNum"),
+        e("ParagraphSentence"),
+        many(e("AmendProvision")),
+        many(e("Class")),
+        structs(),
+        many(e("Item")),
+      ])),
+      vec![
+        req("Num", AttributeKind::PositiveInteger),
+        att("OldStyle", AttributeKind::Boolean),
+        att("OldNum", AttributeKind::Boolean),
+        att("Hide", AttributeKind::Boolean),
+      ],
+    );
+    add("ParagraphCaption", text(), common_caption());
+    add("ParagraphNum", text(), Vec::new());
+    add(
+      "ParagraphSentence",
+      Content::Elements(some(e("Sentence"))),
+      Vec::new(),
+    );
+    add("SupplNote", text(), Vec::new());
+    add(
+      "AmendProvision",
+      Content::Elements(seq(vec![
+        opt(e("AmendProvisionSentence")),
+        many(e("NewProvision")),
+      ])),
+      Vec::new(),
+    );
+    add(
+      "AmendProvisionSentence",
+      Content::Elements(some(e("Sentence"))),
+      Vec::new(),
+    );
+    let mut new_provision = vec![
+      e("LawTitle"),
+      e("Preamble"),
+      e("TOC"),
+      e("Part"),
+      e("PartTitle"),
+      e("Chapter"),
+      e("ChapterTitle"),
+      e("Section"),
+      e("SectionTitle"),
+      e("Subsection"),
+      e("SubsectionTitle"),
+      e("Division"),
+      e("DivisionTitle"),
+      e("Article"),
+      e("SupplNote"),
+      e("Paragraph"),
+      e("Item"),
+    ];
+    new_provision.extend(SUBITEMS.iter().map(|name| e(name)));
+    new_provision.extend(vec![
+      e("List"),
+      e("Sentence"),
+      e("AmendProvision"),
+      e("AppdxTable"),
+      e("AppdxNote"),
+      e("AppdxStyle"),
+      e("Appdx"),
+      e("AppdxFig"),
+      e("AppdxFormat"),
+      e("SupplProvisionAppdxStyle"),
+      e("SupplProvisionAppdxTable"),
+      e("SupplProvisionAppdx"),
+      e("TableStruct"),
+      e("TableRow"),
+      e("TableColumn"),
+      e("FigStruct"),
+      e("NoteStruct"),
+      e("StyleStruct"),
+      e("FormatStruct"),
+      e("Remarks"),
+      e("LawBody"),
+    ]);
+    add(
+      "NewProvision",
+      Content::Elements(many(choice(new_provision))),
+      Vec::new(),
+    );
+    add(
+      "Class",
+      Content::Elements(seq(vec![
+        opt(e("ClassTitle")),
+        e("ClassSentence"),
+        many(e("Item")),
+      ])),
+      vec![req("Num", AttributeKind::String)],
+    );
+    add("ClassTitle", text(), Vec::new());
+    add("ClassSentence", sentence_or_column_or_table(), Vec::new());
+
+    // 号・細分
+    add(
+      "Item",
+      Content::Elements(seq(vec![
+        opt(e("ItemTitle")),
+        e("ItemSentence"),
+        many(e("Subitem1")),
+        structs(),
+      ])),
+      num_delete_hide(),
+    );
+    add("ItemTitle", text(), Vec::new());
+    add("ItemSentence", sentence_or_column_or_table(), Vec::new());
+    const SUBITEM_TITLES: [&str; 10] = [
+      "Subitem1Title",
+      "Subitem2Title",
+      "Subitem3Title",
+      "Subitem4Title",
+      "Subitem5Title",
+      "Subitem6Title",
+      "Subitem7Title",
+      "Subitem8Title",
+      "Subitem9Title",
+      "Subitem10Title",
+    ];
+    const SUBITEM_SENTENCES: [&str; 10] = [
+      "Subitem1Sentence",
+      "Subitem2Sentence",
+      "Subitem3Sentence",
+      "Subitem4Sentence",
+      "Subitem5Sentence",
+      "Subitem6Sentence",
+      "Subitem7Sentence",
+      "Subitem8Sentence",
+      "Subitem9Sentence",
+      "Subitem10Sentence",
+    ];
+    for i in 0..10 {
+      let mut particles = vec![opt(e(SUBITEM_TITLES[i])), e(SUBITEM_SENTENCES[i])];
+      if i < 9 {
+        particles.push(many(e(SUBITEMS[i + 1])));
+      }
+      particles.push(structs());
+      add(
+        SUBITEMS[i],
+        Content::Elements(seq(particles)),
+        num_delete_hide(),
+      );
+      add(SUBITEM_TITLES[i], text(), Vec::new());
+      add(
+        SUBITEM_SENTENCES[i],
+        sentence_or_column_or_table(),
+        Vec::new(),
+      );
+    }
+
+    // 文
+    add(
+      "Sentence",
+      Content::Mixed(many(choice(vec![
+        e("Line"),
+        e("QuoteStruct"),
+        e("ArithFormula"),
+        e("Ruby"),
+        e("Sup"),
+        e("Sub"),
+      ]))),
+      vec![
+        att("Num", AttributeKind::PositiveInteger),
+        att("Function", AttributeKind::Enumeration(FUNCTION)),
+        att("Indent", AttributeKind::Enumeration(INDENT)),
+        att("WritingMode", AttributeKind::Enumeration(WRITING_MODE)),
+      ],
+    );
+    add(
+      "Column",
+      Content::Elements(some(e("Sentence"))),
+      vec![
+        att("Num", AttributeKind::PositiveInteger),
+        att("LineBreak", AttributeKind::Boolean),
+        att("Align", AttributeKind::Enumeration(ALIGN)),
+      ],
+    );
+
+    // 附則
+    add(
+      "SupplProvision",
+      Content::Elements(seq(vec![
+        e("SupplProvisionLabel"),
+        opt(choice(vec![
+          some(e("Chapter")),
+          some(e("Article")),
+          some(e("Paragraph")),
+        ])),
+        many(e("SupplProvisionAppdxTable")),
+        many(e("SupplProvisionAppdxStyle")),
+        many(e("SupplProvisionAppdx")),
+      ])),
+      vec![
+        att("Type", AttributeKind::Enumeration(SUPPL_PROVISION_TYPE)),
+        att("AmendLawNum", AttributeKind::String),
+        att("Extract", AttributeKind::Boolean),
+      ],
+    );
+    add("SupplProvisionLabel", text(), Vec::new());
+    add(
+      "SupplProvisionAppdxTable",
+      Content::Elements(seq(vec![
+        e("SupplProvisionAppdxTableTitle"),
+        opt(e("RelatedArticleNum")),
+        many(e("TableStruct")),
+      ])),
+      num_opt(),
+    );
+    add("SupplProvisionAppdxTableTitle", text(), writing_mode());
+    add(
+      "SupplProvisionAppdxStyle",
+      Content::Elements(seq(vec![
+        e("SupplProvisionAppdxStyleTitle"),
+        opt(e("RelatedArticleNum")),
+        many(e("StyleStruct")),
+      ])),
+      num_opt(),
+    );
+    add("SupplProvisionAppdxStyleTitle", text(), writing_mode());
+    add(
+      "SupplProvisionAppdx",
+      Content::Elements(seq(vec![
+        opt(e("ArithFormulaNum")),
+        opt(e("RelatedArticleNum")),
+        many(e("ArithFormula")),
+      ])),
+      num_opt(),
+    );
+
+    // 付録
+    add(
+      "AppdxTable",
+      Content::Elements(seq(vec![
+        opt(e("AppdxTableTitle")),
+        opt(e("RelatedArticleNum")),
+        many(choice(vec![e("TableStruct"), e("Item")])),
+        opt(e("Remarks")),
+      ])),
+      num_opt(),
+    );
+    add("AppdxTableTitle", text(), writing_mode());
+    add(
+      "AppdxNote",
+      Content::Elements(seq(vec![
+        opt(e("AppdxNoteTitle")),
+        opt(e("RelatedArticleNum")),
+        many(choice(vec![
+          e("NoteStruct"),
+          e("FigStruct"),
+          e("TableStruct"),
+        ])),
+        opt(e("Remarks")),
+      ])),
+      num_opt(),
+    );
+    add("AppdxNoteTitle", text(), writing_mode());
+    add(
+      "AppdxStyle",
+      Content::Elements(seq(vec![
+        opt(e("AppdxStyleTitle")),
+        opt(e("RelatedArticleNum")),
+        many(e("StyleStruct")),
+        opt(e("Remarks")),
+      ])),
+      num_opt(),
+    );
+    add("AppdxStyleTitle", text(), writing_mode());
+    add(
+      "AppdxFormat",
+      Content::Elements(seq(vec![
+        opt(e("AppdxFormatTitle")),
+        opt(e("RelatedArticleNum")),
+        many(e("FormatStruct")),
+        opt(e("Remarks")),
+      ])),
+      num_opt(),
+    );
+    add("AppdxFormatTitle", text(), writing_mode());
+    add(
+      "Appdx",
+      Content::Elements(seq(vec![
+        opt(e("ArithFormulaNum")),
+        opt(e("RelatedArticleNum")),
+        many(e("ArithFormula")),
+        opt(e("Remarks")),
+      ])),
+      Vec::new(),
+    );
+    add("ArithFormulaNum", text(), Vec::new());
+    add(
+      "AppdxFig",
+      Content::Elements(seq(vec![
+        opt(e("AppdxFigTitle")),
+        opt(e("RelatedArticleNum")),
+        many(choice(vec![e("FigStruct"), e("TableStruct")])),
+      ])),
+      num_opt(),
+    );
+    add("AppdxFigTitle", text(), writing_mode());
+    add("RelatedArticleNum", text(), Vec::new());
+
+    // 表
+    add(
+      "TableStruct",
+      Content::Elements(seq(vec![
+        opt(e("TableStructTitle")),
+        many(e("Remarks")),
+        e("Table"),
+        many(e("Remarks")),
+      ])),
+      Vec::new(),
+    );
+    add("TableStructTitle", text(), writing_mode());
+    add(
+      "Table",
+      Content::Elements(seq(vec![many(e("TableHeaderRow")), some(e("TableRow"))])),
+      writing_mode(),
+    );
+    add(
+      "TableHeaderRow",
+      Content::Elements(some(e("TableHeaderColumn"))),
+      Vec::new(),
+    );
+    add("TableHeaderColumn", text(), Vec::new());
+    add(
+      "TableRow",
+      Content::Elements(some(e("TableColumn"))),
+      Vec::new(),
+    );
+    let mut table_column = vec![
+      e("Part"),
+      e("Chapter"),
+      e("Section"),
+      e("Subsection"),
+      e("Division"),
+      e("Article"),
+      e("Paragraph"),
+      e("Item"),
+    ];
+    table_column.extend(SUBITEMS.iter().map(|name| e(name)));
+    table_column.extend(vec![
+      e("FigStruct"),
+      e("Remarks"),
+      e("Sentence"),
+      e("Column"),
+    ]);
+    add(
+      "TableColumn",
+      Content::Elements(some(choice(table_column))),
+      vec![
+        att("BorderTop", AttributeKind::Enumeration(LINE_STYLE)),
+        att("BorderBottom", AttributeKind::Enumeration(LINE_STYLE)),
+        att("BorderLeft", AttributeKind::Enumeration(LINE_STYLE)),
+        att("BorderRight", AttributeKind::Enumeration(LINE_STYLE)),
+        att("rowspan", AttributeKind::String),
+        att("colspan", AttributeKind::String),
+        att("Align", AttributeKind::Enumeration(ALIGN)),
+        att("Valign", AttributeKind::Enumeration(VALIGN)),
+      ],
+    );
+
+    // 図・記式・様式・書式
+    add(
+      "FigStruct",
+      Content::Elements(seq(vec![
+        opt(e("FigStructTitle")),
+        many(e("Remarks")),
+        e("Fig"),
+        many(e("Remarks")),
+      ])),
+      Vec::new(),
+    );
+    add("FigStructTitle", text(), Vec::new());
+    add(
+      "Fig",
+      Content::Empty,
+      vec![req("src", AttributeKind::String)],
+    );
+    for (name, title, body) in [
+      ("NoteStruct", "NoteStructTitle", "Note"),
+      ("StyleStruct", "StyleStructTitle", "Style"),
+      ("FormatStruct", "FormatStructTitle", "Format"),
+    ] {
+      add(
+        name,
+        Content::Elements(seq(vec![
+          opt(e(title)),
+          many(e("Remarks")),
+          e(body),
+          many(e("Remarks")),
+        ])),
+        Vec::new(),
+      );
+      add(title, text(), Vec::new());
+      add(body, Content::Any, Vec::new());
+    }
+
+    // 備考
+    add(
+      "Remarks",
+      Content::Elements(seq(vec![
+        e("RemarksLabel"),
+        opt(choice(vec![some(e("Item")), some(e("Sentence"))])),
+      ])),
+      Vec::new(),
+    );
+    add(
+      "RemarksLabel",
+      text(),
+      vec![att("LineBreak", AttributeKind::Boolean)],
+    );
+
+    // 列記
+    add(
+      "List",
+      Content::Elements(seq(vec![e("ListSentence"), many(e("Sublist1"))])),
+      Vec::new(),
+    );
+    add("ListSentence", sentence_or_column(), Vec::new());
+    add(
+      "Sublist1",
+      Content::Elements(seq(vec![e("Sublist1Sentence"), many(e("Sublist2"))])),
+      Vec::new(),
+    );
+    add("Sublist1Sentence", sentence_or_column(), Vec::new());
+    add(
+      "Sublist2",
+      Content::Elements(seq(vec![e("Sublist2Sentence"), many(e("Sublist3"))])),
+      Vec::new(),
+    );
+    add("Sublist2Sentence", sentence_or_column(), Vec::new());
+    add(
+      "Sublist3",
+      Content::Elements(e("Sublist3Sentence")),
+      Vec::new(),
+    );
+    add("Sublist3Sentence", sentence_or_column(), Vec::new());
+
+    // 文中の要素
+    add("QuoteStruct", Content::Any, Vec::new());
+    add(
+      "ArithFormula",
+      Content::Any,
+      vec![att("Num", AttributeKind::PositiveInteger)],
+    );
+    add(
+      "Line",
+      Content::Mixed(many(choice(vec![
+        e("QuoteStruct"),
+        e("ArithFormula"),
+        e("Ruby"),
+        e("Sup"),
+        e("Sub"),
+      ]))),
+      vec![att("Style", AttributeKind::Enumeration(LINE_STYLE))],
+    );
+    add("Ruby", Content::Mixed(many(e("Rt"))), Vec::new());
+    add("Rt", string(), Vec::new());
+    add("Sup", string(), Vec::new());
+    add("Sub", string(), Vec::new());
+
+    Schema { declarations }
+  }
+}
@@ -19,7 +19,7 @@ pub struct Sentence {
 }
 
 impl Parser for Sentence {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Sentence" {
       let num = get_attribute_opt_with_parse(element, "Num")?;
       let function = match element.attributes.get("Function").map(|s| s.as_str()) {
@@ -60,39 +60,40 @@ impl Parser for Sentence {
         _ => text::WritingMode::Vertical,
       };
       let mut contents = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Line" => {
-              let v = line::Line::parser(e)?;
+              let v = line::Line::parser(reader, &e)?;
               contents.push(SentenceElement::Line(v));
             }
             "QuoteStruct" => {
-              let v = structs::QuoteStruct::parser(e)?;
+              let v = structs::QuoteStruct::parser(reader, &e)?;
               contents.push(SentenceElement::QuoteStruct(v));
             }
             "ArithFormula" => {
-              let v = contents::ArithFormula::parser(e)?;
+              let v = contents::ArithFormula::parser(reader, &e)?;
               contents.push(SentenceElement::ArithFormula(v));
             }
             "Ruby" => {
-              let v = text::Ruby::parser(e)?;
+              let v = text::Ruby::parser(reader, &e)?;
               contents.push(SentenceElement::Ruby(v));
             }
             "Sup" => {
-              let v = text::Sup::parser(e)?;
+              let v = text::Sup::parser(reader, &e)?;
               contents.push(SentenceElement::Sup(v));
             }
             "Sub" => {
-              let v = text::Sub::parser(e)?;
+              let v = text::Sub::parser(reader, &e)?;
               contents.push(SentenceElement::Sub(v));
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
-        } else if let XMLNode::Text(s) = node {
-          contents.push(SentenceElement::String(s.clone()))
+        } else if let Node::Text(s) = node {
+          contents.push(SentenceElement::String(s))
         }
-      }
+        Ok(())
+      })?;
       Ok(Sentence {
         contents,
         num,
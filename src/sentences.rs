@@ -0,0 +1,263 @@
+//! 法令中の全ての文と、その位置
+//!
+//! 本則・附則・別表などの中の文を、項や号だけでなく類・備考・表の欄・列記の中まで含めて文書中の順に列挙する。
+//!
+//! ```no_run
+//! use japanese_law_xml_schema::parse_xml_file;
+//!
+//! let law = parse_xml_file("129AC0000000089_20230614_505AC0000000053.xml").unwrap();
+//! for s in law.sentences() {
+//!   println!("{:?} {:?}", s.location.article().map(|a| &a.num), s.location.num);
+//! }
+//! ```
+
+use crate::appdx::{Appdx, AppdxFig, AppdxFormat, AppdxNote, AppdxStyle, AppdxTable};
+use crate::article::{Article, Chapter, Division, Part, Section, Subsection};
+use crate::class::{Class, Column};
+use crate::contents::ArithFormula;
+use crate::law::{AmendProvision, Law, MainProvision, Preamble};
+use crate::list::{List, Sublist1, Sublist2, Sublist3};
+use crate::paragraph::*;
+use crate::remarks::Remarks;
+use crate::resolve::Provision;
+use crate::sentence::{Sentence, SentenceFunction};
+use crate::structs::{FigStruct, FormatStruct, NoteStruct, QuoteStruct, StyleStruct, TableStruct};
+use crate::suppl_provision::{
+  SupplProvision, SupplProvisionAppdx, SupplProvisionAppdxStyle, SupplProvisionAppdxTable,
+};
+use crate::table::Table;
+use crate::visit::{self, Visit};
+
+/// 文の位置を表す階層の一段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocationSegment<'a> {
+  /// 本則・附則・編から号の細分まで・別表など
+  Provision(Provision<'a>),
+  /// 前文
+  Preamble(&'a Preamble),
+  /// 改正規定
+  AmendProvision(&'a AmendProvision),
+  /// 類
+  Class(&'a Class),
+  /// 段
+  Column(&'a Column),
+  /// 備考
+  Remarks(&'a Remarks),
+  /// 列記（`depth`は`List`が0、`Sublist1`が1）
+  List { depth: usize },
+  /// 表の欄（`row`と`column`は1から数える）
+  TableColumn { row: usize, column: usize },
+  /// 表項目
+  TableStruct(&'a TableStruct),
+  /// 記
+  NoteStruct(&'a NoteStruct),
+  /// 様式
+  StyleStruct(&'a StyleStruct),
+  /// 書式
+  FormatStruct(&'a FormatStruct),
+  /// 図
+  FigStruct(&'a FigStruct),
+  /// 附則別表
+  SupplProvisionAppdxTable(&'a SupplProvisionAppdxTable),
+  /// 附則様式
+  SupplProvisionAppdxStyle(&'a SupplProvisionAppdxStyle),
+  /// 附則付録
+  SupplProvisionAppdx(&'a SupplProvisionAppdx),
+  /// 文の中の引用や算式を含む文
+  Sentence(&'a Sentence),
+  /// 引用
+  QuoteStruct(&'a QuoteStruct),
+  /// 算式
+  ArithFormula(&'a ArithFormula),
+}
+
+/// 文の位置
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SentenceLocation<'a> {
+  /// 本則・附則・別表から順に並べた、文を含む要素
+  pub path: Vec<LocationSegment<'a>>,
+  /// 文の番号
+  pub num: Option<usize>,
+  /// 本文・ただし書の区別
+  pub function: Option<SentenceFunction>,
+}
+
+impl<'a> SentenceLocation<'a> {
+  /// 位置の中の本則・附則・条・項・号などの要素を上から並べる
+  pub fn provisions(&self) -> impl Iterator<Item = Provision<'a>> + '_ {
+    self.path.iter().filter_map(|s| match s {
+      LocationSegment::Provision(p) => Some(*p),
+      _ => None,
+    })
+  }
+
+  /// 文を含む最も内側の条
+  pub fn article(&self) -> Option<&'a Article> {
+    self.provisions().fold(None, |a, p| match p {
+      Provision::Article(article) => Some(article),
+      _ => a,
+    })
+  }
+
+  /// 附則の中の文であればその附則
+  pub fn suppl_provision(&self) -> Option<&'a SupplProvision> {
+    self.provisions().find_map(|p| match p {
+      Provision::SupplProvision(s) => Some(s),
+      _ => None,
+    })
+  }
+}
+
+/// 位置つきの文
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocatedSentence<'a> {
+  pub location: SentenceLocation<'a>,
+  pub sentence: &'a Sentence,
+}
+
+#[derive(Default)]
+struct Collector<'a> {
+  path: Vec<LocationSegment<'a>>,
+  out: Vec<LocatedSentence<'a>>,
+}
+
+impl<'a> Collector<'a> {
+  fn with(&mut self, segment: LocationSegment<'a>, f: impl FnOnce(&mut Self)) {
+    self.path.push(segment);
+    f(self);
+    self.path.pop();
+  }
+}
+
+macro_rules! provision_hooks {
+  ($($method:ident, $t:ident);* $(;)?) => {
+    $(
+      fn $method(&mut self, node: &'a $t) {
+        self.with(LocationSegment::Provision(Provision::$t(node)), |v| {
+          visit::$method(v, node)
+        });
+      }
+    )*
+  };
+}
+
+macro_rules! segment_hooks {
+  ($($method:ident, $t:ident);* $(;)?) => {
+    $(
+      fn $method(&mut self, node: &'a $t) {
+        self.with(LocationSegment::$t(node), |v| visit::$method(v, node));
+      }
+    )*
+  };
+}
+
+impl<'a> Visit<'a> for Collector<'a> {
+  provision_hooks!(
+    visit_main_provision, MainProvision;
+    visit_suppl_provision, SupplProvision;
+    visit_part, Part;
+    visit_chapter, Chapter;
+    visit_section, Section;
+    visit_subsection, Subsection;
+    visit_division, Division;
+    visit_article, Article;
+    visit_paragraph, Paragraph;
+    visit_item, Item;
+    visit_subitem1, Subitem1;
+    visit_subitem2, Subitem2;
+    visit_subitem3, Subitem3;
+    visit_subitem4, Subitem4;
+    visit_subitem5, Subitem5;
+    visit_subitem6, Subitem6;
+    visit_subitem7, Subitem7;
+    visit_subitem8, Subitem8;
+    visit_subitem9, Subitem9;
+    visit_subitem10, Subitem10;
+    visit_appdx_table, AppdxTable;
+    visit_appdx_note, AppdxNote;
+    visit_appdx_style, AppdxStyle;
+    visit_appdx_format, AppdxFormat;
+    visit_appdx, Appdx;
+    visit_appdx_fig, AppdxFig;
+  );
+
+  segment_hooks!(
+    visit_preamble, Preamble;
+    visit_amend_provision, AmendProvision;
+    visit_class, Class;
+    visit_column, Column;
+    visit_remarks, Remarks;
+    visit_table_struct, TableStruct;
+    visit_note_struct, NoteStruct;
+    visit_style_struct, StyleStruct;
+    visit_format_struct, FormatStruct;
+    visit_fig_struct, FigStruct;
+    visit_suppl_provision_appdx_table, SupplProvisionAppdxTable;
+    visit_suppl_provision_appdx_style, SupplProvisionAppdxStyle;
+    visit_suppl_provision_appdx, SupplProvisionAppdx;
+    visit_quote_struct, QuoteStruct;
+    visit_arith_formula, ArithFormula;
+  );
+
+  fn visit_list(&mut self, node: &'a List) {
+    self.with(LocationSegment::List { depth: 0 }, |v| {
+      visit::visit_list(v, node)
+    });
+  }
+
+  fn visit_sublist1(&mut self, node: &'a Sublist1) {
+    self.with(LocationSegment::List { depth: 1 }, |v| {
+      visit::visit_sublist1(v, node)
+    });
+  }
+
+  fn visit_sublist2(&mut self, node: &'a Sublist2) {
+    self.with(LocationSegment::List { depth: 2 }, |v| {
+      visit::visit_sublist2(v, node)
+    });
+  }
+
+  fn visit_sublist3(&mut self, node: &'a Sublist3) {
+    self.with(LocationSegment::List { depth: 3 }, |v| {
+      visit::visit_sublist3(v, node)
+    });
+  }
+
+  fn visit_table(&mut self, node: &'a Table) {
+    for header in node.table_header_row.iter() {
+      visit::visit_table_header_row(self, header);
+    }
+    for (i, row) in node.table_row.iter().enumerate() {
+      for (j, column) in row.columns.iter().enumerate() {
+        let segment = LocationSegment::TableColumn {
+          row: i + 1,
+          column: j + 1,
+        };
+        self.with(segment, |v| v.visit_table_column(column));
+      }
+    }
+  }
+
+  fn visit_sentence(&mut self, node: &'a Sentence) {
+    self.out.push(LocatedSentence {
+      location: SentenceLocation {
+        path: self.path.clone(),
+        num: node.num,
+        function: node.function.clone(),
+      },
+      sentence: node,
+    });
+    self.with(LocationSegment::Sentence(node), |v| {
+      visit::visit_sentence(v, node)
+    });
+  }
+}
+
+impl Law {
+  /// 法令中の全ての文を、位置とともに文書中の順に列挙する
+  pub fn sentences(&self) -> impl Iterator<Item = LocatedSentence<'_>> {
+    let mut collector = Collector::default();
+    collector.visit_law(self);
+    collector.out.into_iter()
+  }
+}
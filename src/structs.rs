@@ -21,12 +21,12 @@ pub enum Struct {
 }
 
 impl Parser for Struct {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     match element.name.as_str() {
-      "TableStruct" => TableStruct::parser(element).map(Struct::TableStruct),
-      "FigStruct" => FigStruct::parser(element).map(Struct::FigStruct),
-      "StyleStruct" => StyleStruct::parser(element).map(Struct::StyleStruct),
-      "List" => list::List::parser(element).map(Struct::List),
+      "TableStruct" => TableStruct::parser(reader, element).map(Struct::TableStruct),
+      "FigStruct" => FigStruct::parser(reader, element).map(Struct::FigStruct),
+      "StyleStruct" => StyleStruct::parser(reader, element).map(Struct::StyleStruct),
+      "List" => list::List::parser(reader, element).map(Struct::List),
       _ => Err(Error::missing_required_tag("Struct")),
     }
   }
@@ -50,8 +50,12 @@ pub struct QuoteStruct {
 }
 
 impl Parser for QuoteStruct {
-  fn parser(element: &Element) -> result::Result<Self> {
-    contents::Contents::parser(element).map(|c| QuoteStruct { contentes: c })
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
+    if element.name.as_str() == "QuoteStruct" {
+      contents::Contents::parser(reader, element).map(|c| QuoteStruct { contentes: c })
+    } else {
+      Err(Error::wrong_tag_name(element, "QuoteStruct"))
+    }
   }
 }
 
@@ -70,35 +74,37 @@ pub struct NoteStruct {
 }
 
 impl Parser for NoteStruct {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "NoteStruct" {
       let mut title = None;
       let mut title_remarks = Vec::new();
       let mut note = None;
       let mut note_remarks = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "NoteStructTitle" => {
-              title = Some(Text::from_children(&e.children));
+              title = Some(Text::from_reader(reader, &e)?);
             }
-            "Remarks" => {
-              if let Ok(r) = Remarks::parser(e) {
+            "Remarks" => match Remarks::parser(reader, &e) {
+              Ok(r) => {
                 if note.is_none() {
                   title_remarks.push(r);
                 } else {
                   note_remarks.push(r);
                 }
               }
-            }
+              Err(err) => reader.warn(err),
+            },
             "Note" => {
-              let v = Note::parser(e)?;
+              let v = Note::parser(reader, &e)?;
               note = Some(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(note) = note {
         Ok(NoteStruct {
           title,
@@ -146,35 +152,37 @@ pub struct StyleStruct {
 }
 
 impl Parser for StyleStruct {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "StyleStruct" {
       let mut title = None;
       let mut title_remarks = Vec::new();
       let mut style = None;
       let mut style_remarks = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "StyleStructTitle" => {
-              title = Some(Text::from_children(&e.children));
+              title = Some(Text::from_reader(reader, &e)?);
             }
-            "Remarks" => {
-              if let Ok(r) = Remarks::parser(e) {
+            "Remarks" => match Remarks::parser(reader, &e) {
+              Ok(r) => {
                 if style.is_none() {
                   title_remarks.push(r);
                 } else {
                   style_remarks.push(r);
                 }
               }
-            }
+              Err(err) => reader.warn(err),
+            },
             "Style" => {
-              let v = Style::parser(e)?;
+              let v = Style::parser(reader, &e)?;
               style = Some(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(style) = style {
         Ok(StyleStruct {
           title,
@@ -215,42 +223,44 @@ impl ToXmlElement for StyleStruct {
 
 #[derive(Debug, Clone, Hash, Serialize, Deserialize, PartialEq, Eq)]
 pub struct FormatStruct {
-  title: Option<Text>,
-  title_remarks: Vec<Remarks>,
-  format: Format,
-  format_remarks: Vec<Remarks>,
+  pub title: Option<Text>,
+  pub title_remarks: Vec<Remarks>,
+  pub format: Format,
+  pub format_remarks: Vec<Remarks>,
 }
 
 impl Parser for FormatStruct {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "FormatStruct" {
       let mut title = None;
       let mut title_remarks = Vec::new();
       let mut format = None;
       let mut format_remarks = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "FormatStructTitle" => {
-              title = Some(Text::from_children(&e.children));
+              title = Some(Text::from_reader(reader, &e)?);
             }
-            "Remarks" => {
-              if let Ok(r) = Remarks::parser(e) {
+            "Remarks" => match Remarks::parser(reader, &e) {
+              Ok(r) => {
                 if format.is_none() {
                   title_remarks.push(r);
                 } else {
                   format_remarks.push(r);
                 }
               }
-            }
+              Err(err) => reader.warn(err),
+            },
             "Format" => {
-              let v = Format::parser(e)?;
+              let v = Format::parser(reader, &e)?;
               format = Some(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(format) = format {
         Ok(FormatStruct {
           title,
@@ -298,35 +308,37 @@ pub struct FigStruct {
 }
 
 impl Parser for FigStruct {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "FigStruct" {
       let mut title = None;
       let mut title_remarks = Vec::new();
       let mut fig = None;
       let mut fig_remarks = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "FigStructTitle" => {
-              title = Some(Text::from_children(&e.children));
+              title = Some(Text::from_reader(reader, &e)?);
             }
-            "Remarks" => {
-              if let Ok(r) = Remarks::parser(e) {
+            "Remarks" => match Remarks::parser(reader, &e) {
+              Ok(r) => {
                 if fig.is_none() {
                   title_remarks.push(r);
                 } else {
                   fig_remarks.push(r);
                 }
               }
-            }
+              Err(err) => reader.warn(err),
+            },
             "Fig" => {
-              let v = Fig::parser(e)?;
+              let v = Fig::parser(reader, &e)?;
               fig = Some(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(fig) = fig {
         Ok(FigStruct {
           title,
@@ -373,36 +385,38 @@ pub struct TableStruct {
 }
 
 impl Parser for TableStruct {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "TableStruct" {
       let mut title = None;
       let mut title_remarks = Vec::new();
       let mut table = None;
       let mut table_remarks = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "TableStructTitle" => {
-              let v = TextWithWritingMode::parser(e)?;
+              let v = TextWithWritingMode::parser(reader, &e)?;
               title = Some(v);
             }
-            "Remarks" => {
-              if let Ok(r) = Remarks::parser(e) {
+            "Remarks" => match Remarks::parser(reader, &e) {
+              Ok(r) => {
                 if table.is_none() {
                   title_remarks.push(r);
                 } else {
                   table_remarks.push(r);
                 }
               }
-            }
+              Err(err) => reader.warn(err),
+            },
             "Table" => {
-              let v = Table::parser(e)?;
+              let v = Table::parser(reader, &e)?;
               table = Some(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(table) = table {
         Ok(TableStruct {
           title,
@@ -23,7 +23,7 @@ pub struct SupplProvision {
 }
 
 impl Parser for SupplProvision {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "SupplProvision" {
       let suppl_provision_type = match element.attributes.get("Type").map(|s| s.as_str()) {
         Some("New") => Some(SupplProvisionType::New),
@@ -40,40 +40,41 @@ impl Parser for SupplProvision {
       let extract = get_attribute_opt_with_parse(element, "Extract")?;
       let mut label = Text::new();
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "SupplProvisionLabel" => {
-              label = Text::from_children(&e.children);
+              label = Text::from_reader(reader, &e)?;
             }
             "Chapter" => {
-              let v = Chapter::parser(e)?;
+              let v = Chapter::parser(reader, &e)?;
               children.push(SupplProvisionChildrenElement::Chapter(v))
             }
             "Article" => {
-              let v = Article::parser(e)?;
+              let v = Article::parser(reader, &e)?;
               children.push(SupplProvisionChildrenElement::Article(v))
             }
             "Paragraph" => {
-              let v = Paragraph::parser(e)?;
+              let v = Paragraph::parser(reader, &e)?;
               children.push(SupplProvisionChildrenElement::Paragraph(v))
             }
             "SupplProvisionAppdxTable" => {
-              let v = SupplProvisionAppdxTable::parser(e)?;
+              let v = SupplProvisionAppdxTable::parser(reader, &e)?;
               children.push(SupplProvisionChildrenElement::SupplProvisionAppdxTable(v))
             }
             "SupplProvisionAppdxStyle" => {
-              let v = SupplProvisionAppdxStyle::parser(e)?;
+              let v = SupplProvisionAppdxStyle::parser(reader, &e)?;
               children.push(SupplProvisionChildrenElement::SupplProvisionAppdxStyle(v))
             }
             "SupplProvisionAppdx" => {
-              let v = SupplProvisionAppdx::parser(e)?;
+              let v = SupplProvisionAppdx::parser(reader, &e)?;
               children.push(SupplProvisionChildrenElement::SupplProvisionAppdx(v))
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(SupplProvision {
         label,
         children,
@@ -162,30 +163,31 @@ pub struct SupplProvisionAppdxTable {
 }
 
 impl Parser for SupplProvisionAppdxTable {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "SupplProvisionAppdxTable" {
       let num = get_attribute_opt_with_parse(element, "Num")?;
       let mut title = None;
       let mut related_article_num = None;
       let mut table_struct = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "SupplProvisionAppdxTableTitle" => {
-              let v = TextWithWritingMode::parser(e)?;
+              let v = TextWithWritingMode::parser(reader, &e)?;
               title = Some(v);
             }
             "RelatedArticleNum" => {
-              related_article_num = Some(Text::from_children(&e.children));
+              related_article_num = Some(Text::from_reader(reader, &e)?);
             }
             "TableStruct" => {
-              let v = TableStruct::parser(e)?;
+              let v = TableStruct::parser(reader, &e)?;
               table_struct.push(v)
             }
-            s => return Err(Error::wrong_tag_name(e, s)),
+            s => return Err(Error::wrong_tag_name(&e, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(title) = title {
         Ok(SupplProvisionAppdxTable {
           title,
@@ -210,7 +212,7 @@ impl ToXmlElement for SupplProvisionAppdxTable {
     e.children.push(XMLNode::Element(
       self
         .title
-        .to_xml_element_with_name("SupplProvisionAppdxTableitle"),
+        .to_xml_element_with_name("SupplProvisionAppdxTableTitle"),
     ));
     if let Some(t) = &self.related_article_num {
       e.children.push(XMLNode::Element(
@@ -236,30 +238,31 @@ pub struct SupplProvisionAppdxStyle {
 }
 
 impl Parser for SupplProvisionAppdxStyle {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "SupplProvisionAppdxStyle" {
       let num = get_attribute_opt_with_parse(element, "Num")?;
       let mut title = None;
       let mut related_article_num = None;
       let mut style_struct = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "SupplProvisionAppdxStyleTitle" => {
-              let v = TextWithWritingMode::parser(e)?;
+              let v = TextWithWritingMode::parser(reader, &e)?;
               title = Some(v);
             }
             "RelatedArticleNum" => {
-              related_article_num = Some(Text::from_children(&e.children));
+              related_article_num = Some(Text::from_reader(reader, &e)?);
             }
             "StyleStruct" => {
-              let v = StyleStruct::parser(e)?;
+              let v = StyleStruct::parser(reader, &e)?;
               style_struct.push(v)
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(title) = title {
         Ok(SupplProvisionAppdxStyle {
           title,
@@ -310,29 +313,30 @@ pub struct SupplProvisionAppdx {
 }
 
 impl Parser for SupplProvisionAppdx {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "SupplProvisionAppdx" {
       let num = get_attribute_opt_with_parse(element, "Num")?;
       let mut arith_formula_num = None;
       let mut related_article_num = None;
       let mut arith_formula = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "ArithFormulaNum" => {
-              arith_formula_num = Some(Text::from_children(&e.children));
+              arith_formula_num = Some(Text::from_reader(reader, &e)?);
             }
             "RelatedArticleNum" => {
-              related_article_num = Some(Text::from_children(&e.children));
+              related_article_num = Some(Text::from_reader(reader, &e)?);
             }
             "ArithFormula" => {
-              let v = ArithFormula::parser(e)?;
+              let v = ArithFormula::parser(reader, &e)?;
               arith_formula.push(v)
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(SupplProvisionAppdx {
         arith_formula_num,
         related_article_num,
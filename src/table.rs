@@ -22,7 +22,7 @@ pub struct Table {
 }
 
 impl Parser for Table {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Table" {
       let writing_mode = match element.attributes.get("WritingMode").map(|s| s.as_str()) {
         Some("vetical") => WritingMode::Vertical,
@@ -31,21 +31,22 @@ impl Parser for Table {
       };
       let mut table_header_row = Vec::new();
       let mut table_row = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "TableHeaderRow" => {
-              let v = TableHeaderRow::parser(e)?;
+              let v = TableHeaderRow::parser(reader, &e)?;
               table_header_row.push(v)
             }
             "TableRow" => {
-              let v = TableRow::parser(e)?;
+              let v = TableRow::parser(reader, &e)?;
               table_row.push(v)
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(Table {
         table_header_row,
         table_row,
@@ -80,17 +81,18 @@ pub struct TableHeaderRow {
 }
 
 impl Parser for TableHeaderRow {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "TableHeaderRow" {
       let mut columns = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           if e.name.as_str() == "TableHeaderColumn" {
-            let text = Text::from_children(&e.children);
+            let text = Text::from_reader(reader, &e)?;
             columns.push(text);
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(TableHeaderRow { columns })
     } else {
       Err(Error::wrong_tag_name(element, "TableHeaderRow"))
@@ -116,17 +118,18 @@ pub struct TableRow {
 }
 
 impl Parser for TableRow {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "TableRow" {
       let mut columns = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           if e.name.as_str() == "TableColumn" {
-            let v = TableColumn::parser(e)?;
+            let v = TableColumn::parser(reader, &e)?;
             columns.push(v);
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(TableRow { columns })
     } else {
       Err(Error::wrong_tag_name(element, "TableRow"))
@@ -160,7 +163,7 @@ pub struct TableColumn {
 }
 
 impl Parser for TableColumn {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "TableColumn" {
       let border_top =
         LineStyle::from_attribute(element.attributes.get("BorderTop")).unwrap_or(LineStyle::Solid);
@@ -175,103 +178,104 @@ impl Parser for TableColumn {
       let align = Align::from_attribute(element.attributes.get("Align"));
       let valign = Position::from_attribute(element.attributes.get("Valign"));
       let mut contents = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "Part" => {
-              let v = Part::parser(e)?;
+              let v = Part::parser(reader, &e)?;
               contents.push(TableColumnContents::Part(v));
             }
             "Chapter" => {
-              let v = Chapter::parser(e)?;
+              let v = Chapter::parser(reader, &e)?;
               contents.push(TableColumnContents::Chapter(v));
             }
             "Section" => {
-              let v = Section::parser(e)?;
+              let v = Section::parser(reader, &e)?;
               contents.push(TableColumnContents::Section(v));
             }
             "Subsection" => {
-              let v = Subsection::parser(e)?;
+              let v = Subsection::parser(reader, &e)?;
               contents.push(TableColumnContents::Subsection(v));
             }
             "Division" => {
-              let v = Division::parser(e)?;
+              let v = Division::parser(reader, &e)?;
               contents.push(TableColumnContents::Division(v));
             }
             "Article" => {
-              let v = Article::parser(e)?;
+              let v = Article::parser(reader, &e)?;
               contents.push(TableColumnContents::Article(v));
             }
             "Paragraph" => {
-              let v = Paragraph::parser(e)?;
+              let v = Paragraph::parser(reader, &e)?;
               contents.push(TableColumnContents::Paragraph(v));
             }
             "Item" => {
-              let v = Item::parser(e)?;
+              let v = Item::parser(reader, &e)?;
               contents.push(TableColumnContents::Item(v));
             }
             "Subitem1" => {
-              let v = Subitem1::parser(e)?;
+              let v = Subitem1::parser(reader, &e)?;
               contents.push(TableColumnContents::Subitem1(v));
             }
             "Subitem2" => {
-              let v = Subitem2::parser(e)?;
+              let v = Subitem2::parser(reader, &e)?;
               contents.push(TableColumnContents::Subitem2(v));
             }
             "Subitem3" => {
-              let v = Subitem3::parser(e)?;
+              let v = Subitem3::parser(reader, &e)?;
               contents.push(TableColumnContents::Subitem3(v));
             }
             "Subitem4" => {
-              let v = Subitem4::parser(e)?;
+              let v = Subitem4::parser(reader, &e)?;
               contents.push(TableColumnContents::Subitem4(v));
             }
             "Subitem5" => {
-              let v = Subitem5::parser(e)?;
+              let v = Subitem5::parser(reader, &e)?;
               contents.push(TableColumnContents::Subitem5(v));
             }
             "Subitem6" => {
-              let v = Subitem6::parser(e)?;
+              let v = Subitem6::parser(reader, &e)?;
               contents.push(TableColumnContents::Subitem6(v));
             }
             "Subitem7" => {
-              let v = Subitem7::parser(e)?;
+              let v = Subitem7::parser(reader, &e)?;
               contents.push(TableColumnContents::Subitem7(v));
             }
             "Subitem8" => {
-              let v = Subitem8::parser(e)?;
+              let v = Subitem8::parser(reader, &e)?;
               contents.push(TableColumnContents::Subitem8(v));
             }
             "Subitem9" => {
-              let v = Subitem9::parser(e)?;
+              let v = Subitem9::parser(reader, &e)?;
               contents.push(TableColumnContents::Subitem9(v));
             }
             "Subitem10" => {
-              let v = Subitem10::parser(e)?;
+              let v = Subitem10::parser(reader, &e)?;
               contents.push(TableColumnContents::Subitem10(v));
             }
             "FigStruct" => {
-              let v = FigStruct::parser(e)?;
+              let v = FigStruct::parser(reader, &e)?;
               contents.push(TableColumnContents::FigStruct(v));
             }
             "Remarks" => {
-              let v = Remarks::parser(e)?;
+              let v = Remarks::parser(reader, &e)?;
               contents.push(TableColumnContents::Remarks(v));
             }
             "Sentence" => {
-              let v = Sentence::parser(e)?;
+              let v = Sentence::parser(reader, &e)?;
               contents.push(TableColumnContents::Sentence(v));
             }
             "Column" => {
-              let v = Column::parser(e)?;
+              let v = Column::parser(reader, &e)?;
               contents.push(TableColumnContents::Column(v));
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
-        } else if let XMLNode::Text(s) = node {
-          contents.push(TableColumnContents::String(s.clone()))
+        } else if let Node::Text(s) = node {
+          contents.push(TableColumnContents::String(s))
         }
-      }
+        Ok(())
+      })?;
       Ok(TableColumn {
         contents,
         border_top,
@@ -309,10 +313,10 @@ impl ToXmlElement for TableColumn {
       e.attributes.insert("colspan".to_string(), s.clone());
     }
     if let Some(a) = &self.align {
-      e.attributes.insert("rowspan".to_string(), a.to_attribute());
+      e.attributes.insert("Align".to_string(), a.to_attribute());
     }
     if let Some(v) = &self.valign {
-      e.attributes.insert("rowspan".to_string(), v.to_attribute());
+      e.attributes.insert("Valign".to_string(), v.to_attribute());
     }
     for n in self.contents.iter() {
       match n {
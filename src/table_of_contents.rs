@@ -25,49 +25,50 @@ pub struct TOC {
 }
 
 impl Parser for TOC {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "TOC" {
       let mut label = None;
       let mut preamble_label = None;
       let mut main_contents = Vec::new();
       let mut suppl_provision = None;
       let mut appdx_table_lable = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "TOCLabel" => {
-              label = Some(Text::from_children(&e.children));
+              label = Some(Text::from_reader(reader, &e)?);
             }
             "TOCPreambleLabel" => {
-              preamble_label = Some(Text::from_children(&e.children));
+              preamble_label = Some(Text::from_reader(reader, &e)?);
             }
             "TOCPart" => {
-              let v = TOCPart::parser(e)?;
+              let v = TOCPart::parser(reader, &e)?;
               main_contents.push(TOCMainContents::TOCPart(v));
             }
             "TOCChapter" => {
-              let v = TOCChapter::parser(e)?;
+              let v = TOCChapter::parser(reader, &e)?;
               main_contents.push(TOCMainContents::TOCChapter(v));
             }
             "TOCSection" => {
-              let v = TOCSection::parser(e)?;
+              let v = TOCSection::parser(reader, &e)?;
               main_contents.push(TOCMainContents::TOCSection(v));
             }
             "TOCArticle" => {
-              let v = TOCArticle::parser(e)?;
+              let v = TOCArticle::parser(reader, &e)?;
               main_contents.push(TOCMainContents::TOCArticle(v));
             }
             "TOCSupplProvision" => {
-              let v = TOCSupplProvision::parser(e)?;
+              let v = TOCSupplProvision::parser(reader, &e)?;
               suppl_provision = Some(v);
             }
             "TOCAppdxTableLabel" => {
-              appdx_table_lable.push(Text::from_children(&e.children));
+              appdx_table_lable.push(Text::from_reader(reader, &e)?);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(TOC {
         toc_label: label,
         toc_preamble_label: preamble_label,
@@ -89,8 +90,9 @@ impl ToXmlElement for TOC {
         .push(XMLNode::Element(label.to_xml_element_with_name("TOCLabel")));
     }
     if let Some(label) = &self.toc_preamble_label {
-      e.children
-        .push(XMLNode::Element(label.to_xml_element_with_name("TOCLabel")));
+      e.children.push(XMLNode::Element(
+        label.to_xml_element_with_name("TOCPreambleLabel"),
+      ));
     }
     for v in self.toc_main_contents.iter() {
       match v {
@@ -140,30 +142,31 @@ pub struct TOCPart {
 }
 
 impl Parser for TOCPart {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "TOCPart" {
       let mut title = Text::new();
       let mut article_range = None;
       let mut children = Vec::new();
       let num = get_attribute_with_parse(element, "Num")?;
       let delete = get_attribute_opt_with_parse(element, "Delete")?.unwrap_or(false);
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "PartTitle" => {
-              title = Text::from_children(&e.children);
+              title = Text::from_reader(reader, &e)?;
             }
             "ArticleRange" => {
-              article_range = Some(Text::from_children(&e.children));
+              article_range = Some(Text::from_reader(reader, &e)?);
             }
             "TOCChapter" => {
-              let v = TOCChapter::parser(e)?;
+              let v = TOCChapter::parser(reader, &e)?;
               children.push(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(TOCPart {
         part_title: title,
         article_range,
@@ -216,30 +219,31 @@ pub struct TOCChapter {
 }
 
 impl Parser for TOCChapter {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "TOCChapter" {
       let mut title = Text::new();
       let mut article_range = None;
       let mut children = Vec::new();
       let num = get_attribute_with_parse(element, "Num")?;
       let delete = get_attribute_opt_with_parse(element, "Delete")?.unwrap_or(false);
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "ChapterTitle" => {
-              title = Text::from_children(&e.children);
+              title = Text::from_reader(reader, &e)?;
             }
             "ArticleRange" => {
-              article_range = Some(Text::from_children(&e.children));
+              article_range = Some(Text::from_reader(reader, &e)?);
             }
             "TOCSection" => {
-              let v = TOCSection::parser(e)?;
+              let v = TOCSection::parser(reader, &e)?;
               children.push(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(TOCChapter {
         chapter_title: title,
         article_range,
@@ -292,34 +296,35 @@ pub struct TOCSection {
 }
 
 impl Parser for TOCSection {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "TOCSection" {
       let mut title = Text::new();
       let mut article_range = None;
       let mut children = Vec::new();
       let num = get_attribute_with_parse(element, "Num")?;
       let delete = get_attribute_opt_with_parse(element, "Delete")?.unwrap_or(false);
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "SectionTitle" => {
-              title = Text::from_children(&e.children);
+              title = Text::from_reader(reader, &e)?;
             }
             "ArticleRange" => {
-              article_range = Some(Text::from_children(&e.children));
+              article_range = Some(Text::from_reader(reader, &e)?);
             }
             "TOCSubsection" => {
-              let v = TOCSubsection::parser(e)?;
+              let v = TOCSubsection::parser(reader, &e)?;
               children.push(TOCSectionContents::TOCSubsection(v));
             }
             "TOCDivision" => {
-              let v = TOCDivision::parser(e)?;
+              let v = TOCDivision::parser(reader, &e)?;
               children.push(TOCSectionContents::TOCDivision(v));
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(TOCSection {
         section_title: title,
         article_range,
@@ -386,30 +391,31 @@ pub struct TOCSubsection {
 }
 
 impl Parser for TOCSubsection {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "TOCSubsection" {
       let mut title = Text::new();
       let mut article_range = None;
       let mut children = Vec::new();
       let num = get_attribute_with_parse(element, "Num")?;
       let delete = get_attribute_opt_with_parse(element, "Delete")?.unwrap_or(false);
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "SubsectionTitle" => {
-              title = Text::from_children(&e.children);
+              title = Text::from_reader(reader, &e)?;
             }
             "ArticleRange" => {
-              article_range = Some(Text::from_children(&e.children));
+              article_range = Some(Text::from_reader(reader, &e)?);
             }
             "TOCDivision" => {
-              let v = TOCDivision::parser(e)?;
+              let v = TOCDivision::parser(reader, &e)?;
               children.push(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(TOCSubsection {
         subsection_title: title,
         article_range,
@@ -462,25 +468,26 @@ pub struct TOCDivision {
 }
 
 impl Parser for TOCDivision {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "TOCDivision" {
       let mut title = Text::new();
       let mut article_range = None;
       let num = get_attribute_with_parse(element, "Num")?;
       let delete = get_attribute_opt_with_parse(element, "Delete")?.unwrap_or(false);
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "DivisionTitle" => {
-              title = Text::from_children(&e.children);
+              title = Text::from_reader(reader, &e)?;
             }
             "ArticleRange" => {
-              article_range = Some(Text::from_children(&e.children));
+              article_range = Some(Text::from_reader(reader, &e)?);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(TOCDivision {
         division_title: title,
         article_range,
@@ -529,26 +536,27 @@ pub struct TOCArticle {
 }
 
 impl Parser for TOCArticle {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "TOCArticle" {
       let mut title = Text::new();
       let mut caption = None;
       let num = get_attribute_with_parse(element, "Num")?;
       let delete = get_attribute_opt_with_parse(element, "Delete")?.unwrap_or(false);
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "ArticleTitle" => {
-              title = Text::from_children(&e.children);
+              title = Text::from_reader(reader, &e)?;
             }
             "ArticleCaption" => {
-              let v = Caption::parser(e)?;
+              let v = Caption::parser(reader, &e)?;
               caption = Some(v);
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       if let Some(caption) = caption {
         Ok(TOCArticle {
           article_title: title,
@@ -599,32 +607,33 @@ pub struct TOCSupplProvision {
 }
 
 impl Parser for TOCSupplProvision {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name == "TOCSupplProvision" {
       let mut label = Text::new();
       let mut range = None;
       let mut children = Vec::new();
-      for node in element.children.iter() {
-        if let XMLNode::Element(e) = node {
+      reader.each_child(element, |reader, node| {
+        if let Node::Element(e) = node {
           match e.name.as_str() {
             "SupplProvisionLabel" => {
-              label = Text::from_children(&e.children);
+              label = Text::from_reader(reader, &e)?;
             }
             "ArticleRange" => {
-              range = Some(Text::from_children(&e.children));
+              range = Some(Text::from_reader(reader, &e)?);
             }
             "TOCArticle" => {
-              let v = TOCArticle::parser(e)?;
+              let v = TOCArticle::parser(reader, &e)?;
               children.push(TOCSupplProvisionContents::TOCArticle(v));
             }
             "TOCChapter" => {
-              let v = TOCChapter::parser(e)?;
+              let v = TOCChapter::parser(reader, &e)?;
               children.push(TOCSupplProvisionContents::TOCChapter(v));
             }
             s => return Err(Error::unexpected_tag(element, s)),
           }
         }
-      }
+        Ok(())
+      })?;
       Ok(TOCSupplProvision {
         suppl_provision_label: label,
         article_range: range,
@@ -1,2 +1,7 @@
+mod corpus;
 mod parse;
+mod resolve;
+mod round_trip;
+mod validate;
+mod visit;
 mod write;
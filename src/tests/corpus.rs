@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod corpus_mod {
+  use crate::corpus::*;
+  use crate::*;
+  use std::io::Write;
+
+  const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Law Era="Heisei" Lang="ja" LawType="Act" Num="1" Year="1">
+  <LawNum>平成元年法律第一号</LawNum>
+  <LawBody>
+    <LawTitle>テスト法</LawTitle>
+    <MainProvision>
+      <Paragraph Num="1">
+        <ParagraphNum/>
+        <ParagraphSentence><Sentence>本文</Sentence></ParagraphSentence>
+      </Paragraph>
+    </MainProvision>
+    <AppdxFig Num="1"><FigStruct><Fig src="./pict/001.jpg"/></FigStruct></AppdxFig>
+  </LawBody>
+</Law>"#;
+
+  const FILES: [(&str, &[u8]); 4] = [
+    (
+      "all_xml/401AC0000000001_20200401_502AC0000000010/401AC0000000001_20200401_502AC0000000010.xml",
+      XML.as_bytes(),
+    ),
+    (
+      "all_xml/401AC0000000001_20200401_502AC0000000010/pict/001.jpg",
+      b"jpg",
+    ),
+    (
+      "all_xml/401AC0000000002/401AC0000000002.xml",
+      XML.as_bytes(),
+    ),
+    ("all_xml/README.txt", b"readme"),
+  ];
+
+  fn check(corpus: &Corpus) {
+    let entries = corpus.entries();
+    assert_eq!(entries.len(), 2);
+    assert!(corpus.skipped().is_empty());
+    let entry = &entries[0];
+    assert_eq!(entry.name, "401AC0000000001_20200401_502AC0000000010");
+    assert_eq!(entry.law_id.to_string(), "401AC0000000001");
+    let revision_id = entry.revision_id.as_ref().unwrap();
+    assert_eq!(revision_id.date(), koyomi::Date::from_ymd(2020, 4, 1).ok());
+    assert_eq!(
+      revision_id.amend_law_id.as_ref().map(|id| id.to_string()),
+      Some("502AC0000000010".to_string())
+    );
+    assert_eq!(entry.attachments, vec!["pict/001.jpg".to_string()]);
+    assert_eq!(entries[1].law_id.to_string(), "401AC0000000002");
+    assert_eq!(entries[1].revision_id, None);
+    assert!(entries[1].attachments.is_empty());
+    assert_eq!(corpus.find("401AC0000000002").count(), 1);
+
+    let law = corpus.parse(entry).unwrap();
+    let fig = &law.law_body.appdx_fig[0].children[0];
+    match fig {
+      appdx::AppdxFigContents::FigStruct(fig_struct) => {
+        assert_eq!(corpus.fig(entry, &fig_struct.fig).unwrap(), b"jpg");
+      }
+      _ => unreachable!(),
+    }
+    assert!(matches!(
+      corpus.read_attachment(entry, "../README.txt"),
+      Err(result::Error::AttachmentNotFound(_))
+    ));
+    assert_eq!(corpus.laws().filter(|(_, law)| law.is_ok()).count(), 2);
+  }
+
+  #[test]
+  fn test_corpus() {
+    let root = std::env::temp_dir().join(format!("japanese_law_corpus_{}", std::process::id()));
+    for (path, data) in FILES.iter() {
+      let path = root.join(path);
+      std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+      std::fs::write(path, data).unwrap();
+    }
+    let corpus = Corpus::open(&root).unwrap();
+    check(&corpus);
+
+    let zip_path = root.join("all_xml.zip");
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(&zip_path).unwrap());
+    for (path, data) in FILES.iter() {
+      zip
+        .start_file(*path, zip::write::FileOptions::default())
+        .unwrap();
+      zip.write_all(data).unwrap();
+    }
+    zip.finish().unwrap();
+    let corpus = Corpus::open(&zip_path).unwrap();
+    check(&corpus);
+
+    // 法令IDで始まらないディレクトリのXMLファイルは読み飛ばす
+    let stray = root.join("stray");
+    for path in [
+      "all_xml/not_a_law_id/not_a_law_id.xml",
+      "all_xml/401AC0000000002/401AC0000000002.xml",
+    ] {
+      let path = stray.join(path);
+      std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+      std::fs::write(path, XML).unwrap();
+    }
+    let corpus = Corpus::open(&stray).unwrap();
+    assert_eq!(corpus.entries().len(), 1);
+    assert_eq!(corpus.entries()[0].law_id.to_string(), "401AC0000000002");
+    assert_eq!(
+      corpus.skipped(),
+      ["all_xml/not_a_law_id/not_a_law_id.xml".to_string()]
+    );
+
+    std::fs::remove_dir_all(&root).unwrap();
+  }
+}
@@ -54,4 +54,704 @@ mod parse_mod {
       _ => unreachable!(),
     }
   }
+
+  #[test]
+  fn test_parse_xml_bytes_and_file() {
+    let path = "src/tests/129AC0000000089_20230614_505AC0000000053.xml";
+    let bytes = std::fs::read(path).unwrap();
+    assert_eq!(parse_xml(&bytes).unwrap(), parse_xml_file(path).unwrap());
+  }
+
+  #[test]
+  fn test_parse_sentence_with_comment_and_cdata() {
+    let xml = r#"<Sentence Num="1">前段<!-- comment -->後段<![CDATA[<注>]]><Ruby>甚<Rt>じん</Rt></Ruby></Sentence>"#;
+    let sentence = parser::parse_reader::<sentence::Sentence, _>(xml.as_bytes()).unwrap();
+    assert_eq!(sentence.num, Some(1));
+    assert_eq!(
+      sentence.contents,
+      vec![
+        SentenceElement::String("前段後段<注>".to_string()),
+        SentenceElement::Ruby(text::Ruby {
+          text: text::Text::from_value("甚"),
+          ruby: "じん".to_string()
+        }),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_parse_xml_lenient() {
+    use result::Severity;
+    let xml = r#"<Law Era="Heisei" Lang="ja" LawType="Act" Num="1" Year="1">
+      <LawNum>平成元年法律第一号</LawNum>
+      <LawBody>
+        <MainProvision>
+          <Article Num="1">
+            <ArticleTitle>第一条</ArticleTitle>
+            <Foo>不明</Foo>
+            <Paragraph Num="1">
+              <ParagraphNum/>
+              <ParagraphSentence>
+                <Sentence Num="1">前段<Line Style="wavy">線</Line>後段</Sentence>
+              </ParagraphSentence>
+            </Paragraph>
+          </Article>
+          <Article Num="2"><ArticleTitle>第二条</ArticleTitle></Article>
+        </MainProvision>
+      </LawBody>
+    </Law>"#;
+    let err = parse_xml(xml.as_bytes()).unwrap_err();
+    assert!(matches!(err.inner(), result::Error::UnexpectedTag { .. }));
+    assert_eq!(
+      err.path().unwrap().to_string(),
+      "Law/LawBody/MainProvision/Article[Num=1]/Foo"
+    );
+    assert_eq!(
+      err.position(),
+      Some(result::Position {
+        line: 7,
+        column: 13
+      })
+    );
+    let (law, diagnostics) = parse_xml_lenient(xml.as_bytes()).unwrap();
+    assert_eq!(law.law_body.main_provision.children.len(), 2);
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+    assert_eq!(
+      diagnostics[0].path.to_string(),
+      "Law/LawBody/MainProvision/Article[Num=1]/Foo"
+    );
+    assert_eq!(diagnostics[0].position.line, 7);
+    assert_eq!(
+      diagnostics[1].path.to_string(),
+      "Law/LawBody/MainProvision/Article[Num=1]/Paragraph[1]/ParagraphSentence/Sentence[1]/Line"
+    );
+    match &law.law_body.main_provision.children[0] {
+      MainProvisionContents::Article(v) => {
+        assert_eq!(
+          v.paragraph[0].sentence[0].contents,
+          vec![
+            SentenceElement::String("前段".to_string()),
+            SentenceElement::String("後段".to_string()),
+          ]
+        );
+      }
+      _ => unreachable!(),
+    }
+  }
+
+  #[test]
+  fn test_parse_xml_error_location() {
+    let xml = r#"<Law Era="Heisei" Lang="ja" LawType="Act" Num="1" Year="1">
+      <LawNum>平成元年法律第一号</LawNum>
+      <LawBody>
+        <MainProvision>
+          <Article Num="1"><ArticleTitle>第一条</ArticleTitle></Article>
+          <Article><ArticleTitle>第二条</ArticleTitle></Article>
+        </MainProvision>
+      </LawBody>
+    </Law>"#;
+    let err = parse_xml(xml.as_bytes()).unwrap_err();
+    assert!(matches!(
+      err.inner(),
+      result::Error::MissingRequiredAttribute { .. }
+    ));
+    assert_eq!(
+      err.path().unwrap().to_string(),
+      "Law/LawBody/MainProvision/Article[2]"
+    );
+    assert_eq!(err.position().unwrap().line, 6);
+
+    let xml = r#"<Law Era="Heisei" Lang="ja" LawType="Act" Num="1" Year="1"><LawNum>x</Law>"#;
+    let err = parse_xml(xml.as_bytes()).unwrap_err();
+    match err {
+      result::Error::XMLParsing { message, position } => {
+        assert!(!message.is_empty());
+        assert_eq!(position.line, 1);
+      }
+      _ => unreachable!(),
+    }
+  }
+
+  #[test]
+  fn test_from_xml_fragment() {
+    use fragment::*;
+    use structs::TableStruct;
+    let xml = r#"<TableStruct>
+      <Table>
+        <TableRow><TableColumn Align="center"><Sentence>甲</Sentence></TableColumn></TableRow>
+      </Table>
+    </TableStruct>"#;
+    let table_struct = TableStruct::from_xml(xml.as_bytes()).unwrap();
+    assert_eq!(table_struct.table.table_row.len(), 1);
+    let s = table_struct.to_xml_string().unwrap();
+    let table_struct2: TableStruct = from_xml_reader(s.as_bytes()).unwrap();
+    assert_eq!(table_struct, table_struct2);
+
+    let err = paragraph::Paragraph::from_xml(xml.as_bytes()).unwrap_err();
+    assert!(matches!(err.inner(), result::Error::WrongTagName { .. }));
+    let err = contents::Note::from_xml(xml.as_bytes()).unwrap_err();
+    assert!(matches!(err.inner(), result::Error::WrongTagName { .. }));
+
+    let xml =
+      r#"<ArticleCaption CommonCaption="true">（<Ruby>目<Rt>もく</Rt></Ruby>的）</ArticleCaption>"#;
+    let caption = class::Caption::from_xml(xml.as_bytes()).unwrap();
+    assert_eq!(caption.common_caption, Some(true));
+    let s = caption.to_xml_string_with_name("ArticleCaption").unwrap();
+    assert!(s.contains("<ArticleCaption"), "{s}");
+    assert_eq!(class::Caption::from_xml(s.as_bytes()).unwrap(), caption);
+    let s = caption
+      .text
+      .to_xml_string_with_name("ParagraphNum")
+      .unwrap();
+    assert_eq!(text::Text::from_xml(s.as_bytes()).unwrap(), caption.text);
+  }
+
+  #[test]
+  fn test_parse_xml_compressed() {
+    use std::io::Write;
+    let xml = std::fs::read("src/tests/129AC0000000089_20230614_505AC0000000053.xml").unwrap();
+    let law = parse_xml(&xml).unwrap();
+
+    let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    gz.write_all(&xml).unwrap();
+    let gz = gz.finish().unwrap();
+    assert_eq!(parse_xml(&gz).unwrap(), law);
+    assert_eq!(parse_xml_reader(&gz[..]).unwrap(), law);
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip::write::FileOptions::default();
+    zip
+      .start_file("129AC0000000089/pict/a.jpg", options)
+      .unwrap();
+    zip.write_all(b"jpg").unwrap();
+    zip
+      .start_file("129AC0000000089/129AC0000000089.xml", options)
+      .unwrap();
+    zip.write_all(&xml).unwrap();
+    let zip = zip.finish().unwrap().into_inner();
+    assert_eq!(parse_xml_reader(&zip[..]).unwrap(), law);
+  }
+
+  #[test]
+  fn test_parse_xml_io_error() {
+    use std::io::{self, Read};
+    let err = parse_xml_file("src/tests/not_found.xml").unwrap_err();
+    match err {
+      result::Error::FileIoError { path, error } => {
+        assert_eq!(path, std::path::Path::new("src/tests/not_found.xml"));
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+      }
+      _ => unreachable!(),
+    }
+
+    /// 途中で読み込みに失敗する入力
+    struct Broken<'a>(&'a [u8]);
+    impl Read for Broken<'_> {
+      fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.0.is_empty() {
+          Err(io::Error::new(io::ErrorKind::ConnectionReset, "broken"))
+        } else {
+          self.0.read(buf)
+        }
+      }
+    }
+    let xml = r#"<Law Era="Heisei" Lang="ja" LawType="Act" Num="1" Year="1"><LawNum>"#;
+    let err = parse_xml_reader(Broken(xml.as_bytes())).unwrap_err();
+    match err {
+      result::Error::IoError(error) => {
+        assert_eq!(error.kind(), io::ErrorKind::ConnectionReset);
+        assert_eq!(error.to_string(), "broken");
+      }
+      _ => unreachable!(),
+    }
+  }
+
+  #[test]
+  fn test_law_num() {
+    use law_num::*;
+    let law = parse_xml_file("src/tests/129AC0000000089_20230614_505AC0000000053.xml").unwrap();
+    let law_num = LawNum::from_law(&law).unwrap();
+    assert!(law_num.matches(&law));
+    assert_eq!(law_num.to_string(), law.law_num);
+    for suppl_provision in law.law_body.suppl_provision.iter() {
+      if let Some(s) = &suppl_provision.amend_law_num {
+        let amend_law_num = LawNum::from_str(s).unwrap();
+        assert_eq!(&amend_law_num.to_amend_law_num_string(), s);
+      }
+    }
+    let mut law = law;
+    law.year = 30;
+    assert_eq!(law_num.check(&law), vec![LawNumMismatch::Year]);
+  }
+
+  #[test]
+  fn test_parse_text_promulgation_date() {
+    let text = "第一条　この法律は、公布の日から施行する。";
+    let law = parse_text(
+      text,
+      1989,
+      Some(1),
+      Some(7),
+      law::LawType::Act,
+      law::Lang::Ja,
+      None,
+      String::new(),
+      "",
+    )
+    .unwrap();
+    assert_eq!((law.era, law.year), (law::Era::Showa, 64));
+    let result = parse_text(
+      text,
+      2023,
+      Some(2),
+      Some(30),
+      law::LawType::Act,
+      law::Lang::Ja,
+      None,
+      String::new(),
+      "",
+    );
+    assert!(matches!(result, Err(result::Error::InvalidDate(_))));
+  }
+
+  #[test]
+  fn test_parse_text_diagnostics() {
+    use crate::result::{Error, Severity};
+    let text = "第一条　この法律は、公布の日から施行する。
+（見出し）
+第一章　総則
+第二条　次に掲げる者
+一　第一号
+イ　第一号イ
+（１）　第一号イ（１）
+二　第二号
+
+第一款　款
+附則
+第一条　この法律は、公布の日から施行する。
+附則（令和五年六月一四日法律第五三号）";
+    let args = || {
+      (
+        2023,
+        Some(6),
+        Some(14),
+        law::LawType::Act,
+        law::Lang::Ja,
+        None,
+        String::new(),
+      )
+    };
+    let (y, m, d, t, l, n, ln) = args();
+    let (law, diagnostics) = parse_text_lenient(text, y, m, d, t, l, n, ln, "").unwrap();
+    let found = diagnostics
+      .iter()
+      .map(|d| (d.position.line, d.severity, d.error.to_string()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      found,
+      vec![
+        (
+          2,
+          Severity::Warning,
+          Error::UnusedCaption("見出し".to_string()).to_string()
+        ),
+        (
+          10,
+          Severity::Error,
+          Error::UnexpectedLine("第一款　款".to_string()).to_string()
+        ),
+        (
+          13,
+          Severity::Warning,
+          Error::EmptySupplProvision("附則（令和五年六月一四日法律第五三号）".to_string())
+            .to_string()
+        ),
+      ]
+    );
+    assert_eq!(
+      diagnostics[2].path.to_string(),
+      "Law/LawBody/SupplProvision[2]"
+    );
+    assert_eq!(law.law_body.main_provision.children.len(), 2);
+    assert_eq!(law.law_body.suppl_provision.len(), 2);
+    assert!(law.law_body.suppl_provision[1].children.is_empty());
+    match &law.law_body.main_provision.children[1] {
+      law::MainProvisionContents::Chapter(chapter) => match &chapter.children[0] {
+        article::ChapterContents::Article(article) => {
+          let items = &article.paragraph[0].children;
+          assert_eq!(items.len(), 2);
+          assert_eq!(items[0].children[0].children.len(), 1);
+        }
+        c => panic!("{c:?}"),
+      },
+      c => panic!("{c:?}"),
+    }
+
+    let (y, m, d, t, l, n, ln) = args();
+    match parse_text(text, y, m, d, t, l, n, ln, "") {
+      Err(e) => {
+        assert_eq!(e.position().map(|p| p.line), Some(10));
+        assert!(matches!(e.inner(), Error::UnexpectedLine(_)));
+      }
+      Ok(law) => panic!("{law:?}"),
+    }
+
+    // 号の番号のように見えるが番号として読めない行は、本文の続きとして扱い警告する
+    let text = "第一条　次に掲げる者
+アイ　テキスト
+ン　テキスト
+（ン）　テキスト
+その他　テキスト
+イ　号
+（ン）　テキスト";
+    let (y, m, d, t, l, n, ln) = args();
+    let (law, diagnostics) = parse_text_lenient(text, y, m, d, t, l, n, ln, "").unwrap();
+    assert_eq!(
+      diagnostics
+        .iter()
+        .map(|d| (d.position.line, d.severity, d.error.to_string()))
+        .collect::<Vec<_>>(),
+      ["アイ", "ン", "（ン）", "（ン）"]
+        .iter()
+        .zip([2, 3, 4, 7])
+        .map(|(s, line)| {
+          (
+            line,
+            Severity::Warning,
+            Error::UnreadableNumber(s.to_string()).to_string(),
+          )
+        })
+        .collect::<Vec<_>>()
+    );
+    match &law.law_body.main_provision.children[..] {
+      [law::MainProvisionContents::Article(article)] => {
+        assert_eq!(article.paragraph[0].sentence.len(), 5);
+        match &article.paragraph[0].children[0].sentence {
+          class::SentenceOrColumnOrTable::Sentence(s) => assert_eq!(s.len(), 2),
+          s => panic!("{s:?}"),
+        }
+      }
+      c => panic!("{c:?}"),
+    }
+  }
+
+  #[test]
+  fn test_parse_text_titles() {
+    let text = "第二条の二　次に掲げる者
+一　第一号
+イ　第一号イ
+２　第二項";
+    let law = parse_text(
+      text,
+      2023,
+      Some(6),
+      Some(14),
+      law::LawType::Act,
+      law::Lang::Ja,
+      None,
+      String::new(),
+      "",
+    )
+    .unwrap();
+    let xml = to_xml(&law).unwrap();
+    assert!(xml.contains("<ArticleTitle>第二条の二</ArticleTitle>"));
+    assert!(xml.contains("<ParagraphNum />") || xml.contains("<ParagraphNum/>"));
+    assert!(xml.contains("<ParagraphNum>２</ParagraphNum>"));
+    assert!(xml.contains("<ItemTitle>一</ItemTitle>"));
+    assert!(xml.contains("<Subitem1Title>イ</Subitem1Title>"));
+  }
+
+  #[test]
+  fn test_parse_text_suppl_provision() {
+    use crate::suppl_provision::SupplProvisionChildrenElement;
+    use crate::table::TableColumnContents;
+    let text = "第一条　この法律は、公布の日から施行する。
+附　則
+この法律は、公布の日から施行する。
+附　則　（令和五年六月一四日法律第五三号）　抄
+１　この法律は、公布の日から起算して一年を超えない範囲内において政令で定める日から施行する。
+２　次に掲げる規定は、公布の日から施行する。
+一　第一条の規定
+附　則　（令和六年一月一日法律第一号）
+第一章　総則
+第一条　この法律は、公布の日から施行する。
+附則別表第一（第一条関係）
+区分\t金額
+一\t百円
+附則別表第二
+甲\t\t丙";
+    let law = parse_text(
+      text,
+      2023,
+      Some(6),
+      Some(14),
+      law::LawType::Act,
+      law::Lang::Ja,
+      Some(article_number::ArticleNumber::from_num_str("53").unwrap()),
+      String::new(),
+      "",
+    )
+    .unwrap();
+    let suppl = &law.law_body.suppl_provision;
+    assert_eq!(suppl.len(), 3);
+
+    assert_eq!(suppl[0].label, text::Text::from_value("附　則"));
+    assert_eq!(suppl[0].amend_law_num, None);
+    assert_eq!(suppl[0].extract, None);
+    assert_eq!(suppl[0].suppl_provision_type, None);
+    match &suppl[0].children[..] {
+      [SupplProvisionChildrenElement::Paragraph(p)] => {
+        assert_eq!(p.paragraph_num, text::Text::new());
+      }
+      c => panic!("{c:?}"),
+    }
+
+    assert_eq!(
+      suppl[1].amend_law_num.as_deref(),
+      Some("令和五年六月一四日法律第五三号")
+    );
+    assert_eq!(suppl[1].extract, Some(true));
+    assert_eq!(suppl[1].suppl_provision_type, None);
+    match &suppl[1].children[..] {
+      [SupplProvisionChildrenElement::Paragraph(p1), SupplProvisionChildrenElement::Paragraph(p2)] =>
+      {
+        assert_eq!(p1.paragraph_num, text::Text::from_value("１"));
+        assert_eq!(
+          p2.num,
+          article_number::ArticleNumber::from_num_str("2").unwrap()
+        );
+        assert_eq!(p2.children.len(), 1);
+      }
+      c => panic!("{c:?}"),
+    }
+
+    match &suppl[2].children[..] {
+      [SupplProvisionChildrenElement::Chapter(chapter), SupplProvisionChildrenElement::SupplProvisionAppdxTable(t1), SupplProvisionChildrenElement::SupplProvisionAppdxTable(t2)] =>
+      {
+        assert_eq!(chapter.children.len(), 1);
+        assert_eq!(t1.num.as_deref(), Some("1"));
+        assert_eq!(
+          t1.related_article_num,
+          Some(text::Text::from_value("（第一条関係）"))
+        );
+        let rows = &t1.table_struct[0].table.table_row;
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].columns.len(), 2);
+        assert_eq!(t2.num.as_deref(), Some("2"));
+        let columns = &t2.table_struct[0].table.table_row[0].columns;
+        assert_eq!(columns.len(), 3);
+        assert!(matches!(
+          &columns[1].contents[..],
+          [TableColumnContents::Sentence(s)] if s.contents.is_empty()
+        ));
+      }
+      c => panic!("{c:?}"),
+    }
+    assert!(validate(&law).is_empty(), "{:?}", validate(&law));
+  }
+
+  #[test]
+  fn test_parse_text_appdx() {
+    use crate::appdx::AppdxTableContents;
+    use crate::contents::ContentsElement;
+    let text = "第一条　手数料の額は、別表第一のとおりとする。
+第二条　申請書は、様式第一による。
+別表第一（第一条関係）
+区分\t金額
+一\t百円
+二\t二百円
+別表第二
+甲\t乙
+様式第一（第二条関係）
+申請書
+氏名";
+    let law = parse_text(
+      text,
+      2023,
+      Some(6),
+      Some(14),
+      law::LawType::Act,
+      law::Lang::Ja,
+      Some(article_number::ArticleNumber::from_num_str("53").unwrap()),
+      String::new(),
+      "手数料法",
+    )
+    .unwrap();
+    let body = &law.law_body;
+    assert_eq!(body.appdx_table.len(), 2);
+    let table = &body.appdx_table[0];
+    assert_eq!(
+      table.title.as_ref().map(|t| t.contents.clone()),
+      Some(vec![text::TextElement::Text("別表第一".to_string())])
+    );
+    assert_eq!(
+      table.related_article_num,
+      Some(text::Text::from_value("（第一条関係）"))
+    );
+    assert_eq!(table.num.as_deref(), Some("1"));
+    match &table.children[..] {
+      [AppdxTableContents::TableStruct(t)] => {
+        assert_eq!(t.table.table_row.len(), 3);
+        assert_eq!(t.table.table_row[2].columns.len(), 2);
+      }
+      c => panic!("{c:?}"),
+    }
+    assert_eq!(body.appdx_table[1].related_article_num, None);
+    assert_eq!(body.appdx_style.len(), 1);
+    let style = &body.appdx_style[0];
+    assert_eq!(style.num.as_deref(), Some("1"));
+    assert!(matches!(
+      &style.children[0].style.contentes.contents[..],
+      [ContentsElement::Sentence(_), ContentsElement::Sentence(_)]
+    ));
+    assert!(validate(&law).is_empty(), "{:?}", validate(&law));
+    let xml = to_xml(&law).unwrap();
+    assert_eq!(parse_xml(xml.as_bytes()).unwrap(), law);
+
+    let text = "第一条　手数料の額は、別表のとおりとする。
+別表（第一条関係）
+区分　　金額
+一　百円";
+    let options = ParseTextOptions {
+      table_delimiter: TableDelimiter::Whitespace,
+    };
+    let law = parse_text_with(
+      text,
+      2023,
+      None,
+      None,
+      law::LawType::Act,
+      law::Lang::Ja,
+      None,
+      String::new(),
+      "",
+      &options,
+    )
+    .unwrap();
+    match &law.law_body.appdx_table[0].children[..] {
+      [AppdxTableContents::TableStruct(t)] => {
+        assert!(t.table.table_row.iter().all(|r| r.columns.len() == 2));
+      }
+      c => panic!("{c:?}"),
+    }
+  }
+
+  #[test]
+  fn test_parse_text_toc() {
+    use crate::table_of_contents::*;
+    let text = "目次
+第一章　総則（第一条・第二条）
+第二章　手続
+第一節　申請（第三条）
+第二節　削除
+附則
+第一章　総則
+第一条　この法律は、手続を定める。
+第二条　この法律において「申請」とは、届出をいう。
+第二章　手続
+第一節　申請
+第三条　申請は、書面でしなければならない。
+第二節　削除
+附　則
+この法律は、公布の日から施行する。";
+    let law = parse_text(
+      text,
+      2023,
+      None,
+      None,
+      law::LawType::Act,
+      law::Lang::Ja,
+      Some(article_number::ArticleNumber::from_num_str("1").unwrap()),
+      String::new(),
+      "手続法",
+    )
+    .unwrap();
+    let toc = law.law_body.toc.as_ref().unwrap();
+    assert_eq!(toc.toc_label, Some(text::Text::from_value("目次")));
+    match &toc.toc_main_contents[..] {
+      [TOCMainContents::TOCChapter(c1), TOCMainContents::TOCChapter(c2)] => {
+        assert_eq!(c1.chapter_title, text::Text::from_value("総則"));
+        assert_eq!(
+          c1.article_range,
+          Some(text::Text::from_value("（第一条・第二条）"))
+        );
+        assert_eq!(c2.article_range, None);
+        assert_eq!(c2.children.len(), 2);
+        assert_eq!(
+          c2.children[0].article_range,
+          Some(text::Text::from_value("（第三条）"))
+        );
+        assert!(c2.children[1].delete);
+      }
+      c => panic!("{c:?}"),
+    }
+    assert_eq!(
+      toc
+        .toc_suppl_provision
+        .as_ref()
+        .map(|s| &s.suppl_provision_label),
+      Some(&text::Text::from_value("附則"))
+    );
+    let children = &law.law_body.main_provision.children;
+    assert_eq!(children.len(), 2);
+    match &children[0] {
+      MainProvisionContents::Chapter(c) => assert_eq!(c.children.len(), 2),
+      c => panic!("{c:?}"),
+    }
+    assert_eq!(law.law_body.suppl_provision.len(), 1);
+    let xml = to_xml(&law).unwrap();
+    assert_eq!(parse_xml(xml.as_bytes()).unwrap(), law);
+  }
+
+  #[test]
+  fn test_parse_text_document() {
+    let text = "手続法
+（令和五年六月十四日法律第五十三号）
+国会は、手続を定めるため、この法律を制定する。
+第一条　この法律は、手続を定める。
+附　則
+この法律は、公布の日から施行する。";
+    let law = parse_text_document(text).unwrap();
+    assert_eq!(law.era, law::Era::Reiwa);
+    assert_eq!(law.year, 5);
+    assert_eq!(law.promulgate_month, Some(6));
+    assert_eq!(law.promulgate_day, Some(14));
+    assert_eq!(law.law_type, law::LawType::Act);
+    assert_eq!(
+      law.num,
+      Some(article_number::ArticleNumber::from_num_str("53").unwrap())
+    );
+    assert_eq!(law.law_num, "令和五年六月十四日法律第五十三号");
+    let body = &law.law_body;
+    assert_eq!(
+      body.law_title.as_ref().map(|t| &t.text),
+      Some(&text::Text::from_value("手続法"))
+    );
+    assert_eq!(
+      body.enact_statement,
+      vec![text::Text::from_value(
+        "国会は、手続を定めるため、この法律を制定する。"
+      )]
+    );
+    assert_eq!(body.main_provision.children.len(), 1);
+    assert_eq!(body.suppl_provision.len(), 1);
+    assert!(validate(&law).is_empty(), "{:?}", validate(&law));
+    let xml = to_xml(&law).unwrap();
+    assert_eq!(parse_xml(xml.as_bytes()).unwrap(), law);
+
+    // 空行や`前文`の行の後は前文とする
+    for text in [
+      "手続法\n（令和五年法律第一号）\n制定文\n\n前文の一\n前文の二\n第一条　本文",
+      "手続法\n（令和五年法律第一号）\n制定文\n前文\n前文の一\n前文の二\n第一条　本文",
+    ] {
+      let body = parse_text_document(text).unwrap().law_body;
+      assert_eq!(body.enact_statement, vec![text::Text::from_value("制定文")]);
+      assert_eq!(body.preamble.map(|p| p.children.len()), Some(2));
+    }
+
+    let err = parse_text_document("手続法\n第一条　この法律は、手続を定める。").unwrap_err();
+    assert_eq!(err.position().map(|p| p.line), Some(2));
+    assert!(matches!(err.inner(), Error::ParsingError(..)));
+  }
 }
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod resolve_mod {
+  use crate::{article_number::ArticleNumber, resolve::Provision, result::Error, *};
+
+  fn num(s: &str) -> ArticleNumber {
+    ArticleNumber::from_num_str(s).unwrap()
+  }
+
+  #[test]
+  fn test_resolve() {
+    let law = parse_xml_file("src/tests/129AC0000000089_20230614_505AC0000000053.xml").unwrap();
+    match law.resolve("第十三条").unwrap() {
+      Provision::Article(a) => assert_eq!(a.num, num("13")),
+      p => panic!("{p:?}"),
+    }
+    match law.resolve("第十三条第一項第二号").unwrap() {
+      Provision::Item(item) => assert_eq!(item.num, Some(num("2"))),
+      p => panic!("{p:?}"),
+    }
+    match law.resolve("第一編第一章").unwrap() {
+      Provision::Chapter(c) => assert_eq!(c.num, num("1")),
+      p => panic!("{p:?}"),
+    }
+    match law.resolve("第四百六十五条の六第二項第一号イ").unwrap() {
+      Provision::Subitem1(s) => assert_eq!(s.num, Some(num("1"))),
+      p => panic!("{p:?}"),
+    }
+    match law
+      .resolve("附則（平成十一年十二月八日法律第百四十九号）第一条")
+      .unwrap()
+    {
+      Provision::Article(a) => assert_eq!(a.num, num("1")),
+      p => panic!("{p:?}"),
+    }
+    match law
+      .resolve("附則（大正一五年四月二四日法律第六九号）")
+      .unwrap()
+    {
+      Provision::SupplProvision(s) => assert_eq!(
+        s.amend_law_num.as_deref(),
+        Some("大正一五年四月二四日法律第六九号")
+      ),
+      p => panic!("{p:?}"),
+    }
+    let p = law.resolve("第二条").unwrap();
+    assert_eq!(p.num(), Some(&num("2")));
+    assert!(matches!(
+      law.resolve("第九千条"),
+      Err(Error::ProvisionNotFound(_))
+    ));
+    assert!(matches!(
+      law.resolve("第十三条第一項第二号ン"),
+      Err(Error::ProvisionNotFound(_))
+    ));
+    assert!(matches!(
+      law.resolve("第十条から第十二条まで"),
+      Err(Error::ParsingError(..))
+    ));
+    assert!(matches!(
+      law.resolve("第十三条の規定"),
+      Err(Error::ParsingError(..))
+    ));
+  }
+
+  #[test]
+  fn test_anchor() {
+    let law = parse_xml_file("src/tests/129AC0000000089_20230614_505AC0000000053.xml").unwrap();
+    let index = law.anchor_index();
+    // 対応表はスレッド間で共有できる
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+    assert_send_sync(&index);
+    let article = law.resolve("第一条").unwrap();
+    assert_eq!(index.id_of(&article), Some("Mp-Pa_1-Ch_1-At_1"));
+    let item = law.resolve("第四百六十五条の六第二項第一号イ").unwrap();
+    let id = index.id_of(&item).unwrap();
+    assert!(id.ends_with("-At_465_6-Pr_2-It_1-Si1_1"), "{id}");
+    assert_eq!(index.get(id), Some(item));
+    assert_eq!(law.find_anchor(id), Some(item));
+    let suppl = law
+      .resolve("附則（大正一五年四月二四日法律第六九号）第一項")
+      .unwrap();
+    assert_eq!(index.id_of(&suppl), Some("Sp_1-Pr_1"));
+    let ids = index.iter().map(|(id, _)| id).collect::<Vec<_>>();
+    let unique = ids.iter().collect::<std::collections::HashSet<_>>();
+    assert_eq!(ids.len(), unique.len());
+    assert_eq!(index.get("Mp-At_99999"), None);
+  }
+}
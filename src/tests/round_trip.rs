@@ -0,0 +1,276 @@
+#[cfg(test)]
+mod round_trip_mod {
+  use crate::parser::{parse_reader, Parser};
+  use crate::to_xml::ToXmlElement;
+  use crate::*;
+  use std::fmt::Debug;
+
+  /// 読み込んだ値を書き出して読み直し、元の値と等しいことと書き出したXMLがスキーマに合うことを確かめる
+  fn round_trip<T>(xml: &str) -> T
+  where
+    T: Parser + ToXmlElement + PartialEq + Debug,
+  {
+    let v = parse_reader::<T, _>(xml.as_bytes()).unwrap();
+    let element = v.to_xml_element();
+    assert_eq!(schema::validate_element(&element), Vec::new());
+    let mut buf = Vec::new();
+    element.write(&mut buf).unwrap();
+    let v2 = parse_reader::<T, _>(&buf[..]).unwrap();
+    assert_eq!(v, v2);
+    v
+  }
+
+  #[test]
+  fn test_round_trip_129ac0000000089() {
+    // 民法
+    let law = parse_xml_file("src/tests/129AC0000000089_20230614_505AC0000000053.xml").unwrap();
+    let law2 = parse_xml(to_xml(&law).unwrap().as_bytes()).unwrap();
+    assert_eq!(law, law2);
+  }
+
+  #[test]
+  fn test_round_trip_appdx() {
+    use appdx::*;
+    round_trip::<AppdxTable>(
+      r#"<AppdxTable Num="1">
+        <AppdxTableTitle WritingMode="horizontal">別表第一</AppdxTableTitle>
+        <RelatedArticleNum>（第二条関係）</RelatedArticleNum>
+        <TableStruct><Table><TableRow><TableColumn><Sentence>表</Sentence></TableColumn></TableRow></Table></TableStruct>
+        <Item Num="1"><ItemTitle>一</ItemTitle><ItemSentence><Sentence>項目</Sentence></ItemSentence></Item>
+        <Remarks><RemarksLabel>備考</RemarksLabel><Sentence>備考の文</Sentence></Remarks>
+      </AppdxTable>"#,
+    );
+    round_trip::<AppdxNote>(
+      r#"<AppdxNote Num="2">
+        <AppdxNoteTitle WritingMode="vertical">別記</AppdxNoteTitle>
+        <RelatedArticleNum>（第三条関係）</RelatedArticleNum>
+        <NoteStruct><Note><Sentence>記</Sentence></Note></NoteStruct>
+        <FigStruct><Fig src="./pict/a.jpg"/></FigStruct>
+        <Remarks><RemarksLabel>備考</RemarksLabel></Remarks>
+      </AppdxNote>"#,
+    );
+    round_trip::<AppdxStyle>(
+      r#"<AppdxStyle Num="1">
+        <AppdxStyleTitle>様式第一</AppdxStyleTitle>
+        <RelatedArticleNum>（第四条関係）</RelatedArticleNum>
+        <StyleStruct><StyleStructTitle>様式</StyleStructTitle><Style><Fig src="./pict/b.pdf"/></Style></StyleStruct>
+        <Remarks><RemarksLabel>備考</RemarksLabel><Sentence>用紙</Sentence></Remarks>
+      </AppdxStyle>"#,
+    );
+    round_trip::<AppdxFormat>(
+      r#"<AppdxFormat Num="1">
+        <AppdxFormatTitle>別記書式</AppdxFormatTitle>
+        <RelatedArticleNum>（第五条関係）</RelatedArticleNum>
+        <FormatStruct><FormatStructTitle>書式</FormatStructTitle><Format><Sentence>書式の文</Sentence></Format></FormatStruct>
+        <Remarks><RemarksLabel>備考</RemarksLabel></Remarks>
+      </AppdxFormat>"#,
+    );
+    round_trip::<Appdx>(
+      r#"<Appdx>
+        <ArithFormulaNum>付録第一</ArithFormulaNum>
+        <RelatedArticleNum>（第六条関係）</RelatedArticleNum>
+        <ArithFormula Num="1"><Sentence>式</Sentence></ArithFormula>
+        <Remarks><RemarksLabel>備考</RemarksLabel></Remarks>
+      </Appdx>"#,
+    );
+    round_trip::<AppdxFig>(
+      r#"<AppdxFig Num="1">
+        <AppdxFigTitle>別図</AppdxFigTitle>
+        <RelatedArticleNum>（第七条関係）</RelatedArticleNum>
+        <FigStruct><FigStructTitle>図</FigStructTitle><Fig src="./pict/c.jpg"/></FigStruct>
+        <TableStruct><Table><TableRow><TableColumn><Sentence>表</Sentence></TableColumn></TableRow></Table></TableStruct>
+      </AppdxFig>"#,
+    );
+  }
+
+  #[test]
+  fn test_round_trip_amend_provision() {
+    use law::*;
+    let amend = round_trip::<AmendProvision>(
+      r#"<AmendProvision>
+        <AmendProvisionSentence><Sentence>第一条を次のように改める。</Sentence></AmendProvisionSentence>
+        <NewProvision>
+          <Article Num="1">
+            <ArticleCaption>（目的）</ArticleCaption>
+            <ArticleTitle>第一条</ArticleTitle>
+            <Paragraph Num="1"><ParagraphNum/><ParagraphSentence><Sentence>新しい条</Sentence></ParagraphSentence></Paragraph>
+          </Article>
+        </NewProvision>
+        <NewProvision>
+          <Item Num="2"><ItemTitle>二</ItemTitle><ItemSentence><Sentence>新しい号</Sentence></ItemSentence></Item>
+        </NewProvision>
+        <NewProvision>
+          <Sentence>新しい文</Sentence>
+        </NewProvision>
+      </AmendProvision>"#,
+    );
+    assert!(amend.sentence.is_some());
+    // 新しい規定ごとに`NewProvision`で包んで書き出す
+    let element = amend.to_xml_element();
+    let names = element
+      .children
+      .iter()
+      .filter_map(|n| n.as_element().map(|e| e.name.as_str()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      names,
+      [
+        "AmendProvisionSentence",
+        "NewProvision",
+        "NewProvision",
+        "NewProvision"
+      ]
+    );
+    assert!(matches!(
+      &amend.new_provision[..],
+      [
+        NewProvision::Article(_),
+        NewProvision::Item(_),
+        NewProvision::Sentence(_)
+      ]
+    ));
+  }
+
+  #[test]
+  fn test_round_trip_structs() {
+    use structs::*;
+    round_trip::<NoteStruct>(
+      r#"<NoteStruct>
+        <NoteStructTitle>記</NoteStructTitle>
+        <Remarks><RemarksLabel>前</RemarksLabel></Remarks>
+        <Note><Sentence>記の本文</Sentence><Item Num="1"><ItemTitle>一</ItemTitle><ItemSentence><Sentence>号</Sentence></ItemSentence></Item></Note>
+        <Remarks><RemarksLabel>後</RemarksLabel></Remarks>
+      </NoteStruct>"#,
+    );
+    round_trip::<StyleStruct>(
+      r#"<StyleStruct>
+        <StyleStructTitle>様式</StyleStructTitle>
+        <Style><Fig src="./pict/a.pdf"/></Style>
+        <Remarks><RemarksLabel LineBreak="true">備考</RemarksLabel><Sentence>備考の文</Sentence></Remarks>
+      </StyleStruct>"#,
+    );
+    round_trip::<FormatStruct>(
+      r#"<FormatStruct>
+        <FormatStructTitle>書式</FormatStructTitle>
+        <Format><Sentence>書式の文</Sentence></Format>
+      </FormatStruct>"#,
+    );
+    round_trip::<FigStruct>(
+      r#"<FigStruct>
+        <FigStructTitle>図</FigStructTitle>
+        <Remarks><RemarksLabel>前</RemarksLabel></Remarks>
+        <Fig src="./pict/b.jpg"/>
+        <Remarks><RemarksLabel>後</RemarksLabel></Remarks>
+      </FigStruct>"#,
+    );
+    round_trip::<TableStruct>(
+      r#"<TableStruct>
+        <TableStructTitle WritingMode="horizontal">表</TableStructTitle>
+        <Remarks><RemarksLabel>前</RemarksLabel></Remarks>
+        <Table><TableRow><TableColumn><Sentence>表</Sentence></TableColumn></TableRow></Table>
+        <Remarks><RemarksLabel>後</RemarksLabel></Remarks>
+      </TableStruct>"#,
+    );
+  }
+
+  #[test]
+  fn test_round_trip_list() {
+    use list::*;
+    round_trip::<List>(
+      r#"<List>
+        <ListSentence><Sentence>列記</Sentence></ListSentence>
+        <Sublist1>
+          <Sublist1Sentence><Column Num="1"><Sentence>一</Sentence></Column><Column Num="2"><Sentence>二</Sentence></Column></Sublist1Sentence>
+          <Sublist2>
+            <Sublist2Sentence><Sentence>細目</Sentence></Sublist2Sentence>
+            <Sublist3><Sublist3Sentence><Sentence>細々目</Sentence></Sublist3Sentence></Sublist3>
+          </Sublist2>
+        </Sublist1>
+      </List>"#,
+    );
+  }
+
+  #[test]
+  fn test_round_trip_remarks() {
+    use remarks::*;
+    let remarks = round_trip::<Remarks>(
+      r#"<Remarks>
+        <RemarksLabel LineBreak="true">備考</RemarksLabel>
+        <Item Num="1"><ItemTitle>一</ItemTitle><ItemSentence><Sentence>項目</Sentence></ItemSentence></Item>
+        <Item Num="2"><ItemTitle>二</ItemTitle><ItemSentence><Sentence>項目</Sentence></ItemSentence></Item>
+      </Remarks>"#,
+    );
+    assert_eq!(remarks.label.text, text::Text::from_value("備考"));
+    assert!(remarks.label.line_break);
+    round_trip::<RemarksLabel>(r#"<RemarksLabel>備<Ruby>考<Rt>こう</Rt></Ruby></RemarksLabel>"#);
+  }
+
+  #[test]
+  fn test_round_trip_table() {
+    use table::*;
+    let table = round_trip::<Table>(
+      r#"<Table WritingMode="horizontal">
+        <TableHeaderRow><TableHeaderColumn>見出し</TableHeaderColumn></TableHeaderRow>
+        <TableRow>
+          <TableColumn BorderTop="double" BorderBottom="dotted" BorderLeft="none" BorderRight="solid" rowspan="2" colspan="3" Align="center" Valign="top">
+            <Sentence>本文</Sentence>
+          </TableColumn>
+          <TableColumn><Column Num="1"><Sentence>列</Sentence></Column></TableColumn>
+        </TableRow>
+      </Table>"#,
+    );
+    let column = &table.table_row[0].columns[0];
+    assert_eq!(column.border_top, line::LineStyle::Double);
+    assert_eq!(column.align, Some(class::Align::Center));
+    assert_eq!(column.valign, Some(Position::Top));
+    round_trip::<TableHeaderRow>(
+      r#"<TableHeaderRow><TableHeaderColumn>一</TableHeaderColumn><TableHeaderColumn>二</TableHeaderColumn></TableHeaderRow>"#,
+    );
+    round_trip::<TableRow>(
+      r#"<TableRow><TableColumn><Item Num="1"><ItemTitle>一</ItemTitle><ItemSentence><Sentence>号</Sentence></ItemSentence></Item></TableColumn></TableRow>"#,
+    );
+    round_trip::<TableColumn>(
+      r#"<TableColumn><FigStruct><Fig src="./pict/a.jpg"/></FigStruct><Remarks><RemarksLabel>備考</RemarksLabel></Remarks></TableColumn>"#,
+    );
+  }
+
+  #[test]
+  fn test_round_trip_table_of_contents() {
+    use table_of_contents::*;
+    let toc = round_trip::<TOC>(
+      r#"<TOC>
+        <TOCLabel>目次</TOCLabel>
+        <TOCPreambleLabel>前文</TOCPreambleLabel>
+        <TOCPart Num="1">
+          <PartTitle>第一編　総則</PartTitle>
+          <ArticleRange>（第一条―第十条）</ArticleRange>
+          <TOCChapter Num="1">
+            <ChapterTitle>第一章　通則</ChapterTitle>
+            <ArticleRange>（第一条・第二条）</ArticleRange>
+            <TOCSection Num="1">
+              <SectionTitle>第一節　総則</SectionTitle>
+              <TOCSubsection Num="1">
+                <SubsectionTitle>第一款　通則</SubsectionTitle>
+                <TOCDivision Num="1"><DivisionTitle>第一目　通則</DivisionTitle><ArticleRange>（第一条）</ArticleRange></TOCDivision>
+              </TOCSubsection>
+              <TOCDivision Num="2" Delete="true"><DivisionTitle>第二目　削除</DivisionTitle></TOCDivision>
+            </TOCSection>
+          </TOCChapter>
+        </TOCPart>
+        <TOCSupplProvision>
+          <SupplProvisionLabel>附則</SupplProvisionLabel>
+          <ArticleRange>（第一条）</ArticleRange>
+          <TOCArticle Num="1"><ArticleTitle>第一条</ArticleTitle><ArticleCaption>（施行期日）</ArticleCaption></TOCArticle>
+        </TOCSupplProvision>
+        <TOCAppdxTableLabel>別表</TOCAppdxTableLabel>
+      </TOC>"#,
+    );
+    assert_eq!(toc.toc_preamble_label, Some(text::Text::from_value("前文")));
+    round_trip::<TOCArticle>(
+      r#"<TOCArticle Num="2_3:5"><ArticleTitle>第二条の三から第五条まで</ArticleTitle><ArticleCaption>削除</ArticleCaption></TOCArticle>"#,
+    );
+    round_trip::<TOCSupplProvision>(
+      r#"<TOCSupplProvision><SupplProvisionLabel>附則</SupplProvisionLabel><TOCChapter Num="1"><ChapterTitle>第一章</ChapterTitle></TOCChapter></TOCSupplProvision>"#,
+    );
+  }
+}
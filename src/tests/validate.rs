@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod validate_mod {
+  use crate::schema::*;
+  use crate::*;
+
+  #[test]
+  fn test_validate_129ac0000000089() {
+    // 民法
+    let xml = std::fs::read("src/tests/129AC0000000089_20230614_505AC0000000053.xml").unwrap();
+    assert_eq!(validate_xml(&xml).unwrap(), Vec::new());
+  }
+
+  #[test]
+  fn test_validate_xml_violations() {
+    let xml = r#"<Law Era="Kyoho" Lang="ja" LawType="Act" Num="1" Year="1" Foo="x">
+      <LawNum>平成元年法律第一号</LawNum>
+      <LawBody>
+        <MainProvision>
+          <Article Num="1">
+            <Paragraph Num="1">
+              <ParagraphNum/>
+              <ParagraphSentence><Sentence>本文</Sentence></ParagraphSentence>
+            </Paragraph>
+            <ArticleTitle>第一条</ArticleTitle>
+          </Article>
+          <Article Num="2">
+            <ArticleTitle>第二条</ArticleTitle>
+          </Article>
+        </MainProvision>
+      </LawBody>
+    </Law>"#;
+    let violations = validate_xml(xml.as_bytes())
+      .unwrap()
+      .iter()
+      .map(|v| (v.path.to_string(), v.kind.clone()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      violations,
+      vec![
+        (
+          "Law".to_string(),
+          ViolationKind::InvalidAttributeValue {
+            name: "Era".to_string(),
+            value: "Kyoho".to_string()
+          }
+        ),
+        (
+          "Law".to_string(),
+          ViolationKind::UnknownAttribute {
+            name: "Foo".to_string()
+          }
+        ),
+        (
+          "Law/LawBody/MainProvision/Article[Num=1]".to_string(),
+          ViolationKind::UnexpectedElement {
+            name: "Paragraph".to_string()
+          }
+        ),
+        (
+          "Law/LawBody/MainProvision/Article[Num=2]".to_string(),
+          ViolationKind::IncompleteContent
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_validate_law() {
+    let law = parse_xml_file("src/tests/129AC0000000089_20230614_505AC0000000053.xml").unwrap();
+    assert_eq!(validate(&law), Vec::new());
+  }
+}
@@ -0,0 +1,350 @@
+#[cfg(test)]
+mod visit_mod {
+  use crate::{
+    anchor::anchors,
+    article::Article,
+    article_number::{ArticleLevel, ArticleNumber},
+    item_like::ItemLike,
+    paragraph::{Item, Subitem1},
+    resolve::Provision,
+    visit::{self, Visit},
+    visit_mut::VisitMut,
+    *,
+  };
+
+  fn num(s: &str) -> ArticleNumber {
+    ArticleNumber::from_num_str(s).unwrap()
+  }
+
+  #[derive(Default)]
+  struct Count {
+    article: usize,
+    period: usize,
+  }
+
+  impl<'ast> Visit<'ast> for Count {
+    fn visit_article(&mut self, node: &'ast Article) {
+      self.article += 1;
+      visit::visit_article(self, node);
+    }
+    fn visit_string(&mut self, node: &'ast str) {
+      self.period += node.matches('。').count();
+    }
+  }
+
+  struct Replace;
+
+  impl VisitMut for Replace {
+    fn visit_string_mut(&mut self, node: &mut String) {
+      *node = node.replace('。', "．");
+    }
+  }
+
+  #[test]
+  fn test_visit() {
+    let mut law = parse_xml_file("src/tests/129AC0000000089_20230614_505AC0000000053.xml").unwrap();
+    let mut count = Count::default();
+    count.visit_law(&law);
+    let articles = anchors(&law)
+      .iter()
+      .filter(|(_, p)| matches!(p, Provision::Article(_)))
+      .count();
+    assert_eq!(count.article, articles);
+    assert!(count.period > count.article);
+
+    Replace.visit_law_mut(&mut law);
+    let mut count = Count::default();
+    count.visit_law(&law);
+    assert_eq!(count.article, articles);
+    assert_eq!(count.period, 0);
+  }
+
+  #[derive(Default)]
+  struct CollectItem<'ast> {
+    items: Vec<&'ast Item>,
+    subitem1: usize,
+  }
+
+  impl<'ast> Visit<'ast> for CollectItem<'ast> {
+    fn visit_item(&mut self, node: &'ast Item) {
+      self.items.push(node);
+      visit::visit_item(self, node);
+    }
+    fn visit_subitem1(&mut self, node: &'ast Subitem1) {
+      self.subitem1 += 1;
+      visit::visit_subitem1(self, node);
+    }
+  }
+
+  #[test]
+  fn test_item_like() {
+    let law = parse_xml_file("src/tests/129AC0000000089_20230614_505AC0000000053.xml").unwrap();
+    let mut collect = CollectItem::default();
+    collect.visit_law(&law);
+    assert!(collect.subitem1 > 0);
+    let mut subitem1 = 0;
+    for item in collect.items.iter() {
+      let like = ItemLike::from(*item);
+      assert_eq!(like.depth(), 0);
+      assert_eq!(like.num(), item.num.as_ref());
+      let children = like.children();
+      subitem1 += children.len();
+      for child in children.iter() {
+        assert_eq!(child.depth(), 1);
+      }
+      let tree = like.to_tree();
+      assert_eq!(tree.children.len(), item.children.len());
+      assert_eq!(tree.into_item().unwrap(), **item);
+    }
+    assert_eq!(subitem1, collect.subitem1);
+  }
+
+  #[test]
+  fn test_item_too_deep() {
+    use crate::class::SentenceOrColumnOrTable;
+    use crate::item_like::ItemTree;
+    use crate::result::Error;
+
+    // 号の下に`depth`段の細分が続く木
+    fn tree(depth: usize) -> ItemTree {
+      ItemTree {
+        title: Some(text::Text::from_value("一")),
+        sentence: SentenceOrColumnOrTable::Sentence(Vec::new()),
+        struct_list: Vec::new(),
+        num: Some(num("1")),
+        delete: false,
+        hide: false,
+        children: if depth == 0 {
+          Vec::new()
+        } else {
+          vec![tree(depth - 1)]
+        },
+      }
+    }
+
+    // 号から細分10までの11段は変換できる
+    let item = tree(10);
+    assert_eq!(item.depth(), 10);
+    assert!(item.into_item().is_ok());
+    // 12段目があるとエラーになる
+    let item = tree(11);
+    assert_eq!(item.depth(), 11);
+    assert!(matches!(item.into_item(), Err(Error::ItemTooDeep)));
+  }
+
+  #[test]
+  fn test_sentences() {
+    use crate::sentence::SentenceFunction;
+    use crate::sentences::LocationSegment;
+
+    struct CountSentence(usize);
+    impl<'ast> Visit<'ast> for CountSentence {
+      fn visit_sentence(&mut self, node: &'ast crate::sentence::Sentence) {
+        self.0 += 1;
+        visit::visit_sentence(self, node);
+      }
+    }
+
+    let law = parse_xml_file("src/tests/129AC0000000089_20230614_505AC0000000053.xml").unwrap();
+    let sentences = law.sentences().collect::<Vec<_>>();
+    let mut count = CountSentence(0);
+    count.visit_law(&law);
+    assert_eq!(sentences.len(), count.0);
+
+    let first = &sentences[0].location;
+    let levels = first.provisions().map(|p| p.level()).collect::<Vec<_>>();
+    assert_eq!(
+      levels,
+      vec![
+        None,
+        Some(ArticleLevel::Part),
+        Some(ArticleLevel::Chapter),
+        Some(ArticleLevel::Article),
+        Some(ArticleLevel::Paragraph),
+      ]
+    );
+    assert_eq!(first.article().map(|a| a.num.clone()), Some(num("1")));
+    assert!(first.suppl_provision().is_none());
+    assert!(matches!(
+      first.path[0],
+      LocationSegment::Provision(Provision::MainProvision(_))
+    ));
+
+    assert!(sentences
+      .iter()
+      .any(|s| s.location.function == Some(SentenceFunction::Proviso)));
+    assert!(sentences
+      .iter()
+      .any(|s| s.location.suppl_provision().is_some()));
+    let item = sentences
+      .iter()
+      .find(|s| {
+        matches!(
+          s.location.path.last(),
+          Some(LocationSegment::Provision(Provision::Item(_)))
+        )
+      })
+      .unwrap();
+    assert!(item.location.article().is_some());
+    assert_eq!(item.location.num, item.sentence.num);
+  }
+
+  #[test]
+  fn test_sentence_locations() {
+    use crate::sentence::SentenceElement;
+    use crate::sentences::{LocatedSentence, LocationSegment as L};
+    use Provision as P;
+
+    let xml = r#"<Law Era="Reiwa" Lang="ja" LawType="Act" Num="1" Year="5">
+      <LawNum>令和五年法律第一号</LawNum>
+      <LawBody>
+        <LawTitle>テスト法</LawTitle>
+        <MainProvision>
+          <Article Num="1">
+            <ArticleTitle>第一条</ArticleTitle>
+            <Paragraph Num="1">
+              <ParagraphNum/>
+              <ParagraphSentence><Sentence>項</Sentence></ParagraphSentence>
+              <Class Num="1">
+                <ClassTitle>一</ClassTitle>
+                <ClassSentence><Sentence>類</Sentence></ClassSentence>
+              </Class>
+              <Item Num="1">
+                <ItemTitle>一</ItemTitle>
+                <ItemSentence><Sentence>号</Sentence></ItemSentence>
+                <List>
+                  <ListSentence><Sentence>列記</Sentence></ListSentence>
+                  <Sublist1><Sublist1Sentence><Sentence>細分</Sentence></Sublist1Sentence></Sublist1>
+                </List>
+              </Item>
+            </Paragraph>
+          </Article>
+        </MainProvision>
+        <SupplProvision>
+          <SupplProvisionLabel>附則</SupplProvisionLabel>
+          <Paragraph Num="1"><ParagraphNum/><ParagraphSentence><Sentence>附則</Sentence></ParagraphSentence></Paragraph>
+          <SupplProvisionAppdxTable Num="1">
+            <SupplProvisionAppdxTableTitle>附則別表第一</SupplProvisionAppdxTableTitle>
+            <TableStruct><Table><TableRow><TableColumn><Sentence>附則別表</Sentence></TableColumn></TableRow></Table></TableStruct>
+          </SupplProvisionAppdxTable>
+        </SupplProvision>
+        <AppdxTable Num="1">
+          <AppdxTableTitle>別表第一</AppdxTableTitle>
+          <TableStruct>
+            <Table>
+              <TableRow><TableColumn><Sentence>一行一列</Sentence></TableColumn><TableColumn><Sentence>一行二列</Sentence></TableColumn></TableRow>
+              <TableRow><TableColumn><Sentence>二行一列</Sentence></TableColumn><TableColumn><Sentence>二行二列</Sentence></TableColumn></TableRow>
+            </Table>
+          </TableStruct>
+          <Remarks><RemarksLabel>備考</RemarksLabel><Sentence>備考</Sentence></Remarks>
+        </AppdxTable>
+      </LawBody>
+    </Law>"#;
+    let law = parse_xml(xml.as_bytes()).unwrap();
+    let sentences = law.sentences().collect::<Vec<_>>();
+    let find = |text: &str| -> &LocatedSentence {
+      sentences
+        .iter()
+        .find(|s| s.sentence.contents == [SentenceElement::String(text.to_string())])
+        .unwrap()
+    };
+
+    assert!(matches!(
+      find("類").location.path[..],
+      [
+        L::Provision(P::MainProvision(_)),
+        L::Provision(P::Article(_)),
+        L::Provision(P::Paragraph(_)),
+        L::Class(_)
+      ]
+    ));
+    assert!(matches!(
+      find("列記").location.path[..],
+      [
+        L::Provision(P::MainProvision(_)),
+        L::Provision(P::Article(_)),
+        L::Provision(P::Paragraph(_)),
+        L::Provision(P::Item(_)),
+        L::List { depth: 0 }
+      ]
+    ));
+    assert!(matches!(
+      find("細分").location.path[..],
+      [
+        L::Provision(P::MainProvision(_)),
+        L::Provision(P::Article(_)),
+        L::Provision(P::Paragraph(_)),
+        L::Provision(P::Item(_)),
+        L::List { depth: 0 },
+        L::List { depth: 1 }
+      ]
+    ));
+    assert!(matches!(
+      find("一行二列").location.path[..],
+      [
+        L::Provision(P::AppdxTable(_)),
+        L::TableStruct(_),
+        L::TableColumn { row: 1, column: 2 }
+      ]
+    ));
+    assert!(matches!(
+      find("二行一列").location.path[..],
+      [
+        L::Provision(P::AppdxTable(_)),
+        L::TableStruct(_),
+        L::TableColumn { row: 2, column: 1 }
+      ]
+    ));
+    assert!(matches!(
+      find("備考").location.path[..],
+      [L::Provision(P::AppdxTable(_)), L::Remarks(_)]
+    ));
+    let suppl = find("附則別表");
+    assert!(matches!(
+      suppl.location.path[..],
+      [
+        L::Provision(P::SupplProvision(_)),
+        L::SupplProvisionAppdxTable(_),
+        L::TableStruct(_),
+        L::TableColumn { row: 1, column: 1 }
+      ]
+    ));
+    assert!(suppl.location.suppl_provision().is_some());
+    assert!(suppl.location.article().is_none());
+    assert_eq!(sentences.len(), 12);
+  }
+
+  #[test]
+  fn test_visit_paragraph_order() {
+    use crate::fragment::FromXml;
+    use crate::sentence::{Sentence, SentenceElement};
+
+    struct Collect(Vec<String>);
+    impl<'ast> Visit<'ast> for Collect {
+      fn visit_sentence(&mut self, node: &'ast Sentence) {
+        for e in node.contents.iter() {
+          if let SentenceElement::String(s) = e {
+            self.0.push(s.clone());
+          }
+        }
+      }
+    }
+
+    let xml = r#"<Paragraph Num="1">
+  <ParagraphNum/>
+  <ParagraphSentence><Sentence>本文</Sentence></ParagraphSentence>
+  <Class Num="1">
+    <ClassTitle>一</ClassTitle>
+    <ClassSentence><Sentence>類</Sentence></ClassSentence>
+  </Class>
+  <Item Num="1">
+    <ItemTitle>一</ItemTitle>
+    <ItemSentence><Sentence>号</Sentence></ItemSentence>
+  </Item>
+</Paragraph>"#;
+    let paragraph = paragraph::Paragraph::from_xml(xml.as_bytes()).unwrap();
+    let mut collect = Collect(Vec::new());
+    collect.visit_paragraph(&paragraph);
+    assert_eq!(collect.0, vec!["本文", "類", "号"]);
+  }
+}
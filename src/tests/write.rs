@@ -1,5 +1,5 @@
 #[cfg(test)]
-mod write {
+mod write_mod {
   use crate::{
     article::PartContents, law::MainProvisionContents, sentence::SentenceElement, text::*, *,
   };
@@ -37,4 +37,65 @@ mod write {
       _ => unreachable!(),
     }
   }
+
+  #[test]
+  fn test_to_xml_with_options() {
+    use writer::WriteOptions;
+    let xml = r#"<Law Year="1" Num="2" PromulgateDay="3" LawType="Act" Lang="ja" Era="Heisei" PromulgateMonth="4">
+      <LawNum>平成元年法律第二号</LawNum>
+      <LawBody>
+        <LawTitle AbbrevKana="" Abbrev="" Kana="てすと">テスト法</LawTitle>
+        <MainProvision>
+          <Paragraph Num="1" OldStyle="true">
+            <ParagraphNum></ParagraphNum>
+            <ParagraphSentence><Sentence WritingMode="horizontal" Num="1" Function="main">本文</Sentence></ParagraphSentence>
+          </Paragraph>
+        </MainProvision>
+      </LawBody>
+    </Law>"#;
+    let law = parse_xml(xml.as_bytes()).unwrap();
+
+    let s = to_xml(&law).unwrap();
+    assert!(s.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+    assert!(s.contains(
+      r#"<Law Era="Heisei" Lang="ja" LawType="Act" Num="2" Year="1" PromulgateMonth="4" PromulgateDay="3">"#
+    ));
+    assert!(s.contains(r#"<LawTitle Kana="てすと" Abbrev="" AbbrevKana="">"#));
+    assert!(s.contains(r#"<Sentence Function="main" Num="1" WritingMode="horizontal">"#));
+    assert!(s.contains("\n  <LawNum>"));
+    assert!(s.contains("<ParagraphNum/>"));
+    assert_eq!(s, to_xml(&law).unwrap());
+
+    let options = WriteOptions {
+      indent: false,
+      xml_declaration: false,
+      self_closing: false,
+      ..WriteOptions::default()
+    };
+    let s = to_xml_with(&law, &options).unwrap();
+    assert!(s.starts_with("<Law "));
+    assert!(!s.contains('\n'));
+    assert!(s.contains("<ParagraphNum></ParagraphNum>"));
+    assert_eq!(parse_xml(s.as_bytes()).unwrap(), law);
+
+    let options = WriteOptions {
+      indent_width: 4,
+      standalone: Some(false),
+      ..WriteOptions::default()
+    };
+    let mut buf = Vec::new();
+    write_file_with(&law, &mut buf, &options).unwrap();
+    let s = String::from_utf8(buf).unwrap();
+    assert!(s.starts_with(r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?>"#));
+    assert!(s.contains("\n    <LawNum>"));
+
+    let options = WriteOptions {
+      encoding: "Shift_JIS".to_string(),
+      ..WriteOptions::default()
+    };
+    assert!(matches!(
+      to_xml_with(&law, &options),
+      Err(result::Error::UnsupportedEncoding(_))
+    ));
+  }
 }
@@ -27,28 +27,11 @@ impl Text {
     let mut text = Text::new();
     for node in children {
       if let XMLNode::Element(element) = node {
-        match element.name.as_str() {
-          "Ruby" => {
-            if let Ok(ruby) = Ruby::parser(element) {
-              text.add_ruby(ruby)
-            }
-          }
-          "Line" => {
-            if let Ok(line) = Line::parser(element) {
-              text.add_line(line)
-            }
-          }
-          "Sup" => {
-            if let Ok(sup) = Sup::parser(element) {
-              text.add_sup(sup)
-            }
+        let mut buf = Vec::new();
+        if element.write(&mut buf).is_ok() {
+          if let Ok(t) = parser::parse_reader::<Text, _>(buf.as_slice()) {
+            text.add_text(t)
           }
-          "Sub" => {
-            if let Ok(sub) = Sub::parser(element) {
-              text.add_sub(sub)
-            }
-          }
-          _ => (),
         }
       } else if let XMLNode::Text(s) = node {
         text.add_string(s)
@@ -57,6 +40,37 @@ impl Text {
     text
   }
 
+  /// 読み出し中の要素の子ノードからの生成
+  pub(crate) fn from_reader(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
+    let mut text = Text::new();
+    while let Some(node) = reader.next_child(element)? {
+      if let Node::Element(e) = node {
+        match e.name.as_str() {
+          "Ruby" => match Ruby::parser(reader, &e) {
+            Ok(ruby) => text.add_ruby(ruby),
+            Err(err) => reader.warn(err),
+          },
+          "Line" => match Line::parser(reader, &e) {
+            Ok(line) => text.add_line(line),
+            Err(err) => reader.warn(err),
+          },
+          "Sup" => match Sup::parser(reader, &e) {
+            Ok(sup) => text.add_sup(sup),
+            Err(err) => reader.warn(err),
+          },
+          "Sub" => match Sub::parser(reader, &e) {
+            Ok(sub) => text.add_sub(sub),
+            Err(err) => reader.warn(err),
+          },
+          _ => (),
+        }
+      } else if let Node::Text(s) = node {
+        text.add_string(&s)
+      }
+    }
+    Ok(text)
+  }
+
   /// 値から
   pub fn from_value<T: ToString>(t: T) -> Self {
     Text {
@@ -130,26 +144,26 @@ impl ToString for Text {
 }
 
 impl Parser for Text {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     let mut text = Text::new();
     match element.name.as_str() {
       "Ruby" => {
-        if let Ok(ruby) = Ruby::parser(element) {
+        if let Ok(ruby) = Ruby::parser(reader, element) {
           text.add_ruby(ruby)
         }
       }
       "Line" => {
-        if let Ok(line) = Line::parser(element) {
+        if let Ok(line) = Line::parser(reader, element) {
           text.add_line(line)
         }
       }
       "Sup" => {
-        if let Ok(sup) = Sup::parser(element) {
+        if let Ok(sup) = Sup::parser(reader, element) {
           text.add_sup(sup)
         }
       }
       "Sub" => {
-        if let Ok(sub) = Sub::parser(element) {
+        if let Ok(sub) = Sub::parser(reader, element) {
           text.add_sub(sub)
         }
       }
@@ -189,9 +203,9 @@ pub struct TextWithWritingMode {
 }
 
 impl Parser for TextWithWritingMode {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     let writing_mode = element.attributes.get("WritingMode").map(|s| s.as_str());
-    let text = Text::from_children(&element.children);
+    let text = Text::from_reader(reader, element)?;
     match writing_mode {
       Some("vertical") => Ok(TextWithWritingMode {
         contents: text.contents,
@@ -275,29 +289,23 @@ impl Ruby {
 }
 
 impl Parser for Ruby {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name == "Ruby" {
-      let children = &element.children;
       let mut text = Text::new();
       let mut ruby = None;
-      for children_node in children.iter() {
-        if let XMLNode::Element(element) = children_node {
-          if "Rt" == element.name.as_str() {
-            let s = element
-              .children
-              .iter()
-              .map(|n| {
-                if let XMLNode::Text(s) = n {
-                  s.clone()
-                } else {
-                  String::new()
-                }
-              })
-              .collect::<String>();
+      while let Some(children_node) = reader.next_child(element)? {
+        if let Node::Element(rt) = children_node {
+          if "Rt" == rt.name.as_str() {
+            let mut s = String::new();
+            while let Some(n) = reader.next_child(&rt)? {
+              if let Node::Text(str) = n {
+                s.push_str(&str)
+              }
+            }
             ruby = Some(s)
           }
-        } else if let XMLNode::Text(s) = children_node {
-          text.add_string(s)
+        } else if let Node::Text(s) = children_node {
+          text.add_string(&s)
         }
       }
       let r = ruby.unwrap_or_default();
@@ -328,18 +336,11 @@ pub struct Sup {
 }
 
 impl Parser for Sup {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Sup" {
-      let text = element
-        .children
-        .first()
-        .and_then(|n| {
-          if let XMLNode::Text(s) = n {
-            Some(s.clone())
-          } else {
-            None
-          }
-        })
+      let text = reader
+        .next_child(element)?
+        .and_then(|n| if let Node::Text(s) = n { Some(s) } else { None })
         .unwrap_or_default();
       Ok(Sup { text })
     } else {
@@ -363,18 +364,11 @@ pub struct Sub {
 }
 
 impl Parser for Sub {
-  fn parser(element: &Element) -> result::Result<Self> {
+  fn parser(reader: &mut XmlReader, element: &Tag) -> result::Result<Self> {
     if element.name.as_str() == "Sub" {
-      let text = element
-        .children
-        .first()
-        .and_then(|n| {
-          if let XMLNode::Text(s) = n {
-            Some(s.clone())
-          } else {
-            None
-          }
-        })
+      let text = reader
+        .next_child(element)?
+        .and_then(|n| if let Node::Text(s) = n { Some(s) } else { None })
         .unwrap_or_default();
       Ok(Sub { text })
     } else {
@@ -406,8 +400,7 @@ mod tests {
   #[test]
   fn check_ruby() {
     let data = r#"<Ruby>甚<Rt>じん</Rt></Ruby>"#;
-    let node = xmltree::Element::parse(data.as_bytes()).unwrap();
-    let ruby = Ruby::parser(&node).unwrap();
+    let ruby = parser::parse_reader::<Ruby, _>(data.as_bytes()).unwrap();
     assert_eq!(ruby, Ruby::new(&Text::from_value("甚"), "じん"));
     assert_eq!(ruby.text.to_string(), "甚".to_string())
   }
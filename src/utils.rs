@@ -9,11 +9,9 @@ use crate::{
   article_number::ArticleNumber,
   class::SentenceOrColumnOrTable,
   contents::ContentsElement,
+  item_like::ItemLike,
   law::{MainProvision, MainProvisionContents},
-  paragraph::{
-    Paragraph, Subitem1, Subitem10, Subitem2, Subitem3, Subitem4, Subitem5, Subitem6, Subitem7,
-    Subitem8, Subitem9,
-  },
+  paragraph::Paragraph,
   sentence::SentenceElement,
   suppl_provision::{self, SupplProvision},
   table::{Table, TableColumnContents},
@@ -373,431 +371,53 @@ pub fn text_info_list_from_paragraph(lst: &[Paragraph]) -> Vec<(TextIndex, Strin
       },
       sentence_text,
     ));
-    let mut items = Vec::new();
     for item in para.children.iter() {
-      let n = &item.num;
-      items.push(n.clone());
-      // childrenとsentenceの処理をする
-      let sentence_str = match &item.sentence {
-        SentenceOrColumnOrTable::Sentence(se) => se
-          .iter()
-          .map(|sentence| sentence_element_to_str(&sentence.contents))
-          .collect::<String>(),
-        SentenceOrColumnOrTable::Column(cs) => cs
-          .iter()
-          .flat_map(|column| {
-            column
-              .sentence
-              .iter()
-              .map(|sentence| sentence_element_to_str(&sentence.contents))
-          })
-          .collect::<Vec<_>>()
-          .join(" "),
-        SentenceOrColumnOrTable::Table(table) => table_to_str(table),
-      };
-      v.push((
-        TextIndex {
-          paragraph: paragraph_num.clone(),
-          items: items.clone(),
-        },
-        sentence_str,
-      ));
-      let mut v2 = text_list_from_subitem1(paragraph_num, items.clone(), &item.children);
+      let mut v2 = text_list_from_item(paragraph_num, Vec::new(), ItemLike::Item(item));
       v.append(&mut v2);
-      items.pop();
     }
   }
   v
 }
 
-fn text_list_from_subitem1(
-  para_num: &ArticleNumber,
-  items: Vec<Option<ArticleNumber>>,
-  chldren: &[Subitem1],
-) -> Vec<(TextIndex, String)> {
-  let mut v = Vec::new();
-  for t in chldren.iter() {
-    let mut l = items.clone();
-    l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      SentenceOrColumnOrTable::Column(cs) => cs
-        .iter()
-        .flat_map(|column| {
-          column
-            .sentence
-            .iter()
-            .map(|sentence| sentence_element_to_str(&sentence.contents))
-        })
-        .collect::<Vec<_>>()
-        .join(" "),
-      SentenceOrColumnOrTable::Table(table) => table_to_str(table),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
-    ));
-    let mut v2 = text_list_from_subitem2(para_num, l.clone(), &t.children);
-    v.append(&mut v2);
-  }
-  v
-}
-
-fn text_list_from_subitem2(
-  para_num: &ArticleNumber,
-  items: Vec<Option<ArticleNumber>>,
-  chldren: &[Subitem2],
-) -> Vec<(TextIndex, String)> {
-  let mut v = Vec::new();
-  for t in chldren.iter() {
-    let mut l = items.clone();
-    l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      SentenceOrColumnOrTable::Column(cs) => cs
-        .iter()
-        .flat_map(|column| {
-          column
-            .sentence
-            .iter()
-            .map(|sentence| sentence_element_to_str(&sentence.contents))
-        })
-        .collect::<Vec<_>>()
-        .join(" "),
-      SentenceOrColumnOrTable::Table(table) => table_to_str(table),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
-    ));
-    let mut v2 = text_list_from_subitem3(para_num, l.clone(), &t.children);
-    v.append(&mut v2);
-  }
-  v
-}
-
-fn text_list_from_subitem3(
-  para_num: &ArticleNumber,
-  items: Vec<Option<ArticleNumber>>,
-  chldren: &[Subitem3],
-) -> Vec<(TextIndex, String)> {
-  let mut v = Vec::new();
-  for t in chldren.iter() {
-    let mut l = items.clone();
-    l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      SentenceOrColumnOrTable::Column(cs) => cs
-        .iter()
-        .flat_map(|column| {
-          column
-            .sentence
-            .iter()
-            .map(|sentence| sentence_element_to_str(&sentence.contents))
-        })
-        .collect::<Vec<_>>()
-        .join(" "),
-      SentenceOrColumnOrTable::Table(table) => table_to_str(table),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
-    ));
-    let mut v2 = text_list_from_subitem4(para_num, l.clone(), &t.children);
-    v.append(&mut v2);
-  }
-  v
-}
-
-fn text_list_from_subitem4(
-  para_num: &ArticleNumber,
-  items: Vec<Option<ArticleNumber>>,
-  chldren: &[Subitem4],
-) -> Vec<(TextIndex, String)> {
-  let mut v = Vec::new();
-  for t in chldren.iter() {
-    let mut l = items.clone();
-    l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      SentenceOrColumnOrTable::Column(cs) => cs
-        .iter()
-        .flat_map(|column| {
-          column
-            .sentence
-            .iter()
-            .map(|sentence| sentence_element_to_str(&sentence.contents))
-        })
-        .collect::<Vec<_>>()
-        .join(" "),
-      SentenceOrColumnOrTable::Table(table) => table_to_str(table),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
-    ));
-    let mut v2 = text_list_from_subitem5(para_num, l.clone(), &t.children);
-    v.append(&mut v2);
-  }
-  v
-}
-
-fn text_list_from_subitem5(
+/// 号と、その下の細分の文字列を文書中の順に並べる
+fn text_list_from_item(
   para_num: &ArticleNumber,
-  items: Vec<Option<ArticleNumber>>,
-  chldren: &[Subitem5],
+  mut items: Vec<Option<ArticleNumber>>,
+  item: ItemLike,
 ) -> Vec<(TextIndex, String)> {
   let mut v = Vec::new();
-  for t in chldren.iter() {
-    let mut l = items.clone();
-    l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      SentenceOrColumnOrTable::Column(cs) => cs
-        .iter()
-        .flat_map(|column| {
-          column
-            .sentence
-            .iter()
-            .map(|sentence| sentence_element_to_str(&sentence.contents))
-        })
-        .collect::<Vec<_>>()
-        .join(" "),
-      SentenceOrColumnOrTable::Table(table) => table_to_str(table),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
-    ));
-    let mut v2 = text_list_from_subitem6(para_num, l.clone(), &t.children);
-    v.append(&mut v2);
-  }
-  v
-}
-
-fn text_list_from_subitem6(
-  para_num: &ArticleNumber,
-  items: Vec<Option<ArticleNumber>>,
-  chldren: &[Subitem6],
-) -> Vec<(TextIndex, String)> {
-  let mut v = Vec::new();
-  for t in chldren.iter() {
-    let mut l = items.clone();
-    l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      SentenceOrColumnOrTable::Column(cs) => cs
-        .iter()
-        .flat_map(|column| {
-          column
-            .sentence
-            .iter()
-            .map(|sentence| sentence_element_to_str(&sentence.contents))
-        })
-        .collect::<Vec<_>>()
-        .join(" "),
-      SentenceOrColumnOrTable::Table(_) => String::new(),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
-    ));
-    let mut v2 = text_list_from_subitem7(para_num, l.clone(), &t.children);
-    v.append(&mut v2);
-  }
-  v
-}
-
-fn text_list_from_subitem7(
-  para_num: &ArticleNumber,
-  items: Vec<Option<ArticleNumber>>,
-  chldren: &[Subitem7],
-) -> Vec<(TextIndex, String)> {
-  let mut v = Vec::new();
-  for t in chldren.iter() {
-    let mut l = items.clone();
-    l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      SentenceOrColumnOrTable::Column(cs) => cs
-        .iter()
-        .flat_map(|column| {
-          column
-            .sentence
-            .iter()
-            .map(|sentence| sentence_element_to_str(&sentence.contents))
-        })
-        .collect::<Vec<_>>()
-        .join(" "),
-      SentenceOrColumnOrTable::Table(table) => table_to_str(table),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
-    ));
-    let mut v2 = text_list_from_subitem8(para_num, l.clone(), &t.children);
-    v.append(&mut v2);
-  }
-  v
-}
-
-fn text_list_from_subitem8(
-  para_num: &ArticleNumber,
-  items: Vec<Option<ArticleNumber>>,
-  chldren: &[Subitem8],
-) -> Vec<(TextIndex, String)> {
-  let mut v = Vec::new();
-  for t in chldren.iter() {
-    let mut l = items.clone();
-    l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      SentenceOrColumnOrTable::Column(cs) => cs
-        .iter()
-        .flat_map(|column| {
-          column
-            .sentence
-            .iter()
-            .map(|sentence| sentence_element_to_str(&sentence.contents))
-        })
-        .collect::<Vec<_>>()
-        .join(" "),
-      SentenceOrColumnOrTable::Table(table) => table_to_str(table),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
-    ));
-    let mut v2 = text_list_from_subitem9(para_num, l.clone(), &t.children);
-    v.append(&mut v2);
-  }
-  v
-}
-
-fn text_list_from_subitem9(
-  para_num: &ArticleNumber,
-  items: Vec<Option<ArticleNumber>>,
-  chldren: &[Subitem9],
-) -> Vec<(TextIndex, String)> {
-  let mut v = Vec::new();
-  for t in chldren.iter() {
-    let mut l = items.clone();
-    l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      SentenceOrColumnOrTable::Column(cs) => cs
-        .iter()
-        .flat_map(|column| {
-          column
-            .sentence
-            .iter()
-            .map(|sentence| sentence_element_to_str(&sentence.contents))
-        })
-        .collect::<Vec<_>>()
-        .join(" "),
-      SentenceOrColumnOrTable::Table(table) => table_to_str(table),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l.clone(),
-      },
-      sentence_str,
-    ));
-    let mut v2 = text_list_from_subitem10(para_num, l.clone(), &t.children);
+  items.push(item.num().cloned());
+  let sentence_str = match item.sentence() {
+    SentenceOrColumnOrTable::Sentence(se) => se
+      .iter()
+      .map(|sentence| sentence_element_to_str(&sentence.contents))
+      .collect::<String>(),
+    SentenceOrColumnOrTable::Column(cs) => cs
+      .iter()
+      .flat_map(|column| {
+        column
+          .sentence
+          .iter()
+          .map(|sentence| sentence_element_to_str(&sentence.contents))
+      })
+      .collect::<Vec<_>>()
+      .join(" "),
+    SentenceOrColumnOrTable::Table(table) => table_to_str(table),
+  };
+  v.push((
+    TextIndex {
+      paragraph: para_num.clone(),
+      items: items.clone(),
+    },
+    sentence_str,
+  ));
+  for child in item.children() {
+    let mut v2 = text_list_from_item(para_num, items.clone(), child);
     v.append(&mut v2);
   }
   v
 }
 
-fn text_list_from_subitem10(
-  para_num: &ArticleNumber,
-  items: Vec<Option<ArticleNumber>>,
-  chldren: &[Subitem10],
-) -> Vec<(TextIndex, String)> {
-  let mut v = Vec::new();
-  for t in chldren.iter() {
-    let mut l = items.clone();
-    l.push(t.num.clone());
-    let sentence_str = match &t.sentence {
-      SentenceOrColumnOrTable::Sentence(se) => se
-        .iter()
-        .map(|sentence| sentence_element_to_str(&sentence.contents))
-        .collect::<String>(),
-      SentenceOrColumnOrTable::Column(cs) => cs
-        .iter()
-        .flat_map(|column| {
-          column
-            .sentence
-            .iter()
-            .map(|sentence| sentence_element_to_str(&sentence.contents))
-        })
-        .collect::<Vec<_>>()
-        .join(" "),
-      SentenceOrColumnOrTable::Table(table) => table_to_str(table),
-    };
-    v.push((
-      TextIndex {
-        paragraph: para_num.clone(),
-        items: l,
-      },
-      sentence_str,
-    ));
-  }
-  v
-}
-
 pub fn table_to_str(table: &Table) -> String {
   let header_row_str = table
     .table_header_row
@@ -0,0 +1,1412 @@
+//! 法令の構造体の走査
+//!
+//! [`Visit`]の各メソッドは、既定では同名の関数を呼び出して子要素を文書中の順に走査する。
+//! 必要な要素のメソッドだけを実装し、子要素も走査する場合はその中で同名の関数を呼び出す。
+//!
+//! ```no_run
+//! use japanese_law_xml_schema::article::Article;
+//! use japanese_law_xml_schema::parse_xml_file;
+//! use japanese_law_xml_schema::visit::{self, Visit};
+//!
+//! struct CountArticle(usize);
+//!
+//! impl<'ast> Visit<'ast> for CountArticle {
+//!   fn visit_article(&mut self, node: &'ast Article) {
+//!     self.0 += 1;
+//!     visit::visit_article(self, node);
+//!   }
+//! }
+//!
+//! let law = parse_xml_file("129AC0000000089_20230614_505AC0000000053.xml").unwrap();
+//! let mut count = CountArticle(0);
+//! count.visit_law(&law);
+//! ```
+
+use crate::appdx::{
+  Appdx, AppdxFig, AppdxFigContents, AppdxFormat, AppdxNote, AppdxNoteContents, AppdxStyle,
+  AppdxTable, AppdxTableContents,
+};
+use crate::article::{
+  Article, Chapter, ChapterContents, Division, Part, PartContents, Section, SectionContents,
+  Subsection, SubsectionContents,
+};
+use crate::class::{Caption, Class, Column, SentenceOrColumnOrTable};
+use crate::contents::{ArithFormula, Contents, ContentsElement, Format, Note, Style};
+use crate::fig::Fig;
+use crate::law::{
+  AmendProvision, Law, LawBody, LawTitle, MainProvision, MainProvisionContents, NewProvision,
+  Preamble,
+};
+use crate::line::{Line, LineContents};
+use crate::list::{List, ListSentence, Sublist1, Sublist2, Sublist3};
+use crate::paragraph::{
+  Item, Paragraph, Subitem1, Subitem10, Subitem2, Subitem3, Subitem4, Subitem5, Subitem6, Subitem7,
+  Subitem8, Subitem9,
+};
+use crate::remarks::{Remarks, RemarksContents, RemarksLabel};
+use crate::sentence::{Sentence, SentenceElement};
+use crate::structs::{
+  FigStruct, FormatStruct, NoteStruct, QuoteStruct, Struct, StyleStruct, TableStruct,
+};
+use crate::suppl_provision::{
+  SupplProvision, SupplProvisionAppdx, SupplProvisionAppdxStyle, SupplProvisionAppdxTable,
+  SupplProvisionChildrenElement,
+};
+use crate::table::{Table, TableColumn, TableColumnContents, TableHeaderRow, TableRow};
+use crate::table_of_contents::{
+  TOCArticle, TOCChapter, TOCDivision, TOCMainContents, TOCPart, TOCSection, TOCSectionContents,
+  TOCSubsection, TOCSupplProvision, TOCSupplProvisionContents, TOC,
+};
+use crate::text::{Ruby, Sub, Sup, Text, TextElement, TextWithWritingMode};
+
+/// 法令の構造体を走査するトレイト
+pub trait Visit<'ast> {
+  fn visit_law(&mut self, node: &'ast Law) {
+    visit_law(self, node)
+  }
+  fn visit_law_body(&mut self, node: &'ast LawBody) {
+    visit_law_body(self, node)
+  }
+  fn visit_law_title(&mut self, node: &'ast LawTitle) {
+    visit_law_title(self, node)
+  }
+  fn visit_preamble(&mut self, node: &'ast Preamble) {
+    visit_preamble(self, node)
+  }
+  fn visit_main_provision(&mut self, node: &'ast MainProvision) {
+    visit_main_provision(self, node)
+  }
+  fn visit_main_provision_contents(&mut self, node: &'ast MainProvisionContents) {
+    visit_main_provision_contents(self, node)
+  }
+  fn visit_amend_provision(&mut self, node: &'ast AmendProvision) {
+    visit_amend_provision(self, node)
+  }
+  fn visit_new_provision(&mut self, node: &'ast NewProvision) {
+    visit_new_provision(self, node)
+  }
+  fn visit_part(&mut self, node: &'ast Part) {
+    visit_part(self, node)
+  }
+  fn visit_part_contents(&mut self, node: &'ast PartContents) {
+    visit_part_contents(self, node)
+  }
+  fn visit_chapter(&mut self, node: &'ast Chapter) {
+    visit_chapter(self, node)
+  }
+  fn visit_chapter_contents(&mut self, node: &'ast ChapterContents) {
+    visit_chapter_contents(self, node)
+  }
+  fn visit_section(&mut self, node: &'ast Section) {
+    visit_section(self, node)
+  }
+  fn visit_section_contents(&mut self, node: &'ast SectionContents) {
+    visit_section_contents(self, node)
+  }
+  fn visit_subsection(&mut self, node: &'ast Subsection) {
+    visit_subsection(self, node)
+  }
+  fn visit_subsection_contents(&mut self, node: &'ast SubsectionContents) {
+    visit_subsection_contents(self, node)
+  }
+  fn visit_division(&mut self, node: &'ast Division) {
+    visit_division(self, node)
+  }
+  fn visit_article(&mut self, node: &'ast Article) {
+    visit_article(self, node)
+  }
+  fn visit_paragraph(&mut self, node: &'ast Paragraph) {
+    visit_paragraph(self, node)
+  }
+  fn visit_item(&mut self, node: &'ast Item) {
+    visit_item(self, node)
+  }
+  fn visit_subitem1(&mut self, node: &'ast Subitem1) {
+    visit_subitem1(self, node)
+  }
+  fn visit_subitem2(&mut self, node: &'ast Subitem2) {
+    visit_subitem2(self, node)
+  }
+  fn visit_subitem3(&mut self, node: &'ast Subitem3) {
+    visit_subitem3(self, node)
+  }
+  fn visit_subitem4(&mut self, node: &'ast Subitem4) {
+    visit_subitem4(self, node)
+  }
+  fn visit_subitem5(&mut self, node: &'ast Subitem5) {
+    visit_subitem5(self, node)
+  }
+  fn visit_subitem6(&mut self, node: &'ast Subitem6) {
+    visit_subitem6(self, node)
+  }
+  fn visit_subitem7(&mut self, node: &'ast Subitem7) {
+    visit_subitem7(self, node)
+  }
+  fn visit_subitem8(&mut self, node: &'ast Subitem8) {
+    visit_subitem8(self, node)
+  }
+  fn visit_subitem9(&mut self, node: &'ast Subitem9) {
+    visit_subitem9(self, node)
+  }
+  fn visit_subitem10(&mut self, node: &'ast Subitem10) {
+    visit_subitem10(self, node)
+  }
+  fn visit_suppl_provision(&mut self, node: &'ast SupplProvision) {
+    visit_suppl_provision(self, node)
+  }
+  fn visit_suppl_provision_children_element(&mut self, node: &'ast SupplProvisionChildrenElement) {
+    visit_suppl_provision_children_element(self, node)
+  }
+  fn visit_suppl_provision_appdx_table(&mut self, node: &'ast SupplProvisionAppdxTable) {
+    visit_suppl_provision_appdx_table(self, node)
+  }
+  fn visit_suppl_provision_appdx_style(&mut self, node: &'ast SupplProvisionAppdxStyle) {
+    visit_suppl_provision_appdx_style(self, node)
+  }
+  fn visit_suppl_provision_appdx(&mut self, node: &'ast SupplProvisionAppdx) {
+    visit_suppl_provision_appdx(self, node)
+  }
+  fn visit_toc(&mut self, node: &'ast TOC) {
+    visit_toc(self, node)
+  }
+  fn visit_toc_main_contents(&mut self, node: &'ast TOCMainContents) {
+    visit_toc_main_contents(self, node)
+  }
+  fn visit_toc_part(&mut self, node: &'ast TOCPart) {
+    visit_toc_part(self, node)
+  }
+  fn visit_toc_chapter(&mut self, node: &'ast TOCChapter) {
+    visit_toc_chapter(self, node)
+  }
+  fn visit_toc_section(&mut self, node: &'ast TOCSection) {
+    visit_toc_section(self, node)
+  }
+  fn visit_toc_section_contents(&mut self, node: &'ast TOCSectionContents) {
+    visit_toc_section_contents(self, node)
+  }
+  fn visit_toc_subsection(&mut self, node: &'ast TOCSubsection) {
+    visit_toc_subsection(self, node)
+  }
+  fn visit_toc_division(&mut self, node: &'ast TOCDivision) {
+    visit_toc_division(self, node)
+  }
+  fn visit_toc_article(&mut self, node: &'ast TOCArticle) {
+    visit_toc_article(self, node)
+  }
+  fn visit_toc_suppl_provision(&mut self, node: &'ast TOCSupplProvision) {
+    visit_toc_suppl_provision(self, node)
+  }
+  fn visit_toc_suppl_provision_contents(&mut self, node: &'ast TOCSupplProvisionContents) {
+    visit_toc_suppl_provision_contents(self, node)
+  }
+  fn visit_appdx_table(&mut self, node: &'ast AppdxTable) {
+    visit_appdx_table(self, node)
+  }
+  fn visit_appdx_table_contents(&mut self, node: &'ast AppdxTableContents) {
+    visit_appdx_table_contents(self, node)
+  }
+  fn visit_appdx_note(&mut self, node: &'ast AppdxNote) {
+    visit_appdx_note(self, node)
+  }
+  fn visit_appdx_note_contents(&mut self, node: &'ast AppdxNoteContents) {
+    visit_appdx_note_contents(self, node)
+  }
+  fn visit_appdx_style(&mut self, node: &'ast AppdxStyle) {
+    visit_appdx_style(self, node)
+  }
+  fn visit_appdx_format(&mut self, node: &'ast AppdxFormat) {
+    visit_appdx_format(self, node)
+  }
+  fn visit_appdx(&mut self, node: &'ast Appdx) {
+    visit_appdx(self, node)
+  }
+  fn visit_appdx_fig(&mut self, node: &'ast AppdxFig) {
+    visit_appdx_fig(self, node)
+  }
+  fn visit_appdx_fig_contents(&mut self, node: &'ast AppdxFigContents) {
+    visit_appdx_fig_contents(self, node)
+  }
+  fn visit_struct(&mut self, node: &'ast Struct) {
+    visit_struct(self, node)
+  }
+  fn visit_quote_struct(&mut self, node: &'ast QuoteStruct) {
+    visit_quote_struct(self, node)
+  }
+  fn visit_note_struct(&mut self, node: &'ast NoteStruct) {
+    visit_note_struct(self, node)
+  }
+  fn visit_style_struct(&mut self, node: &'ast StyleStruct) {
+    visit_style_struct(self, node)
+  }
+  fn visit_format_struct(&mut self, node: &'ast FormatStruct) {
+    visit_format_struct(self, node)
+  }
+  fn visit_fig_struct(&mut self, node: &'ast FigStruct) {
+    visit_fig_struct(self, node)
+  }
+  fn visit_table_struct(&mut self, node: &'ast TableStruct) {
+    visit_table_struct(self, node)
+  }
+  fn visit_contents(&mut self, node: &'ast Contents) {
+    visit_contents(self, node)
+  }
+  fn visit_contents_element(&mut self, node: &'ast ContentsElement) {
+    visit_contents_element(self, node)
+  }
+  fn visit_style(&mut self, node: &'ast Style) {
+    visit_style(self, node)
+  }
+  fn visit_note(&mut self, node: &'ast Note) {
+    visit_note(self, node)
+  }
+  fn visit_format(&mut self, node: &'ast Format) {
+    visit_format(self, node)
+  }
+  fn visit_arith_formula(&mut self, node: &'ast ArithFormula) {
+    visit_arith_formula(self, node)
+  }
+  fn visit_table(&mut self, node: &'ast Table) {
+    visit_table(self, node)
+  }
+  fn visit_table_header_row(&mut self, node: &'ast TableHeaderRow) {
+    visit_table_header_row(self, node)
+  }
+  fn visit_table_row(&mut self, node: &'ast TableRow) {
+    visit_table_row(self, node)
+  }
+  fn visit_table_column(&mut self, node: &'ast TableColumn) {
+    visit_table_column(self, node)
+  }
+  fn visit_table_column_contents(&mut self, node: &'ast TableColumnContents) {
+    visit_table_column_contents(self, node)
+  }
+  fn visit_class(&mut self, node: &'ast Class) {
+    visit_class(self, node)
+  }
+  fn visit_sentence_or_column_or_table(&mut self, node: &'ast SentenceOrColumnOrTable) {
+    visit_sentence_or_column_or_table(self, node)
+  }
+  fn visit_caption(&mut self, node: &'ast Caption) {
+    visit_caption(self, node)
+  }
+  fn visit_column(&mut self, node: &'ast Column) {
+    visit_column(self, node)
+  }
+  fn visit_sentence(&mut self, node: &'ast Sentence) {
+    visit_sentence(self, node)
+  }
+  fn visit_sentence_element(&mut self, node: &'ast SentenceElement) {
+    visit_sentence_element(self, node)
+  }
+  fn visit_text(&mut self, node: &'ast Text) {
+    visit_text(self, node)
+  }
+  fn visit_text_with_writing_mode(&mut self, node: &'ast TextWithWritingMode) {
+    visit_text_with_writing_mode(self, node)
+  }
+  fn visit_text_element(&mut self, node: &'ast TextElement) {
+    visit_text_element(self, node)
+  }
+  fn visit_ruby(&mut self, node: &'ast Ruby) {
+    visit_ruby(self, node)
+  }
+  fn visit_sup(&mut self, node: &'ast Sup) {
+    visit_sup(self, node)
+  }
+  fn visit_sub(&mut self, node: &'ast Sub) {
+    visit_sub(self, node)
+  }
+  fn visit_list(&mut self, node: &'ast List) {
+    visit_list(self, node)
+  }
+  fn visit_list_sentence(&mut self, node: &'ast ListSentence) {
+    visit_list_sentence(self, node)
+  }
+  fn visit_sublist1(&mut self, node: &'ast Sublist1) {
+    visit_sublist1(self, node)
+  }
+  fn visit_sublist2(&mut self, node: &'ast Sublist2) {
+    visit_sublist2(self, node)
+  }
+  fn visit_sublist3(&mut self, node: &'ast Sublist3) {
+    visit_sublist3(self, node)
+  }
+  fn visit_remarks(&mut self, node: &'ast Remarks) {
+    visit_remarks(self, node)
+  }
+  fn visit_remarks_label(&mut self, node: &'ast RemarksLabel) {
+    visit_remarks_label(self, node)
+  }
+  fn visit_remarks_contents(&mut self, node: &'ast RemarksContents) {
+    visit_remarks_contents(self, node)
+  }
+  fn visit_fig(&mut self, node: &'ast Fig) {
+    visit_fig(self, node)
+  }
+  fn visit_line(&mut self, node: &'ast Line) {
+    visit_line(self, node)
+  }
+  fn visit_line_contents(&mut self, node: &'ast LineContents) {
+    visit_line_contents(self, node)
+  }
+  /// 本文の文字列
+  fn visit_string(&mut self, _node: &'ast str) {}
+}
+
+pub fn visit_law<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Law) {
+  v.visit_law_body(&node.law_body);
+}
+
+pub fn visit_law_body<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast LawBody) {
+  if let Some(x) = &node.law_title {
+    v.visit_law_title(x);
+  }
+  for x in node.enact_statement.iter() {
+    v.visit_text(x);
+  }
+  if let Some(x) = &node.toc {
+    v.visit_toc(x);
+  }
+  if let Some(x) = &node.preamble {
+    v.visit_preamble(x);
+  }
+  v.visit_main_provision(&node.main_provision);
+  for x in node.suppl_provision.iter() {
+    v.visit_suppl_provision(x);
+  }
+  for x in node.appdx_table.iter() {
+    v.visit_appdx_table(x);
+  }
+  for x in node.appdx_note.iter() {
+    v.visit_appdx_note(x);
+  }
+  for x in node.appdx_style.iter() {
+    v.visit_appdx_style(x);
+  }
+  for x in node.appdx.iter() {
+    v.visit_appdx(x);
+  }
+  for x in node.appdx_fig.iter() {
+    v.visit_appdx_fig(x);
+  }
+  for x in node.appdx_format.iter() {
+    v.visit_appdx_format(x);
+  }
+}
+
+pub fn visit_law_title<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast LawTitle) {
+  v.visit_text(&node.text);
+}
+
+pub fn visit_preamble<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Preamble) {
+  for x in node.children.iter() {
+    v.visit_paragraph(x);
+  }
+}
+
+pub fn visit_main_provision<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast MainProvision) {
+  for x in node.children.iter() {
+    v.visit_main_provision_contents(x);
+  }
+}
+
+pub fn visit_main_provision_contents<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast MainProvisionContents,
+) {
+  match node {
+    MainProvisionContents::Part(x) => v.visit_part(x),
+    MainProvisionContents::Chapter(x) => v.visit_chapter(x),
+    MainProvisionContents::Section(x) => v.visit_section(x),
+    MainProvisionContents::Article(x) => v.visit_article(x),
+    MainProvisionContents::Paragraph(x) => v.visit_paragraph(x),
+  }
+}
+
+pub fn visit_amend_provision<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast AmendProvision) {
+  if let Some(x) = &node.sentence {
+    v.visit_sentence(x);
+  }
+  for x in node.new_provision.iter() {
+    v.visit_new_provision(x);
+  }
+}
+
+pub fn visit_new_provision<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast NewProvision) {
+  match node {
+    NewProvision::LawTitle(x) => v.visit_law_title(x),
+    NewProvision::Preamble(x) => v.visit_preamble(x),
+    NewProvision::TOC(x) => v.visit_toc(x),
+    NewProvision::Part(x) => v.visit_part(x),
+    NewProvision::PartTitle(x) => v.visit_text(x),
+    NewProvision::Chapter(x) => v.visit_chapter(x),
+    NewProvision::ChapterTitle(x) => v.visit_text(x),
+    NewProvision::Section(x) => v.visit_section(x),
+    NewProvision::SectionTitle(x) => v.visit_text(x),
+    NewProvision::Subsection(x) => v.visit_subsection(x),
+    NewProvision::SubsectionTitle(x) => v.visit_text(x),
+    NewProvision::Division(x) => v.visit_division(x),
+    NewProvision::DivisionTitle(x) => v.visit_text(x),
+    NewProvision::Article(x) => v.visit_article(x),
+    NewProvision::SupplNote(x) => v.visit_text(x),
+    NewProvision::Paragraph(x) => v.visit_paragraph(x),
+    NewProvision::Item(x) => v.visit_item(x),
+    NewProvision::Subitem1(x) => v.visit_subitem1(x),
+    NewProvision::Subitem2(x) => v.visit_subitem2(x),
+    NewProvision::Subitem3(x) => v.visit_subitem3(x),
+    NewProvision::Subitem4(x) => v.visit_subitem4(x),
+    NewProvision::Subitem5(x) => v.visit_subitem5(x),
+    NewProvision::Subitem6(x) => v.visit_subitem6(x),
+    NewProvision::Subitem7(x) => v.visit_subitem7(x),
+    NewProvision::Subitem8(x) => v.visit_subitem8(x),
+    NewProvision::Subitem9(x) => v.visit_subitem9(x),
+    NewProvision::Subitem10(x) => v.visit_subitem10(x),
+    NewProvision::List(x) => v.visit_list(x),
+    NewProvision::Sentence(x) => v.visit_sentence(x),
+    NewProvision::AmendProvision(x) => v.visit_amend_provision(x),
+    NewProvision::AppdxTable(x) => v.visit_appdx_table(x),
+    NewProvision::AppdxNote(x) => v.visit_appdx_note(x),
+    NewProvision::AppdxStyle(x) => v.visit_appdx_style(x),
+    NewProvision::Appdx(x) => v.visit_appdx(x),
+    NewProvision::AppdxFig(x) => v.visit_appdx_fig(x),
+    NewProvision::AppdxFormat(x) => v.visit_appdx_format(x),
+    NewProvision::SupplProvisionAppdxStyle(x) => v.visit_suppl_provision_appdx_style(x),
+    NewProvision::SupplProvisionAppdxTable(x) => v.visit_suppl_provision_appdx_table(x),
+    NewProvision::SupplProvisionAppdx(x) => v.visit_suppl_provision_appdx(x),
+    NewProvision::TableStruct(x) => v.visit_table_struct(x),
+    NewProvision::TableRow(x) => v.visit_table_row(x),
+    NewProvision::TableColumn(x) => v.visit_table_column(x),
+    NewProvision::FigStruct(x) => v.visit_fig_struct(x),
+    NewProvision::NoteStruct(x) => v.visit_note_struct(x),
+    NewProvision::StyleStruct(x) => v.visit_style_struct(x),
+    NewProvision::FormatStruct(x) => v.visit_format_struct(x),
+    NewProvision::Remarks(x) => v.visit_remarks(x),
+    NewProvision::LawBody(x) => v.visit_law_body(x),
+  }
+}
+
+pub fn visit_part<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Part) {
+  v.visit_text(&node.part_title);
+  for x in node.children.iter() {
+    v.visit_part_contents(x);
+  }
+}
+
+pub fn visit_part_contents<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PartContents) {
+  match node {
+    PartContents::Article(x) => v.visit_article(x),
+    PartContents::Chapter(x) => v.visit_chapter(x),
+  }
+}
+
+pub fn visit_chapter<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Chapter) {
+  v.visit_text(&node.chapter_title);
+  for x in node.children.iter() {
+    v.visit_chapter_contents(x);
+  }
+}
+
+pub fn visit_chapter_contents<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast ChapterContents,
+) {
+  match node {
+    ChapterContents::Article(x) => v.visit_article(x),
+    ChapterContents::Section(x) => v.visit_section(x),
+  }
+}
+
+pub fn visit_section<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Section) {
+  v.visit_text(&node.section_title);
+  for x in node.children.iter() {
+    v.visit_section_contents(x);
+  }
+}
+
+pub fn visit_section_contents<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast SectionContents,
+) {
+  match node {
+    SectionContents::Article(x) => v.visit_article(x),
+    SectionContents::Subsection(x) => v.visit_subsection(x),
+    SectionContents::Division(x) => v.visit_division(x),
+  }
+}
+
+pub fn visit_subsection<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Subsection) {
+  v.visit_text(&node.subsection_title);
+  for x in node.children.iter() {
+    v.visit_subsection_contents(x);
+  }
+}
+
+pub fn visit_subsection_contents<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast SubsectionContents,
+) {
+  match node {
+    SubsectionContents::Article(x) => v.visit_article(x),
+    SubsectionContents::Division(x) => v.visit_division(x),
+  }
+}
+
+pub fn visit_division<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Division) {
+  v.visit_text(&node.division_title);
+  for x in node.children.iter() {
+    v.visit_article(x);
+  }
+}
+
+pub fn visit_article<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Article) {
+  if let Some(x) = &node.caption {
+    v.visit_caption(x);
+  }
+  v.visit_text(&node.title);
+  for x in node.paragraph.iter() {
+    v.visit_paragraph(x);
+  }
+  if let Some(x) = &node.suppl_note {
+    v.visit_text(x);
+  }
+}
+
+pub fn visit_paragraph<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Paragraph) {
+  if let Some(x) = &node.caption {
+    v.visit_caption(x);
+  }
+  v.visit_text(&node.paragraph_num);
+  for x in node.sentence.iter() {
+    v.visit_sentence(x);
+  }
+  for x in node.amend_provision.iter() {
+    v.visit_amend_provision(x);
+  }
+  for x in node.class.iter() {
+    v.visit_class(x);
+  }
+  for x in node.struct_list.iter() {
+    v.visit_struct(x);
+  }
+  for x in node.children.iter() {
+    v.visit_item(x);
+  }
+}
+
+pub fn visit_item<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Item) {
+  if let Some(x) = &node.title {
+    v.visit_text(x);
+  }
+  v.visit_sentence_or_column_or_table(&node.sentence);
+  for x in node.children.iter() {
+    v.visit_subitem1(x);
+  }
+  for x in node.struct_list.iter() {
+    v.visit_struct(x);
+  }
+}
+
+pub fn visit_subitem1<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Subitem1) {
+  if let Some(x) = &node.title {
+    v.visit_text(x);
+  }
+  v.visit_sentence_or_column_or_table(&node.sentence);
+  for x in node.children.iter() {
+    v.visit_subitem2(x);
+  }
+  for x in node.struct_list.iter() {
+    v.visit_struct(x);
+  }
+}
+
+pub fn visit_subitem2<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Subitem2) {
+  if let Some(x) = &node.title {
+    v.visit_text(x);
+  }
+  v.visit_sentence_or_column_or_table(&node.sentence);
+  for x in node.children.iter() {
+    v.visit_subitem3(x);
+  }
+  for x in node.struct_list.iter() {
+    v.visit_struct(x);
+  }
+}
+
+pub fn visit_subitem3<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Subitem3) {
+  if let Some(x) = &node.title {
+    v.visit_text(x);
+  }
+  v.visit_sentence_or_column_or_table(&node.sentence);
+  for x in node.children.iter() {
+    v.visit_subitem4(x);
+  }
+  for x in node.struct_list.iter() {
+    v.visit_struct(x);
+  }
+}
+
+pub fn visit_subitem4<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Subitem4) {
+  if let Some(x) = &node.title {
+    v.visit_text(x);
+  }
+  v.visit_sentence_or_column_or_table(&node.sentence);
+  for x in node.children.iter() {
+    v.visit_subitem5(x);
+  }
+  for x in node.struct_list.iter() {
+    v.visit_struct(x);
+  }
+}
+
+pub fn visit_subitem5<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Subitem5) {
+  if let Some(x) = &node.title {
+    v.visit_text(x);
+  }
+  v.visit_sentence_or_column_or_table(&node.sentence);
+  for x in node.children.iter() {
+    v.visit_subitem6(x);
+  }
+  for x in node.struct_list.iter() {
+    v.visit_struct(x);
+  }
+}
+
+pub fn visit_subitem6<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Subitem6) {
+  if let Some(x) = &node.title {
+    v.visit_text(x);
+  }
+  v.visit_sentence_or_column_or_table(&node.sentence);
+  for x in node.children.iter() {
+    v.visit_subitem7(x);
+  }
+  for x in node.struct_list.iter() {
+    v.visit_struct(x);
+  }
+}
+
+pub fn visit_subitem7<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Subitem7) {
+  if let Some(x) = &node.title {
+    v.visit_text(x);
+  }
+  v.visit_sentence_or_column_or_table(&node.sentence);
+  for x in node.children.iter() {
+    v.visit_subitem8(x);
+  }
+  for x in node.struct_list.iter() {
+    v.visit_struct(x);
+  }
+}
+
+pub fn visit_subitem8<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Subitem8) {
+  if let Some(x) = &node.title {
+    v.visit_text(x);
+  }
+  v.visit_sentence_or_column_or_table(&node.sentence);
+  for x in node.children.iter() {
+    v.visit_subitem9(x);
+  }
+  for x in node.struct_list.iter() {
+    v.visit_struct(x);
+  }
+}
+
+pub fn visit_subitem9<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Subitem9) {
+  if let Some(x) = &node.title {
+    v.visit_text(x);
+  }
+  v.visit_sentence_or_column_or_table(&node.sentence);
+  for x in node.children.iter() {
+    v.visit_subitem10(x);
+  }
+  for x in node.struct_list.iter() {
+    v.visit_struct(x);
+  }
+}
+
+pub fn visit_subitem10<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Subitem10) {
+  if let Some(x) = &node.title {
+    v.visit_text(x);
+  }
+  v.visit_sentence_or_column_or_table(&node.sentence);
+  for x in node.struct_list.iter() {
+    v.visit_struct(x);
+  }
+}
+
+pub fn visit_suppl_provision<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast SupplProvision) {
+  v.visit_text(&node.label);
+  for x in node.children.iter() {
+    v.visit_suppl_provision_children_element(x);
+  }
+}
+
+pub fn visit_suppl_provision_children_element<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast SupplProvisionChildrenElement,
+) {
+  match node {
+    SupplProvisionChildrenElement::Chapter(x) => v.visit_chapter(x),
+    SupplProvisionChildrenElement::Article(x) => v.visit_article(x),
+    SupplProvisionChildrenElement::Paragraph(x) => v.visit_paragraph(x),
+    SupplProvisionChildrenElement::SupplProvisionAppdxTable(x) => {
+      v.visit_suppl_provision_appdx_table(x)
+    }
+    SupplProvisionChildrenElement::SupplProvisionAppdxStyle(x) => {
+      v.visit_suppl_provision_appdx_style(x)
+    }
+    SupplProvisionChildrenElement::SupplProvisionAppdx(x) => v.visit_suppl_provision_appdx(x),
+  }
+}
+
+pub fn visit_suppl_provision_appdx_table<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast SupplProvisionAppdxTable,
+) {
+  v.visit_text_with_writing_mode(&node.title);
+  if let Some(x) = &node.related_article_num {
+    v.visit_text(x);
+  }
+  for x in node.table_struct.iter() {
+    v.visit_table_struct(x);
+  }
+}
+
+pub fn visit_suppl_provision_appdx_style<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast SupplProvisionAppdxStyle,
+) {
+  v.visit_text_with_writing_mode(&node.title);
+  if let Some(x) = &node.related_article_num {
+    v.visit_text(x);
+  }
+  for x in node.style_struct.iter() {
+    v.visit_style_struct(x);
+  }
+}
+
+pub fn visit_suppl_provision_appdx<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast SupplProvisionAppdx,
+) {
+  if let Some(x) = &node.arith_formula_num {
+    v.visit_text(x);
+  }
+  if let Some(x) = &node.related_article_num {
+    v.visit_text(x);
+  }
+  for x in node.arith_formula.iter() {
+    v.visit_arith_formula(x);
+  }
+}
+
+pub fn visit_toc<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TOC) {
+  if let Some(x) = &node.toc_label {
+    v.visit_text(x);
+  }
+  if let Some(x) = &node.toc_preamble_label {
+    v.visit_text(x);
+  }
+  for x in node.toc_main_contents.iter() {
+    v.visit_toc_main_contents(x);
+  }
+  if let Some(x) = &node.toc_suppl_provision {
+    v.visit_toc_suppl_provision(x);
+  }
+  for x in node.toc_appdx_table_label.iter() {
+    v.visit_text(x);
+  }
+}
+
+pub fn visit_toc_main_contents<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast TOCMainContents,
+) {
+  match node {
+    TOCMainContents::TOCPart(x) => v.visit_toc_part(x),
+    TOCMainContents::TOCChapter(x) => v.visit_toc_chapter(x),
+    TOCMainContents::TOCSection(x) => v.visit_toc_section(x),
+    TOCMainContents::TOCArticle(x) => v.visit_toc_article(x),
+  }
+}
+
+pub fn visit_toc_part<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TOCPart) {
+  v.visit_text(&node.part_title);
+  if let Some(x) = &node.article_range {
+    v.visit_text(x);
+  }
+  for x in node.children.iter() {
+    v.visit_toc_chapter(x);
+  }
+}
+
+pub fn visit_toc_chapter<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TOCChapter) {
+  v.visit_text(&node.chapter_title);
+  if let Some(x) = &node.article_range {
+    v.visit_text(x);
+  }
+  for x in node.children.iter() {
+    v.visit_toc_section(x);
+  }
+}
+
+pub fn visit_toc_section<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TOCSection) {
+  v.visit_text(&node.section_title);
+  if let Some(x) = &node.article_range {
+    v.visit_text(x);
+  }
+  for x in node.children.iter() {
+    v.visit_toc_section_contents(x);
+  }
+}
+
+pub fn visit_toc_section_contents<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast TOCSectionContents,
+) {
+  match node {
+    TOCSectionContents::TOCSubsection(x) => v.visit_toc_subsection(x),
+    TOCSectionContents::TOCDivision(x) => v.visit_toc_division(x),
+  }
+}
+
+pub fn visit_toc_subsection<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TOCSubsection) {
+  v.visit_text(&node.subsection_title);
+  if let Some(x) = &node.article_range {
+    v.visit_text(x);
+  }
+  for x in node.children.iter() {
+    v.visit_toc_division(x);
+  }
+}
+
+pub fn visit_toc_division<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TOCDivision) {
+  v.visit_text(&node.division_title);
+  if let Some(x) = &node.article_range {
+    v.visit_text(x);
+  }
+}
+
+pub fn visit_toc_article<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TOCArticle) {
+  v.visit_text(&node.article_title);
+  v.visit_caption(&node.article_caption);
+}
+
+pub fn visit_toc_suppl_provision<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast TOCSupplProvision,
+) {
+  v.visit_text(&node.suppl_provision_label);
+  if let Some(x) = &node.article_range {
+    v.visit_text(x);
+  }
+  for x in node.children.iter() {
+    v.visit_toc_suppl_provision_contents(x);
+  }
+}
+
+pub fn visit_toc_suppl_provision_contents<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast TOCSupplProvisionContents,
+) {
+  match node {
+    TOCSupplProvisionContents::TOCArticle(x) => v.visit_toc_article(x),
+    TOCSupplProvisionContents::TOCChapter(x) => v.visit_toc_chapter(x),
+  }
+}
+
+pub fn visit_appdx_table<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast AppdxTable) {
+  if let Some(x) = &node.title {
+    v.visit_text_with_writing_mode(x);
+  }
+  if let Some(x) = &node.related_article_num {
+    v.visit_text(x);
+  }
+  for x in node.children.iter() {
+    v.visit_appdx_table_contents(x);
+  }
+  if let Some(x) = &node.remarks {
+    v.visit_remarks(x);
+  }
+}
+
+pub fn visit_appdx_table_contents<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast AppdxTableContents,
+) {
+  match node {
+    AppdxTableContents::TableStruct(x) => v.visit_table_struct(x),
+    AppdxTableContents::Item(x) => v.visit_item(x),
+  }
+}
+
+pub fn visit_appdx_note<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast AppdxNote) {
+  if let Some(x) = &node.title {
+    v.visit_text_with_writing_mode(x);
+  }
+  if let Some(x) = &node.related_article_num {
+    v.visit_text(x);
+  }
+  for x in node.children.iter() {
+    v.visit_appdx_note_contents(x);
+  }
+  if let Some(x) = &node.remarks {
+    v.visit_remarks(x);
+  }
+}
+
+pub fn visit_appdx_note_contents<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast AppdxNoteContents,
+) {
+  match node {
+    AppdxNoteContents::NoteStruct(x) => v.visit_note_struct(x),
+    AppdxNoteContents::FigStruct(x) => v.visit_fig_struct(x),
+    AppdxNoteContents::TableStruct(x) => v.visit_table_struct(x),
+  }
+}
+
+pub fn visit_appdx_style<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast AppdxStyle) {
+  if let Some(x) = &node.title {
+    v.visit_text_with_writing_mode(x);
+  }
+  if let Some(x) = &node.related_article_num {
+    v.visit_text(x);
+  }
+  for x in node.children.iter() {
+    v.visit_style_struct(x);
+  }
+  if let Some(x) = &node.remarks {
+    v.visit_remarks(x);
+  }
+}
+
+pub fn visit_appdx_format<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast AppdxFormat) {
+  if let Some(x) = &node.title {
+    v.visit_text_with_writing_mode(x);
+  }
+  if let Some(x) = &node.related_article_num {
+    v.visit_text(x);
+  }
+  for x in node.children.iter() {
+    v.visit_format_struct(x);
+  }
+  if let Some(x) = &node.remarks {
+    v.visit_remarks(x);
+  }
+}
+
+pub fn visit_appdx<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Appdx) {
+  if let Some(x) = &node.arith_formula_num {
+    v.visit_text(x);
+  }
+  if let Some(x) = &node.related_article_num {
+    v.visit_text(x);
+  }
+  for x in node.arith_formula.iter() {
+    v.visit_arith_formula(x);
+  }
+  if let Some(x) = &node.remarks {
+    v.visit_remarks(x);
+  }
+}
+
+pub fn visit_appdx_fig<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast AppdxFig) {
+  if let Some(x) = &node.title {
+    v.visit_text_with_writing_mode(x);
+  }
+  if let Some(x) = &node.related_article_num {
+    v.visit_text(x);
+  }
+  for x in node.children.iter() {
+    v.visit_appdx_fig_contents(x);
+  }
+}
+
+pub fn visit_appdx_fig_contents<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast AppdxFigContents,
+) {
+  match node {
+    AppdxFigContents::FigStruct(x) => v.visit_fig_struct(x),
+    AppdxFigContents::TableStruct(x) => v.visit_table_struct(x),
+  }
+}
+
+pub fn visit_struct<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Struct) {
+  match node {
+    Struct::TableStruct(x) => v.visit_table_struct(x),
+    Struct::FigStruct(x) => v.visit_fig_struct(x),
+    Struct::StyleStruct(x) => v.visit_style_struct(x),
+    Struct::List(x) => v.visit_list(x),
+  }
+}
+
+pub fn visit_quote_struct<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast QuoteStruct) {
+  v.visit_contents(&node.contentes);
+}
+
+pub fn visit_note_struct<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast NoteStruct) {
+  if let Some(x) = &node.title {
+    v.visit_text(x);
+  }
+  for x in node.title_remarks.iter() {
+    v.visit_remarks(x);
+  }
+  v.visit_note(&node.note);
+  for x in node.note_remarks.iter() {
+    v.visit_remarks(x);
+  }
+}
+
+pub fn visit_style_struct<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast StyleStruct) {
+  if let Some(x) = &node.title {
+    v.visit_text(x);
+  }
+  for x in node.title_remarks.iter() {
+    v.visit_remarks(x);
+  }
+  v.visit_style(&node.style);
+  for x in node.style_remarks.iter() {
+    v.visit_remarks(x);
+  }
+}
+
+pub fn visit_format_struct<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast FormatStruct) {
+  if let Some(x) = &node.title {
+    v.visit_text(x);
+  }
+  for x in node.title_remarks.iter() {
+    v.visit_remarks(x);
+  }
+  v.visit_format(&node.format);
+  for x in node.format_remarks.iter() {
+    v.visit_remarks(x);
+  }
+}
+
+pub fn visit_fig_struct<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast FigStruct) {
+  if let Some(x) = &node.title {
+    v.visit_text(x);
+  }
+  for x in node.title_remarks.iter() {
+    v.visit_remarks(x);
+  }
+  v.visit_fig(&node.fig);
+  for x in node.fig_remarks.iter() {
+    v.visit_remarks(x);
+  }
+}
+
+pub fn visit_table_struct<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TableStruct) {
+  if let Some(x) = &node.title {
+    v.visit_text_with_writing_mode(x);
+  }
+  for x in node.title_remarks.iter() {
+    v.visit_remarks(x);
+  }
+  v.visit_table(&node.table);
+  for x in node.table_remarks.iter() {
+    v.visit_remarks(x);
+  }
+}
+
+pub fn visit_contents<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Contents) {
+  for x in node.contents.iter() {
+    v.visit_contents_element(x);
+  }
+}
+
+pub fn visit_contents_element<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast ContentsElement,
+) {
+  match node {
+    ContentsElement::Table(x) => v.visit_table(x),
+    ContentsElement::TableStruct(x) => v.visit_table_struct(x),
+    ContentsElement::Fig(x) => v.visit_fig(x),
+    ContentsElement::FigStruct(x) => v.visit_fig_struct(x),
+    ContentsElement::QuoteStruct(x) => v.visit_quote_struct(x),
+    ContentsElement::NoteStruct(x) => v.visit_note_struct(x),
+    ContentsElement::StyleStruct(x) => v.visit_style_struct(x),
+    ContentsElement::AppdxTable(x) => v.visit_appdx_table(x),
+    ContentsElement::Ruby(x) => v.visit_ruby(x),
+    ContentsElement::Line(x) => v.visit_line(x),
+    ContentsElement::Sup(x) => v.visit_sup(x),
+    ContentsElement::Sub(x) => v.visit_sub(x),
+    ContentsElement::String(x) => v.visit_string(x),
+    ContentsElement::Paragraph(x) => v.visit_paragraph(x),
+    ContentsElement::Item(x) => v.visit_item(x),
+    ContentsElement::Subitem1(x) => v.visit_subitem1(x),
+    ContentsElement::Subitem2(x) => v.visit_subitem2(x),
+    ContentsElement::Subitem3(x) => v.visit_subitem3(x),
+    ContentsElement::Subitem4(x) => v.visit_subitem4(x),
+    ContentsElement::Subitem5(x) => v.visit_subitem5(x),
+    ContentsElement::Subitem6(x) => v.visit_subitem6(x),
+    ContentsElement::Subitem7(x) => v.visit_subitem7(x),
+    ContentsElement::Subitem8(x) => v.visit_subitem8(x),
+    ContentsElement::Subitem9(x) => v.visit_subitem9(x),
+    ContentsElement::Subitem10(x) => v.visit_subitem10(x),
+    ContentsElement::List(x) => v.visit_list(x),
+    ContentsElement::Sentence(x) => v.visit_sentence(x),
+    ContentsElement::ArithFormula(x) => v.visit_arith_formula(x),
+    ContentsElement::Remarks(x) => v.visit_remarks(x),
+    ContentsElement::TOC(x) => v.visit_toc(x),
+    ContentsElement::TOCSection(x) => v.visit_toc_section(x),
+    ContentsElement::TableRow(x) => v.visit_table_row(x),
+  }
+}
+
+pub fn visit_style<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Style) {
+  v.visit_contents(&node.contentes);
+}
+
+pub fn visit_note<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Note) {
+  v.visit_contents(&node.contentes);
+}
+
+pub fn visit_format<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Format) {
+  v.visit_contents(&node.contentes);
+}
+
+pub fn visit_arith_formula<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ArithFormula) {
+  v.visit_contents(&node.contentes);
+}
+
+pub fn visit_table<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Table) {
+  for x in node.table_header_row.iter() {
+    v.visit_table_header_row(x);
+  }
+  for x in node.table_row.iter() {
+    v.visit_table_row(x);
+  }
+}
+
+pub fn visit_table_header_row<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast TableHeaderRow,
+) {
+  for x in node.columns.iter() {
+    v.visit_text(x);
+  }
+}
+
+pub fn visit_table_row<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TableRow) {
+  for x in node.columns.iter() {
+    v.visit_table_column(x);
+  }
+}
+
+pub fn visit_table_column<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TableColumn) {
+  for x in node.contents.iter() {
+    v.visit_table_column_contents(x);
+  }
+}
+
+pub fn visit_table_column_contents<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast TableColumnContents,
+) {
+  match node {
+    TableColumnContents::Part(x) => v.visit_part(x),
+    TableColumnContents::Chapter(x) => v.visit_chapter(x),
+    TableColumnContents::Section(x) => v.visit_section(x),
+    TableColumnContents::Subsection(x) => v.visit_subsection(x),
+    TableColumnContents::Division(x) => v.visit_division(x),
+    TableColumnContents::Article(x) => v.visit_article(x),
+    TableColumnContents::Paragraph(x) => v.visit_paragraph(x),
+    TableColumnContents::Item(x) => v.visit_item(x),
+    TableColumnContents::Subitem1(x) => v.visit_subitem1(x),
+    TableColumnContents::Subitem2(x) => v.visit_subitem2(x),
+    TableColumnContents::Subitem3(x) => v.visit_subitem3(x),
+    TableColumnContents::Subitem4(x) => v.visit_subitem4(x),
+    TableColumnContents::Subitem5(x) => v.visit_subitem5(x),
+    TableColumnContents::Subitem6(x) => v.visit_subitem6(x),
+    TableColumnContents::Subitem7(x) => v.visit_subitem7(x),
+    TableColumnContents::Subitem8(x) => v.visit_subitem8(x),
+    TableColumnContents::Subitem9(x) => v.visit_subitem9(x),
+    TableColumnContents::Subitem10(x) => v.visit_subitem10(x),
+    TableColumnContents::FigStruct(x) => v.visit_fig_struct(x),
+    TableColumnContents::Sentence(x) => v.visit_sentence(x),
+    TableColumnContents::Remarks(x) => v.visit_remarks(x),
+    TableColumnContents::Column(x) => v.visit_column(x),
+    TableColumnContents::String(x) => v.visit_string(x),
+  }
+}
+
+pub fn visit_class<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Class) {
+  if let Some(x) = &node.class_title {
+    v.visit_text(x);
+  }
+  v.visit_sentence_or_column_or_table(&node.class_sentence);
+  for x in node.children.iter() {
+    v.visit_item(x);
+  }
+}
+
+pub fn visit_sentence_or_column_or_table<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast SentenceOrColumnOrTable,
+) {
+  match node {
+    SentenceOrColumnOrTable::Sentence(lst) => {
+      for x in lst.iter() {
+        v.visit_sentence(x);
+      }
+    }
+    SentenceOrColumnOrTable::Column(lst) => {
+      for x in lst.iter() {
+        v.visit_column(x);
+      }
+    }
+    SentenceOrColumnOrTable::Table(x) => v.visit_table(x),
+  }
+}
+
+pub fn visit_caption<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Caption) {
+  v.visit_text(&node.text);
+}
+
+pub fn visit_column<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Column) {
+  for x in node.sentence.iter() {
+    v.visit_sentence(x);
+  }
+}
+
+pub fn visit_sentence<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Sentence) {
+  for x in node.contents.iter() {
+    v.visit_sentence_element(x);
+  }
+}
+
+pub fn visit_sentence_element<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast SentenceElement,
+) {
+  match node {
+    SentenceElement::Line(x) => v.visit_line(x),
+    SentenceElement::QuoteStruct(x) => v.visit_quote_struct(x),
+    SentenceElement::ArithFormula(x) => v.visit_arith_formula(x),
+    SentenceElement::Ruby(x) => v.visit_ruby(x),
+    SentenceElement::Sup(x) => v.visit_sup(x),
+    SentenceElement::Sub(x) => v.visit_sub(x),
+    SentenceElement::String(x) => v.visit_string(x),
+  }
+}
+
+pub fn visit_text<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Text) {
+  for x in node.contents.iter() {
+    v.visit_text_element(x);
+  }
+}
+
+pub fn visit_text_with_writing_mode<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast TextWithWritingMode,
+) {
+  for x in node.contents.iter() {
+    v.visit_text_element(x);
+  }
+}
+
+pub fn visit_text_element<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TextElement) {
+  match node {
+    TextElement::Ruby(x) => v.visit_ruby(x),
+    TextElement::Line(x) => v.visit_line(x),
+    TextElement::Sup(x) => v.visit_sup(x),
+    TextElement::Sub(x) => v.visit_sub(x),
+    TextElement::Text(x) => v.visit_string(x),
+  }
+}
+
+pub fn visit_ruby<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Ruby) {
+  v.visit_text(&node.text);
+  v.visit_string(&node.ruby);
+}
+
+pub fn visit_sup<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Sup) {
+  v.visit_string(&node.text);
+}
+
+pub fn visit_sub<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Sub) {
+  v.visit_string(&node.text);
+}
+
+pub fn visit_list<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast List) {
+  for x in node.sentence.iter() {
+    v.visit_list_sentence(x);
+  }
+  for x in node.children.iter() {
+    v.visit_sublist1(x);
+  }
+}
+
+pub fn visit_list_sentence<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ListSentence) {
+  match node {
+    ListSentence::Sentence(x) => v.visit_sentence(x),
+    ListSentence::Column(x) => v.visit_column(x),
+  }
+}
+
+pub fn visit_sublist1<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Sublist1) {
+  for x in node.sentence.iter() {
+    v.visit_list_sentence(x);
+  }
+  for x in node.children.iter() {
+    v.visit_sublist2(x);
+  }
+}
+
+pub fn visit_sublist2<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Sublist2) {
+  for x in node.sentence.iter() {
+    v.visit_list_sentence(x);
+  }
+  for x in node.children.iter() {
+    v.visit_sublist3(x);
+  }
+}
+
+pub fn visit_sublist3<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Sublist3) {
+  for x in node.sentence.iter() {
+    v.visit_list_sentence(x);
+  }
+}
+
+pub fn visit_remarks<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Remarks) {
+  v.visit_remarks_label(&node.label);
+  for x in node.children.iter() {
+    v.visit_remarks_contents(x);
+  }
+}
+
+pub fn visit_remarks_label<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast RemarksLabel) {
+  v.visit_text(&node.text);
+}
+
+pub fn visit_remarks_contents<'ast, V: Visit<'ast> + ?Sized>(
+  v: &mut V,
+  node: &'ast RemarksContents,
+) {
+  match node {
+    RemarksContents::Item(x) => v.visit_item(x),
+    RemarksContents::Sentence(x) => v.visit_sentence(x),
+  }
+}
+
+pub fn visit_fig<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Fig) {}
+
+pub fn visit_line<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Line) {
+  for x in node.contents.iter() {
+    v.visit_line_contents(x);
+  }
+}
+
+pub fn visit_line_contents<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast LineContents) {
+  match node {
+    LineContents::QuoteStruct(x) => v.visit_quote_struct(x),
+    LineContents::ArithFormula(x) => v.visit_arith_formula(x),
+    LineContents::Ruby(x) => v.visit_ruby(x),
+    LineContents::Sup(x) => v.visit_sup(x),
+    LineContents::Sub(x) => v.visit_sub(x),
+    LineContents::String(x) => v.visit_string(x),
+  }
+}
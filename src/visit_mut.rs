@@ -0,0 +1,1372 @@
+//! 法令の構造体を書き換えながら走査する
+//!
+//! [`VisitMut`]の各メソッドは、既定では同名の関数を呼び出して子要素を順に走査する。
+//! 書き換えたい要素のメソッドだけを実装すればよい。
+
+use crate::appdx::{
+  Appdx, AppdxFig, AppdxFigContents, AppdxFormat, AppdxNote, AppdxNoteContents, AppdxStyle,
+  AppdxTable, AppdxTableContents,
+};
+use crate::article::{
+  Article, Chapter, ChapterContents, Division, Part, PartContents, Section, SectionContents,
+  Subsection, SubsectionContents,
+};
+use crate::class::{Caption, Class, Column, SentenceOrColumnOrTable};
+use crate::contents::{ArithFormula, Contents, ContentsElement, Format, Note, Style};
+use crate::fig::Fig;
+use crate::law::{
+  AmendProvision, Law, LawBody, LawTitle, MainProvision, MainProvisionContents, NewProvision,
+  Preamble,
+};
+use crate::line::{Line, LineContents};
+use crate::list::{List, ListSentence, Sublist1, Sublist2, Sublist3};
+use crate::paragraph::{
+  Item, Paragraph, Subitem1, Subitem10, Subitem2, Subitem3, Subitem4, Subitem5, Subitem6, Subitem7,
+  Subitem8, Subitem9,
+};
+use crate::remarks::{Remarks, RemarksContents, RemarksLabel};
+use crate::sentence::{Sentence, SentenceElement};
+use crate::structs::{
+  FigStruct, FormatStruct, NoteStruct, QuoteStruct, Struct, StyleStruct, TableStruct,
+};
+use crate::suppl_provision::{
+  SupplProvision, SupplProvisionAppdx, SupplProvisionAppdxStyle, SupplProvisionAppdxTable,
+  SupplProvisionChildrenElement,
+};
+use crate::table::{Table, TableColumn, TableColumnContents, TableHeaderRow, TableRow};
+use crate::table_of_contents::{
+  TOCArticle, TOCChapter, TOCDivision, TOCMainContents, TOCPart, TOCSection, TOCSectionContents,
+  TOCSubsection, TOCSupplProvision, TOCSupplProvisionContents, TOC,
+};
+use crate::text::{Ruby, Sub, Sup, Text, TextElement, TextWithWritingMode};
+
+/// 法令の構造体を書き換えながら走査するトレイト
+pub trait VisitMut {
+  fn visit_law_mut(&mut self, node: &mut Law) {
+    visit_law_mut(self, node)
+  }
+  fn visit_law_body_mut(&mut self, node: &mut LawBody) {
+    visit_law_body_mut(self, node)
+  }
+  fn visit_law_title_mut(&mut self, node: &mut LawTitle) {
+    visit_law_title_mut(self, node)
+  }
+  fn visit_preamble_mut(&mut self, node: &mut Preamble) {
+    visit_preamble_mut(self, node)
+  }
+  fn visit_main_provision_mut(&mut self, node: &mut MainProvision) {
+    visit_main_provision_mut(self, node)
+  }
+  fn visit_main_provision_contents_mut(&mut self, node: &mut MainProvisionContents) {
+    visit_main_provision_contents_mut(self, node)
+  }
+  fn visit_amend_provision_mut(&mut self, node: &mut AmendProvision) {
+    visit_amend_provision_mut(self, node)
+  }
+  fn visit_new_provision_mut(&mut self, node: &mut NewProvision) {
+    visit_new_provision_mut(self, node)
+  }
+  fn visit_part_mut(&mut self, node: &mut Part) {
+    visit_part_mut(self, node)
+  }
+  fn visit_part_contents_mut(&mut self, node: &mut PartContents) {
+    visit_part_contents_mut(self, node)
+  }
+  fn visit_chapter_mut(&mut self, node: &mut Chapter) {
+    visit_chapter_mut(self, node)
+  }
+  fn visit_chapter_contents_mut(&mut self, node: &mut ChapterContents) {
+    visit_chapter_contents_mut(self, node)
+  }
+  fn visit_section_mut(&mut self, node: &mut Section) {
+    visit_section_mut(self, node)
+  }
+  fn visit_section_contents_mut(&mut self, node: &mut SectionContents) {
+    visit_section_contents_mut(self, node)
+  }
+  fn visit_subsection_mut(&mut self, node: &mut Subsection) {
+    visit_subsection_mut(self, node)
+  }
+  fn visit_subsection_contents_mut(&mut self, node: &mut SubsectionContents) {
+    visit_subsection_contents_mut(self, node)
+  }
+  fn visit_division_mut(&mut self, node: &mut Division) {
+    visit_division_mut(self, node)
+  }
+  fn visit_article_mut(&mut self, node: &mut Article) {
+    visit_article_mut(self, node)
+  }
+  fn visit_paragraph_mut(&mut self, node: &mut Paragraph) {
+    visit_paragraph_mut(self, node)
+  }
+  fn visit_item_mut(&mut self, node: &mut Item) {
+    visit_item_mut(self, node)
+  }
+  fn visit_subitem1_mut(&mut self, node: &mut Subitem1) {
+    visit_subitem1_mut(self, node)
+  }
+  fn visit_subitem2_mut(&mut self, node: &mut Subitem2) {
+    visit_subitem2_mut(self, node)
+  }
+  fn visit_subitem3_mut(&mut self, node: &mut Subitem3) {
+    visit_subitem3_mut(self, node)
+  }
+  fn visit_subitem4_mut(&mut self, node: &mut Subitem4) {
+    visit_subitem4_mut(self, node)
+  }
+  fn visit_subitem5_mut(&mut self, node: &mut Subitem5) {
+    visit_subitem5_mut(self, node)
+  }
+  fn visit_subitem6_mut(&mut self, node: &mut Subitem6) {
+    visit_subitem6_mut(self, node)
+  }
+  fn visit_subitem7_mut(&mut self, node: &mut Subitem7) {
+    visit_subitem7_mut(self, node)
+  }
+  fn visit_subitem8_mut(&mut self, node: &mut Subitem8) {
+    visit_subitem8_mut(self, node)
+  }
+  fn visit_subitem9_mut(&mut self, node: &mut Subitem9) {
+    visit_subitem9_mut(self, node)
+  }
+  fn visit_subitem10_mut(&mut self, node: &mut Subitem10) {
+    visit_subitem10_mut(self, node)
+  }
+  fn visit_suppl_provision_mut(&mut self, node: &mut SupplProvision) {
+    visit_suppl_provision_mut(self, node)
+  }
+  fn visit_suppl_provision_children_element_mut(
+    &mut self,
+    node: &mut SupplProvisionChildrenElement,
+  ) {
+    visit_suppl_provision_children_element_mut(self, node)
+  }
+  fn visit_suppl_provision_appdx_table_mut(&mut self, node: &mut SupplProvisionAppdxTable) {
+    visit_suppl_provision_appdx_table_mut(self, node)
+  }
+  fn visit_suppl_provision_appdx_style_mut(&mut self, node: &mut SupplProvisionAppdxStyle) {
+    visit_suppl_provision_appdx_style_mut(self, node)
+  }
+  fn visit_suppl_provision_appdx_mut(&mut self, node: &mut SupplProvisionAppdx) {
+    visit_suppl_provision_appdx_mut(self, node)
+  }
+  fn visit_toc_mut(&mut self, node: &mut TOC) {
+    visit_toc_mut(self, node)
+  }
+  fn visit_toc_main_contents_mut(&mut self, node: &mut TOCMainContents) {
+    visit_toc_main_contents_mut(self, node)
+  }
+  fn visit_toc_part_mut(&mut self, node: &mut TOCPart) {
+    visit_toc_part_mut(self, node)
+  }
+  fn visit_toc_chapter_mut(&mut self, node: &mut TOCChapter) {
+    visit_toc_chapter_mut(self, node)
+  }
+  fn visit_toc_section_mut(&mut self, node: &mut TOCSection) {
+    visit_toc_section_mut(self, node)
+  }
+  fn visit_toc_section_contents_mut(&mut self, node: &mut TOCSectionContents) {
+    visit_toc_section_contents_mut(self, node)
+  }
+  fn visit_toc_subsection_mut(&mut self, node: &mut TOCSubsection) {
+    visit_toc_subsection_mut(self, node)
+  }
+  fn visit_toc_division_mut(&mut self, node: &mut TOCDivision) {
+    visit_toc_division_mut(self, node)
+  }
+  fn visit_toc_article_mut(&mut self, node: &mut TOCArticle) {
+    visit_toc_article_mut(self, node)
+  }
+  fn visit_toc_suppl_provision_mut(&mut self, node: &mut TOCSupplProvision) {
+    visit_toc_suppl_provision_mut(self, node)
+  }
+  fn visit_toc_suppl_provision_contents_mut(&mut self, node: &mut TOCSupplProvisionContents) {
+    visit_toc_suppl_provision_contents_mut(self, node)
+  }
+  fn visit_appdx_table_mut(&mut self, node: &mut AppdxTable) {
+    visit_appdx_table_mut(self, node)
+  }
+  fn visit_appdx_table_contents_mut(&mut self, node: &mut AppdxTableContents) {
+    visit_appdx_table_contents_mut(self, node)
+  }
+  fn visit_appdx_note_mut(&mut self, node: &mut AppdxNote) {
+    visit_appdx_note_mut(self, node)
+  }
+  fn visit_appdx_note_contents_mut(&mut self, node: &mut AppdxNoteContents) {
+    visit_appdx_note_contents_mut(self, node)
+  }
+  fn visit_appdx_style_mut(&mut self, node: &mut AppdxStyle) {
+    visit_appdx_style_mut(self, node)
+  }
+  fn visit_appdx_format_mut(&mut self, node: &mut AppdxFormat) {
+    visit_appdx_format_mut(self, node)
+  }
+  fn visit_appdx_mut(&mut self, node: &mut Appdx) {
+    visit_appdx_mut(self, node)
+  }
+  fn visit_appdx_fig_mut(&mut self, node: &mut AppdxFig) {
+    visit_appdx_fig_mut(self, node)
+  }
+  fn visit_appdx_fig_contents_mut(&mut self, node: &mut AppdxFigContents) {
+    visit_appdx_fig_contents_mut(self, node)
+  }
+  fn visit_struct_mut(&mut self, node: &mut Struct) {
+    visit_struct_mut(self, node)
+  }
+  fn visit_quote_struct_mut(&mut self, node: &mut QuoteStruct) {
+    visit_quote_struct_mut(self, node)
+  }
+  fn visit_note_struct_mut(&mut self, node: &mut NoteStruct) {
+    visit_note_struct_mut(self, node)
+  }
+  fn visit_style_struct_mut(&mut self, node: &mut StyleStruct) {
+    visit_style_struct_mut(self, node)
+  }
+  fn visit_format_struct_mut(&mut self, node: &mut FormatStruct) {
+    visit_format_struct_mut(self, node)
+  }
+  fn visit_fig_struct_mut(&mut self, node: &mut FigStruct) {
+    visit_fig_struct_mut(self, node)
+  }
+  fn visit_table_struct_mut(&mut self, node: &mut TableStruct) {
+    visit_table_struct_mut(self, node)
+  }
+  fn visit_contents_mut(&mut self, node: &mut Contents) {
+    visit_contents_mut(self, node)
+  }
+  fn visit_contents_element_mut(&mut self, node: &mut ContentsElement) {
+    visit_contents_element_mut(self, node)
+  }
+  fn visit_style_mut(&mut self, node: &mut Style) {
+    visit_style_mut(self, node)
+  }
+  fn visit_note_mut(&mut self, node: &mut Note) {
+    visit_note_mut(self, node)
+  }
+  fn visit_format_mut(&mut self, node: &mut Format) {
+    visit_format_mut(self, node)
+  }
+  fn visit_arith_formula_mut(&mut self, node: &mut ArithFormula) {
+    visit_arith_formula_mut(self, node)
+  }
+  fn visit_table_mut(&mut self, node: &mut Table) {
+    visit_table_mut(self, node)
+  }
+  fn visit_table_header_row_mut(&mut self, node: &mut TableHeaderRow) {
+    visit_table_header_row_mut(self, node)
+  }
+  fn visit_table_row_mut(&mut self, node: &mut TableRow) {
+    visit_table_row_mut(self, node)
+  }
+  fn visit_table_column_mut(&mut self, node: &mut TableColumn) {
+    visit_table_column_mut(self, node)
+  }
+  fn visit_table_column_contents_mut(&mut self, node: &mut TableColumnContents) {
+    visit_table_column_contents_mut(self, node)
+  }
+  fn visit_class_mut(&mut self, node: &mut Class) {
+    visit_class_mut(self, node)
+  }
+  fn visit_sentence_or_column_or_table_mut(&mut self, node: &mut SentenceOrColumnOrTable) {
+    visit_sentence_or_column_or_table_mut(self, node)
+  }
+  fn visit_caption_mut(&mut self, node: &mut Caption) {
+    visit_caption_mut(self, node)
+  }
+  fn visit_column_mut(&mut self, node: &mut Column) {
+    visit_column_mut(self, node)
+  }
+  fn visit_sentence_mut(&mut self, node: &mut Sentence) {
+    visit_sentence_mut(self, node)
+  }
+  fn visit_sentence_element_mut(&mut self, node: &mut SentenceElement) {
+    visit_sentence_element_mut(self, node)
+  }
+  fn visit_text_mut(&mut self, node: &mut Text) {
+    visit_text_mut(self, node)
+  }
+  fn visit_text_with_writing_mode_mut(&mut self, node: &mut TextWithWritingMode) {
+    visit_text_with_writing_mode_mut(self, node)
+  }
+  fn visit_text_element_mut(&mut self, node: &mut TextElement) {
+    visit_text_element_mut(self, node)
+  }
+  fn visit_ruby_mut(&mut self, node: &mut Ruby) {
+    visit_ruby_mut(self, node)
+  }
+  fn visit_sup_mut(&mut self, node: &mut Sup) {
+    visit_sup_mut(self, node)
+  }
+  fn visit_sub_mut(&mut self, node: &mut Sub) {
+    visit_sub_mut(self, node)
+  }
+  fn visit_list_mut(&mut self, node: &mut List) {
+    visit_list_mut(self, node)
+  }
+  fn visit_list_sentence_mut(&mut self, node: &mut ListSentence) {
+    visit_list_sentence_mut(self, node)
+  }
+  fn visit_sublist1_mut(&mut self, node: &mut Sublist1) {
+    visit_sublist1_mut(self, node)
+  }
+  fn visit_sublist2_mut(&mut self, node: &mut Sublist2) {
+    visit_sublist2_mut(self, node)
+  }
+  fn visit_sublist3_mut(&mut self, node: &mut Sublist3) {
+    visit_sublist3_mut(self, node)
+  }
+  fn visit_remarks_mut(&mut self, node: &mut Remarks) {
+    visit_remarks_mut(self, node)
+  }
+  fn visit_remarks_label_mut(&mut self, node: &mut RemarksLabel) {
+    visit_remarks_label_mut(self, node)
+  }
+  fn visit_remarks_contents_mut(&mut self, node: &mut RemarksContents) {
+    visit_remarks_contents_mut(self, node)
+  }
+  fn visit_fig_mut(&mut self, node: &mut Fig) {
+    visit_fig_mut(self, node)
+  }
+  fn visit_line_mut(&mut self, node: &mut Line) {
+    visit_line_mut(self, node)
+  }
+  fn visit_line_contents_mut(&mut self, node: &mut LineContents) {
+    visit_line_contents_mut(self, node)
+  }
+  /// 本文の文字列
+  fn visit_string_mut(&mut self, _node: &mut String) {}
+}
+
+pub fn visit_law_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Law) {
+  v.visit_law_body_mut(&mut node.law_body);
+}
+
+pub fn visit_law_body_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut LawBody) {
+  if let Some(x) = &mut node.law_title {
+    v.visit_law_title_mut(x);
+  }
+  for x in node.enact_statement.iter_mut() {
+    v.visit_text_mut(x);
+  }
+  if let Some(x) = &mut node.toc {
+    v.visit_toc_mut(x);
+  }
+  if let Some(x) = &mut node.preamble {
+    v.visit_preamble_mut(x);
+  }
+  v.visit_main_provision_mut(&mut node.main_provision);
+  for x in node.suppl_provision.iter_mut() {
+    v.visit_suppl_provision_mut(x);
+  }
+  for x in node.appdx_table.iter_mut() {
+    v.visit_appdx_table_mut(x);
+  }
+  for x in node.appdx_note.iter_mut() {
+    v.visit_appdx_note_mut(x);
+  }
+  for x in node.appdx_style.iter_mut() {
+    v.visit_appdx_style_mut(x);
+  }
+  for x in node.appdx.iter_mut() {
+    v.visit_appdx_mut(x);
+  }
+  for x in node.appdx_fig.iter_mut() {
+    v.visit_appdx_fig_mut(x);
+  }
+  for x in node.appdx_format.iter_mut() {
+    v.visit_appdx_format_mut(x);
+  }
+}
+
+pub fn visit_law_title_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut LawTitle) {
+  v.visit_text_mut(&mut node.text);
+}
+
+pub fn visit_preamble_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Preamble) {
+  for x in node.children.iter_mut() {
+    v.visit_paragraph_mut(x);
+  }
+}
+
+pub fn visit_main_provision_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut MainProvision) {
+  for x in node.children.iter_mut() {
+    v.visit_main_provision_contents_mut(x);
+  }
+}
+
+pub fn visit_main_provision_contents_mut<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut MainProvisionContents,
+) {
+  match node {
+    MainProvisionContents::Part(x) => v.visit_part_mut(x),
+    MainProvisionContents::Chapter(x) => v.visit_chapter_mut(x),
+    MainProvisionContents::Section(x) => v.visit_section_mut(x),
+    MainProvisionContents::Article(x) => v.visit_article_mut(x),
+    MainProvisionContents::Paragraph(x) => v.visit_paragraph_mut(x),
+  }
+}
+
+pub fn visit_amend_provision_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AmendProvision) {
+  if let Some(x) = &mut node.sentence {
+    v.visit_sentence_mut(x);
+  }
+  for x in node.new_provision.iter_mut() {
+    v.visit_new_provision_mut(x);
+  }
+}
+
+pub fn visit_new_provision_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut NewProvision) {
+  match node {
+    NewProvision::LawTitle(x) => v.visit_law_title_mut(x),
+    NewProvision::Preamble(x) => v.visit_preamble_mut(x),
+    NewProvision::TOC(x) => v.visit_toc_mut(x),
+    NewProvision::Part(x) => v.visit_part_mut(x),
+    NewProvision::PartTitle(x) => v.visit_text_mut(x),
+    NewProvision::Chapter(x) => v.visit_chapter_mut(x),
+    NewProvision::ChapterTitle(x) => v.visit_text_mut(x),
+    NewProvision::Section(x) => v.visit_section_mut(x),
+    NewProvision::SectionTitle(x) => v.visit_text_mut(x),
+    NewProvision::Subsection(x) => v.visit_subsection_mut(x),
+    NewProvision::SubsectionTitle(x) => v.visit_text_mut(x),
+    NewProvision::Division(x) => v.visit_division_mut(x),
+    NewProvision::DivisionTitle(x) => v.visit_text_mut(x),
+    NewProvision::Article(x) => v.visit_article_mut(x),
+    NewProvision::SupplNote(x) => v.visit_text_mut(x),
+    NewProvision::Paragraph(x) => v.visit_paragraph_mut(x),
+    NewProvision::Item(x) => v.visit_item_mut(x),
+    NewProvision::Subitem1(x) => v.visit_subitem1_mut(x),
+    NewProvision::Subitem2(x) => v.visit_subitem2_mut(x),
+    NewProvision::Subitem3(x) => v.visit_subitem3_mut(x),
+    NewProvision::Subitem4(x) => v.visit_subitem4_mut(x),
+    NewProvision::Subitem5(x) => v.visit_subitem5_mut(x),
+    NewProvision::Subitem6(x) => v.visit_subitem6_mut(x),
+    NewProvision::Subitem7(x) => v.visit_subitem7_mut(x),
+    NewProvision::Subitem8(x) => v.visit_subitem8_mut(x),
+    NewProvision::Subitem9(x) => v.visit_subitem9_mut(x),
+    NewProvision::Subitem10(x) => v.visit_subitem10_mut(x),
+    NewProvision::List(x) => v.visit_list_mut(x),
+    NewProvision::Sentence(x) => v.visit_sentence_mut(x),
+    NewProvision::AmendProvision(x) => v.visit_amend_provision_mut(x),
+    NewProvision::AppdxTable(x) => v.visit_appdx_table_mut(x),
+    NewProvision::AppdxNote(x) => v.visit_appdx_note_mut(x),
+    NewProvision::AppdxStyle(x) => v.visit_appdx_style_mut(x),
+    NewProvision::Appdx(x) => v.visit_appdx_mut(x),
+    NewProvision::AppdxFig(x) => v.visit_appdx_fig_mut(x),
+    NewProvision::AppdxFormat(x) => v.visit_appdx_format_mut(x),
+    NewProvision::SupplProvisionAppdxStyle(x) => v.visit_suppl_provision_appdx_style_mut(x),
+    NewProvision::SupplProvisionAppdxTable(x) => v.visit_suppl_provision_appdx_table_mut(x),
+    NewProvision::SupplProvisionAppdx(x) => v.visit_suppl_provision_appdx_mut(x),
+    NewProvision::TableStruct(x) => v.visit_table_struct_mut(x),
+    NewProvision::TableRow(x) => v.visit_table_row_mut(x),
+    NewProvision::TableColumn(x) => v.visit_table_column_mut(x),
+    NewProvision::FigStruct(x) => v.visit_fig_struct_mut(x),
+    NewProvision::NoteStruct(x) => v.visit_note_struct_mut(x),
+    NewProvision::StyleStruct(x) => v.visit_style_struct_mut(x),
+    NewProvision::FormatStruct(x) => v.visit_format_struct_mut(x),
+    NewProvision::Remarks(x) => v.visit_remarks_mut(x),
+    NewProvision::LawBody(x) => v.visit_law_body_mut(x),
+  }
+}
+
+pub fn visit_part_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Part) {
+  v.visit_text_mut(&mut node.part_title);
+  for x in node.children.iter_mut() {
+    v.visit_part_contents_mut(x);
+  }
+}
+
+pub fn visit_part_contents_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PartContents) {
+  match node {
+    PartContents::Article(x) => v.visit_article_mut(x),
+    PartContents::Chapter(x) => v.visit_chapter_mut(x),
+  }
+}
+
+pub fn visit_chapter_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Chapter) {
+  v.visit_text_mut(&mut node.chapter_title);
+  for x in node.children.iter_mut() {
+    v.visit_chapter_contents_mut(x);
+  }
+}
+
+pub fn visit_chapter_contents_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ChapterContents) {
+  match node {
+    ChapterContents::Article(x) => v.visit_article_mut(x),
+    ChapterContents::Section(x) => v.visit_section_mut(x),
+  }
+}
+
+pub fn visit_section_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Section) {
+  v.visit_text_mut(&mut node.section_title);
+  for x in node.children.iter_mut() {
+    v.visit_section_contents_mut(x);
+  }
+}
+
+pub fn visit_section_contents_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SectionContents) {
+  match node {
+    SectionContents::Article(x) => v.visit_article_mut(x),
+    SectionContents::Subsection(x) => v.visit_subsection_mut(x),
+    SectionContents::Division(x) => v.visit_division_mut(x),
+  }
+}
+
+pub fn visit_subsection_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Subsection) {
+  v.visit_text_mut(&mut node.subsection_title);
+  for x in node.children.iter_mut() {
+    v.visit_subsection_contents_mut(x);
+  }
+}
+
+pub fn visit_subsection_contents_mut<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut SubsectionContents,
+) {
+  match node {
+    SubsectionContents::Article(x) => v.visit_article_mut(x),
+    SubsectionContents::Division(x) => v.visit_division_mut(x),
+  }
+}
+
+pub fn visit_division_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Division) {
+  v.visit_text_mut(&mut node.division_title);
+  for x in node.children.iter_mut() {
+    v.visit_article_mut(x);
+  }
+}
+
+pub fn visit_article_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Article) {
+  if let Some(x) = &mut node.caption {
+    v.visit_caption_mut(x);
+  }
+  v.visit_text_mut(&mut node.title);
+  for x in node.paragraph.iter_mut() {
+    v.visit_paragraph_mut(x);
+  }
+  if let Some(x) = &mut node.suppl_note {
+    v.visit_text_mut(x);
+  }
+}
+
+pub fn visit_paragraph_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Paragraph) {
+  if let Some(x) = &mut node.caption {
+    v.visit_caption_mut(x);
+  }
+  v.visit_text_mut(&mut node.paragraph_num);
+  for x in node.sentence.iter_mut() {
+    v.visit_sentence_mut(x);
+  }
+  for x in node.amend_provision.iter_mut() {
+    v.visit_amend_provision_mut(x);
+  }
+  for x in node.class.iter_mut() {
+    v.visit_class_mut(x);
+  }
+  for x in node.struct_list.iter_mut() {
+    v.visit_struct_mut(x);
+  }
+  for x in node.children.iter_mut() {
+    v.visit_item_mut(x);
+  }
+}
+
+pub fn visit_item_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Item) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_mut(x);
+  }
+  v.visit_sentence_or_column_or_table_mut(&mut node.sentence);
+  for x in node.children.iter_mut() {
+    v.visit_subitem1_mut(x);
+  }
+  for x in node.struct_list.iter_mut() {
+    v.visit_struct_mut(x);
+  }
+}
+
+pub fn visit_subitem1_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Subitem1) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_mut(x);
+  }
+  v.visit_sentence_or_column_or_table_mut(&mut node.sentence);
+  for x in node.children.iter_mut() {
+    v.visit_subitem2_mut(x);
+  }
+  for x in node.struct_list.iter_mut() {
+    v.visit_struct_mut(x);
+  }
+}
+
+pub fn visit_subitem2_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Subitem2) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_mut(x);
+  }
+  v.visit_sentence_or_column_or_table_mut(&mut node.sentence);
+  for x in node.children.iter_mut() {
+    v.visit_subitem3_mut(x);
+  }
+  for x in node.struct_list.iter_mut() {
+    v.visit_struct_mut(x);
+  }
+}
+
+pub fn visit_subitem3_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Subitem3) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_mut(x);
+  }
+  v.visit_sentence_or_column_or_table_mut(&mut node.sentence);
+  for x in node.children.iter_mut() {
+    v.visit_subitem4_mut(x);
+  }
+  for x in node.struct_list.iter_mut() {
+    v.visit_struct_mut(x);
+  }
+}
+
+pub fn visit_subitem4_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Subitem4) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_mut(x);
+  }
+  v.visit_sentence_or_column_or_table_mut(&mut node.sentence);
+  for x in node.children.iter_mut() {
+    v.visit_subitem5_mut(x);
+  }
+  for x in node.struct_list.iter_mut() {
+    v.visit_struct_mut(x);
+  }
+}
+
+pub fn visit_subitem5_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Subitem5) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_mut(x);
+  }
+  v.visit_sentence_or_column_or_table_mut(&mut node.sentence);
+  for x in node.children.iter_mut() {
+    v.visit_subitem6_mut(x);
+  }
+  for x in node.struct_list.iter_mut() {
+    v.visit_struct_mut(x);
+  }
+}
+
+pub fn visit_subitem6_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Subitem6) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_mut(x);
+  }
+  v.visit_sentence_or_column_or_table_mut(&mut node.sentence);
+  for x in node.children.iter_mut() {
+    v.visit_subitem7_mut(x);
+  }
+  for x in node.struct_list.iter_mut() {
+    v.visit_struct_mut(x);
+  }
+}
+
+pub fn visit_subitem7_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Subitem7) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_mut(x);
+  }
+  v.visit_sentence_or_column_or_table_mut(&mut node.sentence);
+  for x in node.children.iter_mut() {
+    v.visit_subitem8_mut(x);
+  }
+  for x in node.struct_list.iter_mut() {
+    v.visit_struct_mut(x);
+  }
+}
+
+pub fn visit_subitem8_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Subitem8) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_mut(x);
+  }
+  v.visit_sentence_or_column_or_table_mut(&mut node.sentence);
+  for x in node.children.iter_mut() {
+    v.visit_subitem9_mut(x);
+  }
+  for x in node.struct_list.iter_mut() {
+    v.visit_struct_mut(x);
+  }
+}
+
+pub fn visit_subitem9_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Subitem9) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_mut(x);
+  }
+  v.visit_sentence_or_column_or_table_mut(&mut node.sentence);
+  for x in node.children.iter_mut() {
+    v.visit_subitem10_mut(x);
+  }
+  for x in node.struct_list.iter_mut() {
+    v.visit_struct_mut(x);
+  }
+}
+
+pub fn visit_subitem10_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Subitem10) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_mut(x);
+  }
+  v.visit_sentence_or_column_or_table_mut(&mut node.sentence);
+  for x in node.struct_list.iter_mut() {
+    v.visit_struct_mut(x);
+  }
+}
+
+pub fn visit_suppl_provision_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SupplProvision) {
+  v.visit_text_mut(&mut node.label);
+  for x in node.children.iter_mut() {
+    v.visit_suppl_provision_children_element_mut(x);
+  }
+}
+
+pub fn visit_suppl_provision_children_element_mut<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut SupplProvisionChildrenElement,
+) {
+  match node {
+    SupplProvisionChildrenElement::Chapter(x) => v.visit_chapter_mut(x),
+    SupplProvisionChildrenElement::Article(x) => v.visit_article_mut(x),
+    SupplProvisionChildrenElement::Paragraph(x) => v.visit_paragraph_mut(x),
+    SupplProvisionChildrenElement::SupplProvisionAppdxTable(x) => {
+      v.visit_suppl_provision_appdx_table_mut(x)
+    }
+    SupplProvisionChildrenElement::SupplProvisionAppdxStyle(x) => {
+      v.visit_suppl_provision_appdx_style_mut(x)
+    }
+    SupplProvisionChildrenElement::SupplProvisionAppdx(x) => v.visit_suppl_provision_appdx_mut(x),
+  }
+}
+
+pub fn visit_suppl_provision_appdx_table_mut<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut SupplProvisionAppdxTable,
+) {
+  v.visit_text_with_writing_mode_mut(&mut node.title);
+  if let Some(x) = &mut node.related_article_num {
+    v.visit_text_mut(x);
+  }
+  for x in node.table_struct.iter_mut() {
+    v.visit_table_struct_mut(x);
+  }
+}
+
+pub fn visit_suppl_provision_appdx_style_mut<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut SupplProvisionAppdxStyle,
+) {
+  v.visit_text_with_writing_mode_mut(&mut node.title);
+  if let Some(x) = &mut node.related_article_num {
+    v.visit_text_mut(x);
+  }
+  for x in node.style_struct.iter_mut() {
+    v.visit_style_struct_mut(x);
+  }
+}
+
+pub fn visit_suppl_provision_appdx_mut<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut SupplProvisionAppdx,
+) {
+  if let Some(x) = &mut node.arith_formula_num {
+    v.visit_text_mut(x);
+  }
+  if let Some(x) = &mut node.related_article_num {
+    v.visit_text_mut(x);
+  }
+  for x in node.arith_formula.iter_mut() {
+    v.visit_arith_formula_mut(x);
+  }
+}
+
+pub fn visit_toc_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TOC) {
+  if let Some(x) = &mut node.toc_label {
+    v.visit_text_mut(x);
+  }
+  if let Some(x) = &mut node.toc_preamble_label {
+    v.visit_text_mut(x);
+  }
+  for x in node.toc_main_contents.iter_mut() {
+    v.visit_toc_main_contents_mut(x);
+  }
+  if let Some(x) = &mut node.toc_suppl_provision {
+    v.visit_toc_suppl_provision_mut(x);
+  }
+  for x in node.toc_appdx_table_label.iter_mut() {
+    v.visit_text_mut(x);
+  }
+}
+
+pub fn visit_toc_main_contents_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TOCMainContents) {
+  match node {
+    TOCMainContents::TOCPart(x) => v.visit_toc_part_mut(x),
+    TOCMainContents::TOCChapter(x) => v.visit_toc_chapter_mut(x),
+    TOCMainContents::TOCSection(x) => v.visit_toc_section_mut(x),
+    TOCMainContents::TOCArticle(x) => v.visit_toc_article_mut(x),
+  }
+}
+
+pub fn visit_toc_part_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TOCPart) {
+  v.visit_text_mut(&mut node.part_title);
+  if let Some(x) = &mut node.article_range {
+    v.visit_text_mut(x);
+  }
+  for x in node.children.iter_mut() {
+    v.visit_toc_chapter_mut(x);
+  }
+}
+
+pub fn visit_toc_chapter_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TOCChapter) {
+  v.visit_text_mut(&mut node.chapter_title);
+  if let Some(x) = &mut node.article_range {
+    v.visit_text_mut(x);
+  }
+  for x in node.children.iter_mut() {
+    v.visit_toc_section_mut(x);
+  }
+}
+
+pub fn visit_toc_section_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TOCSection) {
+  v.visit_text_mut(&mut node.section_title);
+  if let Some(x) = &mut node.article_range {
+    v.visit_text_mut(x);
+  }
+  for x in node.children.iter_mut() {
+    v.visit_toc_section_contents_mut(x);
+  }
+}
+
+pub fn visit_toc_section_contents_mut<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut TOCSectionContents,
+) {
+  match node {
+    TOCSectionContents::TOCSubsection(x) => v.visit_toc_subsection_mut(x),
+    TOCSectionContents::TOCDivision(x) => v.visit_toc_division_mut(x),
+  }
+}
+
+pub fn visit_toc_subsection_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TOCSubsection) {
+  v.visit_text_mut(&mut node.subsection_title);
+  if let Some(x) = &mut node.article_range {
+    v.visit_text_mut(x);
+  }
+  for x in node.children.iter_mut() {
+    v.visit_toc_division_mut(x);
+  }
+}
+
+pub fn visit_toc_division_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TOCDivision) {
+  v.visit_text_mut(&mut node.division_title);
+  if let Some(x) = &mut node.article_range {
+    v.visit_text_mut(x);
+  }
+}
+
+pub fn visit_toc_article_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TOCArticle) {
+  v.visit_text_mut(&mut node.article_title);
+  v.visit_caption_mut(&mut node.article_caption);
+}
+
+pub fn visit_toc_suppl_provision_mut<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut TOCSupplProvision,
+) {
+  v.visit_text_mut(&mut node.suppl_provision_label);
+  if let Some(x) = &mut node.article_range {
+    v.visit_text_mut(x);
+  }
+  for x in node.children.iter_mut() {
+    v.visit_toc_suppl_provision_contents_mut(x);
+  }
+}
+
+pub fn visit_toc_suppl_provision_contents_mut<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut TOCSupplProvisionContents,
+) {
+  match node {
+    TOCSupplProvisionContents::TOCArticle(x) => v.visit_toc_article_mut(x),
+    TOCSupplProvisionContents::TOCChapter(x) => v.visit_toc_chapter_mut(x),
+  }
+}
+
+pub fn visit_appdx_table_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AppdxTable) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_with_writing_mode_mut(x);
+  }
+  if let Some(x) = &mut node.related_article_num {
+    v.visit_text_mut(x);
+  }
+  for x in node.children.iter_mut() {
+    v.visit_appdx_table_contents_mut(x);
+  }
+  if let Some(x) = &mut node.remarks {
+    v.visit_remarks_mut(x);
+  }
+}
+
+pub fn visit_appdx_table_contents_mut<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut AppdxTableContents,
+) {
+  match node {
+    AppdxTableContents::TableStruct(x) => v.visit_table_struct_mut(x),
+    AppdxTableContents::Item(x) => v.visit_item_mut(x),
+  }
+}
+
+pub fn visit_appdx_note_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AppdxNote) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_with_writing_mode_mut(x);
+  }
+  if let Some(x) = &mut node.related_article_num {
+    v.visit_text_mut(x);
+  }
+  for x in node.children.iter_mut() {
+    v.visit_appdx_note_contents_mut(x);
+  }
+  if let Some(x) = &mut node.remarks {
+    v.visit_remarks_mut(x);
+  }
+}
+
+pub fn visit_appdx_note_contents_mut<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut AppdxNoteContents,
+) {
+  match node {
+    AppdxNoteContents::NoteStruct(x) => v.visit_note_struct_mut(x),
+    AppdxNoteContents::FigStruct(x) => v.visit_fig_struct_mut(x),
+    AppdxNoteContents::TableStruct(x) => v.visit_table_struct_mut(x),
+  }
+}
+
+pub fn visit_appdx_style_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AppdxStyle) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_with_writing_mode_mut(x);
+  }
+  if let Some(x) = &mut node.related_article_num {
+    v.visit_text_mut(x);
+  }
+  for x in node.children.iter_mut() {
+    v.visit_style_struct_mut(x);
+  }
+  if let Some(x) = &mut node.remarks {
+    v.visit_remarks_mut(x);
+  }
+}
+
+pub fn visit_appdx_format_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AppdxFormat) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_with_writing_mode_mut(x);
+  }
+  if let Some(x) = &mut node.related_article_num {
+    v.visit_text_mut(x);
+  }
+  for x in node.children.iter_mut() {
+    v.visit_format_struct_mut(x);
+  }
+  if let Some(x) = &mut node.remarks {
+    v.visit_remarks_mut(x);
+  }
+}
+
+pub fn visit_appdx_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Appdx) {
+  if let Some(x) = &mut node.arith_formula_num {
+    v.visit_text_mut(x);
+  }
+  if let Some(x) = &mut node.related_article_num {
+    v.visit_text_mut(x);
+  }
+  for x in node.arith_formula.iter_mut() {
+    v.visit_arith_formula_mut(x);
+  }
+  if let Some(x) = &mut node.remarks {
+    v.visit_remarks_mut(x);
+  }
+}
+
+pub fn visit_appdx_fig_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AppdxFig) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_with_writing_mode_mut(x);
+  }
+  if let Some(x) = &mut node.related_article_num {
+    v.visit_text_mut(x);
+  }
+  for x in node.children.iter_mut() {
+    v.visit_appdx_fig_contents_mut(x);
+  }
+}
+
+pub fn visit_appdx_fig_contents_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AppdxFigContents) {
+  match node {
+    AppdxFigContents::FigStruct(x) => v.visit_fig_struct_mut(x),
+    AppdxFigContents::TableStruct(x) => v.visit_table_struct_mut(x),
+  }
+}
+
+pub fn visit_struct_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Struct) {
+  match node {
+    Struct::TableStruct(x) => v.visit_table_struct_mut(x),
+    Struct::FigStruct(x) => v.visit_fig_struct_mut(x),
+    Struct::StyleStruct(x) => v.visit_style_struct_mut(x),
+    Struct::List(x) => v.visit_list_mut(x),
+  }
+}
+
+pub fn visit_quote_struct_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut QuoteStruct) {
+  v.visit_contents_mut(&mut node.contentes);
+}
+
+pub fn visit_note_struct_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut NoteStruct) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_mut(x);
+  }
+  for x in node.title_remarks.iter_mut() {
+    v.visit_remarks_mut(x);
+  }
+  v.visit_note_mut(&mut node.note);
+  for x in node.note_remarks.iter_mut() {
+    v.visit_remarks_mut(x);
+  }
+}
+
+pub fn visit_style_struct_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut StyleStruct) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_mut(x);
+  }
+  for x in node.title_remarks.iter_mut() {
+    v.visit_remarks_mut(x);
+  }
+  v.visit_style_mut(&mut node.style);
+  for x in node.style_remarks.iter_mut() {
+    v.visit_remarks_mut(x);
+  }
+}
+
+pub fn visit_format_struct_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut FormatStruct) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_mut(x);
+  }
+  for x in node.title_remarks.iter_mut() {
+    v.visit_remarks_mut(x);
+  }
+  v.visit_format_mut(&mut node.format);
+  for x in node.format_remarks.iter_mut() {
+    v.visit_remarks_mut(x);
+  }
+}
+
+pub fn visit_fig_struct_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut FigStruct) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_mut(x);
+  }
+  for x in node.title_remarks.iter_mut() {
+    v.visit_remarks_mut(x);
+  }
+  v.visit_fig_mut(&mut node.fig);
+  for x in node.fig_remarks.iter_mut() {
+    v.visit_remarks_mut(x);
+  }
+}
+
+pub fn visit_table_struct_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TableStruct) {
+  if let Some(x) = &mut node.title {
+    v.visit_text_with_writing_mode_mut(x);
+  }
+  for x in node.title_remarks.iter_mut() {
+    v.visit_remarks_mut(x);
+  }
+  v.visit_table_mut(&mut node.table);
+  for x in node.table_remarks.iter_mut() {
+    v.visit_remarks_mut(x);
+  }
+}
+
+pub fn visit_contents_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Contents) {
+  for x in node.contents.iter_mut() {
+    v.visit_contents_element_mut(x);
+  }
+}
+
+pub fn visit_contents_element_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ContentsElement) {
+  match node {
+    ContentsElement::Table(x) => v.visit_table_mut(x),
+    ContentsElement::TableStruct(x) => v.visit_table_struct_mut(x),
+    ContentsElement::Fig(x) => v.visit_fig_mut(x),
+    ContentsElement::FigStruct(x) => v.visit_fig_struct_mut(x),
+    ContentsElement::QuoteStruct(x) => v.visit_quote_struct_mut(x),
+    ContentsElement::NoteStruct(x) => v.visit_note_struct_mut(x),
+    ContentsElement::StyleStruct(x) => v.visit_style_struct_mut(x),
+    ContentsElement::AppdxTable(x) => v.visit_appdx_table_mut(x),
+    ContentsElement::Ruby(x) => v.visit_ruby_mut(x),
+    ContentsElement::Line(x) => v.visit_line_mut(x),
+    ContentsElement::Sup(x) => v.visit_sup_mut(x),
+    ContentsElement::Sub(x) => v.visit_sub_mut(x),
+    ContentsElement::String(x) => v.visit_string_mut(x),
+    ContentsElement::Paragraph(x) => v.visit_paragraph_mut(x),
+    ContentsElement::Item(x) => v.visit_item_mut(x),
+    ContentsElement::Subitem1(x) => v.visit_subitem1_mut(x),
+    ContentsElement::Subitem2(x) => v.visit_subitem2_mut(x),
+    ContentsElement::Subitem3(x) => v.visit_subitem3_mut(x),
+    ContentsElement::Subitem4(x) => v.visit_subitem4_mut(x),
+    ContentsElement::Subitem5(x) => v.visit_subitem5_mut(x),
+    ContentsElement::Subitem6(x) => v.visit_subitem6_mut(x),
+    ContentsElement::Subitem7(x) => v.visit_subitem7_mut(x),
+    ContentsElement::Subitem8(x) => v.visit_subitem8_mut(x),
+    ContentsElement::Subitem9(x) => v.visit_subitem9_mut(x),
+    ContentsElement::Subitem10(x) => v.visit_subitem10_mut(x),
+    ContentsElement::List(x) => v.visit_list_mut(x),
+    ContentsElement::Sentence(x) => v.visit_sentence_mut(x),
+    ContentsElement::ArithFormula(x) => v.visit_arith_formula_mut(x),
+    ContentsElement::Remarks(x) => v.visit_remarks_mut(x),
+    ContentsElement::TOC(x) => v.visit_toc_mut(x),
+    ContentsElement::TOCSection(x) => v.visit_toc_section_mut(x),
+    ContentsElement::TableRow(x) => v.visit_table_row_mut(x),
+  }
+}
+
+pub fn visit_style_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Style) {
+  v.visit_contents_mut(&mut node.contentes);
+}
+
+pub fn visit_note_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Note) {
+  v.visit_contents_mut(&mut node.contentes);
+}
+
+pub fn visit_format_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Format) {
+  v.visit_contents_mut(&mut node.contentes);
+}
+
+pub fn visit_arith_formula_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ArithFormula) {
+  v.visit_contents_mut(&mut node.contentes);
+}
+
+pub fn visit_table_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Table) {
+  for x in node.table_header_row.iter_mut() {
+    v.visit_table_header_row_mut(x);
+  }
+  for x in node.table_row.iter_mut() {
+    v.visit_table_row_mut(x);
+  }
+}
+
+pub fn visit_table_header_row_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TableHeaderRow) {
+  for x in node.columns.iter_mut() {
+    v.visit_text_mut(x);
+  }
+}
+
+pub fn visit_table_row_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TableRow) {
+  for x in node.columns.iter_mut() {
+    v.visit_table_column_mut(x);
+  }
+}
+
+pub fn visit_table_column_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TableColumn) {
+  for x in node.contents.iter_mut() {
+    v.visit_table_column_contents_mut(x);
+  }
+}
+
+pub fn visit_table_column_contents_mut<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut TableColumnContents,
+) {
+  match node {
+    TableColumnContents::Part(x) => v.visit_part_mut(x),
+    TableColumnContents::Chapter(x) => v.visit_chapter_mut(x),
+    TableColumnContents::Section(x) => v.visit_section_mut(x),
+    TableColumnContents::Subsection(x) => v.visit_subsection_mut(x),
+    TableColumnContents::Division(x) => v.visit_division_mut(x),
+    TableColumnContents::Article(x) => v.visit_article_mut(x),
+    TableColumnContents::Paragraph(x) => v.visit_paragraph_mut(x),
+    TableColumnContents::Item(x) => v.visit_item_mut(x),
+    TableColumnContents::Subitem1(x) => v.visit_subitem1_mut(x),
+    TableColumnContents::Subitem2(x) => v.visit_subitem2_mut(x),
+    TableColumnContents::Subitem3(x) => v.visit_subitem3_mut(x),
+    TableColumnContents::Subitem4(x) => v.visit_subitem4_mut(x),
+    TableColumnContents::Subitem5(x) => v.visit_subitem5_mut(x),
+    TableColumnContents::Subitem6(x) => v.visit_subitem6_mut(x),
+    TableColumnContents::Subitem7(x) => v.visit_subitem7_mut(x),
+    TableColumnContents::Subitem8(x) => v.visit_subitem8_mut(x),
+    TableColumnContents::Subitem9(x) => v.visit_subitem9_mut(x),
+    TableColumnContents::Subitem10(x) => v.visit_subitem10_mut(x),
+    TableColumnContents::FigStruct(x) => v.visit_fig_struct_mut(x),
+    TableColumnContents::Sentence(x) => v.visit_sentence_mut(x),
+    TableColumnContents::Remarks(x) => v.visit_remarks_mut(x),
+    TableColumnContents::Column(x) => v.visit_column_mut(x),
+    TableColumnContents::String(x) => v.visit_string_mut(x),
+  }
+}
+
+pub fn visit_class_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Class) {
+  if let Some(x) = &mut node.class_title {
+    v.visit_text_mut(x);
+  }
+  v.visit_sentence_or_column_or_table_mut(&mut node.class_sentence);
+  for x in node.children.iter_mut() {
+    v.visit_item_mut(x);
+  }
+}
+
+pub fn visit_sentence_or_column_or_table_mut<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut SentenceOrColumnOrTable,
+) {
+  match node {
+    SentenceOrColumnOrTable::Sentence(lst) => {
+      for x in lst.iter_mut() {
+        v.visit_sentence_mut(x);
+      }
+    }
+    SentenceOrColumnOrTable::Column(lst) => {
+      for x in lst.iter_mut() {
+        v.visit_column_mut(x);
+      }
+    }
+    SentenceOrColumnOrTable::Table(x) => v.visit_table_mut(x),
+  }
+}
+
+pub fn visit_caption_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Caption) {
+  v.visit_text_mut(&mut node.text);
+}
+
+pub fn visit_column_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Column) {
+  for x in node.sentence.iter_mut() {
+    v.visit_sentence_mut(x);
+  }
+}
+
+pub fn visit_sentence_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Sentence) {
+  for x in node.contents.iter_mut() {
+    v.visit_sentence_element_mut(x);
+  }
+}
+
+pub fn visit_sentence_element_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SentenceElement) {
+  match node {
+    SentenceElement::Line(x) => v.visit_line_mut(x),
+    SentenceElement::QuoteStruct(x) => v.visit_quote_struct_mut(x),
+    SentenceElement::ArithFormula(x) => v.visit_arith_formula_mut(x),
+    SentenceElement::Ruby(x) => v.visit_ruby_mut(x),
+    SentenceElement::Sup(x) => v.visit_sup_mut(x),
+    SentenceElement::Sub(x) => v.visit_sub_mut(x),
+    SentenceElement::String(x) => v.visit_string_mut(x),
+  }
+}
+
+pub fn visit_text_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Text) {
+  for x in node.contents.iter_mut() {
+    v.visit_text_element_mut(x);
+  }
+}
+
+pub fn visit_text_with_writing_mode_mut<V: VisitMut + ?Sized>(
+  v: &mut V,
+  node: &mut TextWithWritingMode,
+) {
+  for x in node.contents.iter_mut() {
+    v.visit_text_element_mut(x);
+  }
+}
+
+pub fn visit_text_element_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TextElement) {
+  match node {
+    TextElement::Ruby(x) => v.visit_ruby_mut(x),
+    TextElement::Line(x) => v.visit_line_mut(x),
+    TextElement::Sup(x) => v.visit_sup_mut(x),
+    TextElement::Sub(x) => v.visit_sub_mut(x),
+    TextElement::Text(x) => v.visit_string_mut(x),
+  }
+}
+
+pub fn visit_ruby_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Ruby) {
+  v.visit_text_mut(&mut node.text);
+  v.visit_string_mut(&mut node.ruby);
+}
+
+pub fn visit_sup_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Sup) {
+  v.visit_string_mut(&mut node.text);
+}
+
+pub fn visit_sub_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Sub) {
+  v.visit_string_mut(&mut node.text);
+}
+
+pub fn visit_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut List) {
+  for x in node.sentence.iter_mut() {
+    v.visit_list_sentence_mut(x);
+  }
+  for x in node.children.iter_mut() {
+    v.visit_sublist1_mut(x);
+  }
+}
+
+pub fn visit_list_sentence_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ListSentence) {
+  match node {
+    ListSentence::Sentence(x) => v.visit_sentence_mut(x),
+    ListSentence::Column(x) => v.visit_column_mut(x),
+  }
+}
+
+pub fn visit_sublist1_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Sublist1) {
+  for x in node.sentence.iter_mut() {
+    v.visit_list_sentence_mut(x);
+  }
+  for x in node.children.iter_mut() {
+    v.visit_sublist2_mut(x);
+  }
+}
+
+pub fn visit_sublist2_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Sublist2) {
+  for x in node.sentence.iter_mut() {
+    v.visit_list_sentence_mut(x);
+  }
+  for x in node.children.iter_mut() {
+    v.visit_sublist3_mut(x);
+  }
+}
+
+pub fn visit_sublist3_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Sublist3) {
+  for x in node.sentence.iter_mut() {
+    v.visit_list_sentence_mut(x);
+  }
+}
+
+pub fn visit_remarks_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Remarks) {
+  v.visit_remarks_label_mut(&mut node.label);
+  for x in node.children.iter_mut() {
+    v.visit_remarks_contents_mut(x);
+  }
+}
+
+pub fn visit_remarks_label_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RemarksLabel) {
+  v.visit_text_mut(&mut node.text);
+}
+
+pub fn visit_remarks_contents_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RemarksContents) {
+  match node {
+    RemarksContents::Item(x) => v.visit_item_mut(x),
+    RemarksContents::Sentence(x) => v.visit_sentence_mut(x),
+  }
+}
+
+pub fn visit_fig_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Fig) {}
+
+pub fn visit_line_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Line) {
+  for x in node.contents.iter_mut() {
+    v.visit_line_contents_mut(x);
+  }
+}
+
+pub fn visit_line_contents_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut LineContents) {
+  match node {
+    LineContents::QuoteStruct(x) => v.visit_quote_struct_mut(x),
+    LineContents::ArithFormula(x) => v.visit_arith_formula_mut(x),
+    LineContents::Ruby(x) => v.visit_ruby_mut(x),
+    LineContents::Sup(x) => v.visit_sup_mut(x),
+    LineContents::Sub(x) => v.visit_sub_mut(x),
+    LineContents::String(x) => v.visit_string_mut(x),
+  }
+}
@@ -0,0 +1,152 @@
+//! XMLの書き出し方の設定
+
+use crate::result::{Error, Result};
+use std::borrow::Cow;
+use std::io::Write;
+use xml::attribute::Attribute;
+use xml::common::XmlVersion;
+use xml::name::Name;
+use xml::namespace::Namespace;
+use xml::writer::{EmitterConfig, EventWriter, XmlEvent};
+use xmltree::{Element, XMLNode};
+
+/// XMLを書き出すときの設定
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WriteOptions {
+  /// インデントするかどうか
+  pub indent: bool,
+  /// インデント一段あたりの空白の数
+  pub indent_width: usize,
+  /// XML宣言を書き出すかどうか
+  pub xml_declaration: bool,
+  /// XML宣言に書く文字コード名
+  ///
+  /// 出力は常にUTF-8なので、`"UTF-8"`や`"utf-8"`などUTF-8を表す名前だけを受け付ける。
+  pub encoding: String,
+  /// XML宣言に書く`standalone`の値
+  pub standalone: Option<bool>,
+  /// 属性をe-Govのファイルと同じ順序で並べるかどうか
+  ///
+  /// `Law`は`Era Lang LawType Num Year PromulgateMonth PromulgateDay`、
+  /// `LawTitle`は`Kana Abbrev AbbrevKana`の順で、それ以外の要素は属性名の辞書順で並べる。
+  pub canonical_attribute_order: bool,
+  /// 中身のない要素を`<ParagraphNum/>`のように書き出すかどうか
+  pub self_closing: bool,
+}
+
+impl Default for WriteOptions {
+  fn default() -> Self {
+    WriteOptions {
+      indent: true,
+      indent_width: 2,
+      xml_declaration: true,
+      encoding: "UTF-8".to_string(),
+      standalone: None,
+      canonical_attribute_order: true,
+      self_closing: true,
+    }
+  }
+}
+
+impl WriteOptions {
+  fn emitter_config(&self) -> EmitterConfig {
+    EmitterConfig::new()
+      .perform_indent(self.indent)
+      .indent_string(" ".repeat(self.indent_width))
+      .write_document_declaration(false)
+      .normalize_empty_elements(self.self_closing)
+      .pad_self_closing(false)
+  }
+}
+
+/// e-Govのファイルで決まった順序に並んでいる属性
+fn attribute_order(element_name: &str) -> &'static [&'static str] {
+  match element_name {
+    "Law" => &[
+      "Era",
+      "Lang",
+      "LawType",
+      "Num",
+      "Year",
+      "PromulgateMonth",
+      "PromulgateDay",
+    ],
+    "LawTitle" => &["Kana", "Abbrev", "AbbrevKana"],
+    _ => &[],
+  }
+}
+
+/// 要素を設定に従って書き出す
+pub(crate) fn write_element<W: Write>(
+  element: &Element,
+  w: &mut W,
+  options: &WriteOptions,
+) -> Result<()> {
+  let encoding = options.encoding.to_ascii_lowercase();
+  if encoding != "utf-8" && encoding != "utf8" {
+    return Err(Error::UnsupportedEncoding(options.encoding.clone()));
+  }
+  let mut writer = EventWriter::new_with_config(w, options.emitter_config());
+  if options.xml_declaration {
+    writer
+      .write(XmlEvent::StartDocument {
+        version: XmlVersion::Version10,
+        encoding: Some(&options.encoding),
+        standalone: options.standalone,
+      })
+      .map_err(|_| Error::Io)?;
+  }
+  write_node(element, &mut writer, options)
+}
+
+fn write_node<W: Write>(
+  element: &Element,
+  writer: &mut EventWriter<W>,
+  options: &WriteOptions,
+) -> Result<()> {
+  let mut attributes = element.attributes.iter().collect::<Vec<_>>();
+  if options.canonical_attribute_order {
+    let order = attribute_order(&element.name);
+    attributes.sort_by_key(|(k, _)| {
+      (
+        order.iter().position(|s| s == k).unwrap_or(order.len()),
+        k.as_str(),
+      )
+    });
+  }
+  let attributes = attributes
+    .into_iter()
+    .map(|(k, v)| Attribute {
+      name: Name::local(k),
+      value: v,
+    })
+    .collect::<Vec<_>>();
+  let name = Name::local(&element.name);
+  let namespace = Namespace::empty();
+  writer
+    .write(XmlEvent::StartElement {
+      name,
+      attributes: Cow::Owned(attributes),
+      namespace: Cow::Borrowed(&namespace),
+    })
+    .map_err(|_| Error::Io)?;
+  for node in element.children.iter() {
+    match node {
+      XMLNode::Element(e) => write_node(e, writer, options)?,
+      XMLNode::Text(s) => writer
+        .write(XmlEvent::Characters(s))
+        .map_err(|_| Error::Io)?,
+      XMLNode::CData(s) => writer.write(XmlEvent::CData(s)).map_err(|_| Error::Io)?,
+      XMLNode::Comment(s) => writer.write(XmlEvent::Comment(s)).map_err(|_| Error::Io)?,
+      XMLNode::ProcessingInstruction(name, data) => writer
+        .write(XmlEvent::ProcessingInstruction {
+          name,
+          data: data.as_deref(),
+        })
+        .map_err(|_| Error::Io)?,
+    }
+  }
+  writer
+    .write(XmlEvent::EndElement { name: Some(name) })
+    .map_err(|_| Error::Io)
+}